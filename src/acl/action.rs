@@ -0,0 +1,28 @@
+/// What to do with a flow matching a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    /// Allow the flow
+    Permit,
+
+    /// Block the flow
+    Deny,
+}
+
+impl Action {
+    /// Checks if this action allows a flow
+    ///
+    /// ## Return Value
+    /// Returns true for [`Action::Permit`]
+    pub const fn is_permit(&self) -> bool {
+        matches!(self, Action::Permit)
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Action::Permit => "permit",
+            Action::Deny => "deny",
+        })
+    }
+}
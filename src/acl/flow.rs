@@ -0,0 +1,89 @@
+use crate::{ip::IPAddress, port::Port};
+
+/// The addresses, protocol, and ports of traffic checked against an ACL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flow {
+    source: IPAddress,
+    destination: IPAddress,
+    protocol: u8,
+    ports: Option<(Port, Port)>,
+}
+
+impl Flow {
+    /// Creates a new [`Flow`] without ports
+    ///
+    /// ## Parameters
+    ///  * `source` - The address the traffic is sent from
+    ///  * `destination` - The address the traffic is sent to
+    ///  * `protocol` - The protocol number of the traffic
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Flow`]
+    pub const fn new(source: IPAddress, destination: IPAddress, protocol: u8) -> Self {
+        Flow {
+            source,
+            destination,
+            protocol,
+            ports: None,
+        }
+    }
+
+    /// Sets the ports of a protocol which uses ports
+    ///
+    /// ## Parameters
+    ///  * `source` - The port the traffic is sent from
+    ///  * `destination` - The port the traffic is sent to
+    ///
+    /// ## Return Value
+    /// Returns the modified [`Flow`]
+    pub const fn with_ports(mut self, source: Port, destination: Port) -> Self {
+        self.ports = Some((source, destination));
+        self
+    }
+
+    /// Gets the address the traffic is sent from
+    ///
+    /// ## Return Value
+    /// Returns the source address
+    pub const fn source(&self) -> IPAddress {
+        self.source
+    }
+
+    /// Gets the address the traffic is sent to
+    ///
+    /// ## Return Value
+    /// Returns the destination address
+    pub const fn destination(&self) -> IPAddress {
+        self.destination
+    }
+
+    /// Gets the protocol of the traffic
+    ///
+    /// ## Return Value
+    /// Returns the protocol number
+    pub const fn protocol(&self) -> u8 {
+        self.protocol
+    }
+
+    /// Gets the port the traffic is sent from
+    ///
+    /// ## Return Value
+    /// Returns the source port, if the flow has ports
+    pub const fn source_port(&self) -> Option<Port> {
+        match self.ports {
+            Some((source, _)) => Some(source),
+            None => None,
+        }
+    }
+
+    /// Gets the port the traffic is sent to
+    ///
+    /// ## Return Value
+    /// Returns the destination port, if the flow has ports
+    pub const fn destination_port(&self) -> Option<Port> {
+        match self.ports {
+            Some((_, destination)) => Some(destination),
+            None => None,
+        }
+    }
+}
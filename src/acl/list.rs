@@ -0,0 +1,194 @@
+use super::{Action, Flow, Rule};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// An ordered list of rules evaluated first-match, counting how many flows each rule matches
+#[derive(Debug)]
+pub struct ACL {
+    entries: Vec<Entry>,
+    default_action: Action,
+    default_hits: AtomicU64,
+}
+
+/// A rule and the number of flows it has matched
+#[derive(Debug)]
+struct Entry {
+    rule: Rule,
+    hits: AtomicU64,
+}
+
+impl ACL {
+    /// Creates a new [`ACL`] without any rules
+    ///
+    /// ## Parameters
+    ///  * `default_action` - The action taken for a flow which matches no rule
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ACL`]
+    pub const fn new(default_action: Action) -> Self {
+        ACL {
+            entries: Vec::new(),
+            default_action,
+            default_hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Adds a rule after the existing rules
+    ///
+    /// ## Parameters
+    ///  * `rule` - The rule to add
+    pub fn push(&mut self, rule: Rule) {
+        self.insert(self.entries.len(), rule);
+    }
+
+    /// Adds a rule at `index`, shifting the following rules down
+    ///
+    /// ## Parameters
+    ///  * `index` - The position of the new rule
+    ///  * `rule` - The rule to add
+    pub fn insert(&mut self, index: usize, rule: Rule) {
+        self.entries.insert(
+            index,
+            Entry {
+                rule,
+                hits: AtomicU64::new(0),
+            },
+        );
+    }
+
+    /// Removes the rule at `index`
+    ///
+    /// ## Parameters
+    ///  * `index` - The position of the rule to remove
+    ///
+    /// ## Return Value
+    /// Returns the removed rule, or [`None`] if there is no rule at `index`
+    pub fn remove(&mut self, index: usize) -> Option<Rule> {
+        if index < self.entries.len() {
+            Some(self.entries.remove(index).rule)
+        } else {
+            None
+        }
+    }
+
+    /// Evaluates `flow` against the rules, counting the match
+    ///
+    /// ## Parameters
+    ///  * `flow` - The flow to evaluate
+    ///
+    /// ## Return Value
+    /// Returns the action of the first matching rule, or the default action
+    pub fn evaluate(&self, flow: &Flow) -> Action {
+        match self.entries.iter().find(|entry| entry.rule.matches(flow)) {
+            Some(entry) => {
+                entry.hits.fetch_add(1, Ordering::Relaxed);
+                entry.rule.action()
+            }
+            None => {
+                self.default_hits.fetch_add(1, Ordering::Relaxed);
+                self.default_action
+            }
+        }
+    }
+
+    /// Finds the first rule matching `flow` without counting the match
+    ///
+    /// ## Parameters
+    ///  * `flow` - The flow to check
+    ///
+    /// ## Return Value
+    /// Returns the position of the first matching rule, or [`None`] if no rule matches
+    pub fn find(&self, flow: &Flow) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.rule.matches(flow))
+    }
+
+    /// Gets the rules in the order they are evaluated
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the rules
+    pub fn rules(&self) -> impl Iterator<Item = &Rule> {
+        self.entries.iter().map(|entry| &entry.rule)
+    }
+
+    /// Gets the number of rules
+    ///
+    /// ## Return Value
+    /// Returns the number of rules
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if there are no rules
+    ///
+    /// ## Return Value
+    /// Returns true if there are no rules
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the action taken for a flow which matches no rule
+    ///
+    /// ## Return Value
+    /// Returns the default action
+    pub const fn default_action(&self) -> Action {
+        self.default_action
+    }
+
+    /// Gets the number of flows the rule at `index` has matched
+    ///
+    /// ## Parameters
+    ///  * `index` - The position of the rule
+    ///
+    /// ## Return Value
+    /// Returns the match count, or [`None`] if there is no rule at `index`
+    pub fn hits(&self, index: usize) -> Option<u64> {
+        self.entries
+            .get(index)
+            .map(|entry| entry.hits.load(Ordering::Relaxed))
+    }
+
+    /// Gets the number of flows which matched no rule
+    ///
+    /// ## Return Value
+    /// Returns the number of times the default action was taken
+    pub fn default_hits(&self) -> u64 {
+        self.default_hits.load(Ordering::Relaxed)
+    }
+
+    /// Resets every match count to zero
+    pub fn reset_hits(&self) {
+        for entry in &self.entries {
+            entry.hits.store(0, Ordering::Relaxed);
+        }
+        self.default_hits.store(0, Ordering::Relaxed);
+    }
+}
+
+impl Clone for ACL {
+    fn clone(&self) -> Self {
+        ACL {
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| Entry {
+                    rule: entry.rule.clone(),
+                    hits: AtomicU64::new(entry.hits.load(Ordering::Relaxed)),
+                })
+                .collect(),
+            default_action: self.default_action,
+            default_hits: AtomicU64::new(self.default_hits.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl FromIterator<Rule> for ACL {
+    /// Collects the rules into an [`ACL`] which denies flows matching no rule
+    fn from_iter<I: IntoIterator<Item = Rule>>(iter: I) -> Self {
+        let mut acl = ACL::new(Action::Deny);
+        for rule in iter {
+            acl.push(rule);
+        }
+        acl
+    }
+}
@@ -0,0 +1,15 @@
+//! Access control list (ACL) utilities
+//!
+//! An [`ACL`] holds an ordered list of [`Rule`]s. A [`Flow`] is checked against each rule in
+//! turn and the action of the first matching rule is taken, or the default action if no rule
+//! matches.
+
+mod action;
+mod flow;
+mod list;
+mod rule;
+
+pub use action::Action;
+pub use flow::Flow;
+pub use list::ACL;
+pub use rule::Rule;
@@ -0,0 +1,178 @@
+use super::{Action, Flow};
+use crate::{
+    ip::{IPAddress, IPCIDR},
+    port::{Port, PortSet},
+};
+
+/// A rule of an ACL
+///
+/// Each part of a rule which is left unset matches any flow. A rule with ports only matches flows
+/// with ports.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rule {
+    action: Action,
+    sources: Vec<IPCIDR>,
+    destinations: Vec<IPCIDR>,
+    protocol: Option<u8>,
+    source_ports: Option<PortSet>,
+    destination_ports: Option<PortSet>,
+}
+
+impl Rule {
+    /// Creates a new [`Rule`] which matches every flow
+    ///
+    /// ## Parameters
+    ///  * `action` - The action taken for a matching flow
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Rule`]
+    pub const fn new(action: Action) -> Self {
+        Rule {
+            action,
+            sources: Vec::new(),
+            destinations: Vec::new(),
+            protocol: None,
+            source_ports: None,
+            destination_ports: None,
+        }
+    }
+
+    /// Adds a network the source address may be in
+    ///
+    /// ## Parameters
+    ///  * `source` - The network to add
+    ///
+    /// ## Return Value
+    /// Returns the modified [`Rule`]
+    pub fn with_source<C: Into<IPCIDR>>(mut self, source: C) -> Self {
+        self.sources.push(source.into());
+        self
+    }
+
+    /// Adds a network the destination address may be in
+    ///
+    /// ## Parameters
+    ///  * `destination` - The network to add
+    ///
+    /// ## Return Value
+    /// Returns the modified [`Rule`]
+    pub fn with_destination<C: Into<IPCIDR>>(mut self, destination: C) -> Self {
+        self.destinations.push(destination.into());
+        self
+    }
+
+    /// Sets the protocol a flow must use
+    ///
+    /// ## Parameters
+    ///  * `protocol` - The protocol number
+    ///
+    /// ## Return Value
+    /// Returns the modified [`Rule`]
+    pub const fn with_protocol(mut self, protocol: u8) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    /// Sets the ports the source port must be in
+    ///
+    /// ## Parameters
+    ///  * `ports` - The allowed source ports
+    ///
+    /// ## Return Value
+    /// Returns the modified [`Rule`]
+    pub fn with_source_ports(mut self, ports: PortSet) -> Self {
+        self.source_ports = Some(ports);
+        self
+    }
+
+    /// Sets the ports the destination port must be in
+    ///
+    /// ## Parameters
+    ///  * `ports` - The allowed destination ports
+    ///
+    /// ## Return Value
+    /// Returns the modified [`Rule`]
+    pub fn with_destination_ports(mut self, ports: PortSet) -> Self {
+        self.destination_ports = Some(ports);
+        self
+    }
+
+    /// Gets the action taken for a matching flow
+    ///
+    /// ## Return Value
+    /// Returns the action of this rule
+    pub const fn action(&self) -> Action {
+        self.action
+    }
+
+    /// Gets the networks the source address may be in
+    ///
+    /// ## Return Value
+    /// Returns the source networks, which match any address if empty
+    pub fn sources(&self) -> &[IPCIDR] {
+        &self.sources
+    }
+
+    /// Gets the networks the destination address may be in
+    ///
+    /// ## Return Value
+    /// Returns the destination networks, which match any address if empty
+    pub fn destinations(&self) -> &[IPCIDR] {
+        &self.destinations
+    }
+
+    /// Gets the protocol a flow must use
+    ///
+    /// ## Return Value
+    /// Returns the protocol number, if one is required
+    pub const fn protocol(&self) -> Option<u8> {
+        self.protocol
+    }
+
+    /// Gets the ports the source port must be in
+    ///
+    /// ## Return Value
+    /// Returns the allowed source ports, if they are restricted
+    pub const fn source_ports(&self) -> Option<&PortSet> {
+        self.source_ports.as_ref()
+    }
+
+    /// Gets the ports the destination port must be in
+    ///
+    /// ## Return Value
+    /// Returns the allowed destination ports, if they are restricted
+    pub const fn destination_ports(&self) -> Option<&PortSet> {
+        self.destination_ports.as_ref()
+    }
+
+    /// Checks if `flow` matches this rule
+    ///
+    /// ## Parameters
+    ///  * `flow` - The flow to check
+    ///
+    /// ## Return Value
+    /// Returns true if every part of this rule matches `flow`
+    pub fn matches(&self, flow: &Flow) -> bool {
+        self.protocol
+            .map(|protocol| protocol == flow.protocol())
+            .unwrap_or(true)
+            && networks_match(&self.sources, flow.source())
+            && networks_match(&self.destinations, flow.destination())
+            && ports_match(self.source_ports.as_ref(), flow.source_port())
+            && ports_match(self.destination_ports.as_ref(), flow.destination_port())
+    }
+}
+
+/// Checks if `address` is in any of `networks`, or if `networks` is empty
+fn networks_match(networks: &[IPCIDR], address: IPAddress) -> bool {
+    networks.is_empty() || networks.iter().any(|network| network.contains(address))
+}
+
+/// Checks if `port` is in `ports`, or if `ports` is unrestricted
+fn ports_match(ports: Option<&PortSet>, port: Option<Port>) -> bool {
+    match (ports, port) {
+        (None, _) => true,
+        (Some(ports), Some(port)) => ports.contains(port),
+        (Some(_), None) => false,
+    }
+}
@@ -0,0 +1,58 @@
+use crate::ErrorKind;
+
+/// An error while parsing an ARP packet
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidARPPacketError {
+    /// The packet is shorter than 28 bytes
+    Truncated,
+
+    /// The hardware type is not Ethernet
+    UnsupportedHardware(u16),
+
+    /// The protocol type is not IPv4
+    UnsupportedProtocol(u16),
+
+    /// The address lengths do not match Ethernet and IPv4 addresses
+    InvalidAddressLength,
+}
+
+impl InvalidARPPacketError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidARPPacketError::Truncated => ErrorKind::Truncated,
+            InvalidARPPacketError::UnsupportedHardware(_)
+            | InvalidARPPacketError::UnsupportedProtocol(_) => ErrorKind::Unsupported,
+            InvalidARPPacketError::InvalidAddressLength => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidARPPacketError {}
+
+impl std::fmt::Display for InvalidARPPacketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidARPPacketError::Truncated => write!(f, "ARP packet is truncated"),
+            InvalidARPPacketError::UnsupportedHardware(hardware) => {
+                write!(f, "unsupported ARP hardware type {}", hardware)
+            }
+            InvalidARPPacketError::UnsupportedProtocol(protocol) => {
+                write!(f, "unsupported ARP protocol type 0x{:04X}", protocol)
+            }
+            InvalidARPPacketError::InvalidAddressLength => {
+                write!(f, "invalid ARP address length")
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidARPPacketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
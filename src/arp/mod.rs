@@ -0,0 +1,11 @@
+//! Address Resolution Protocol (ARP) utilities from RFC 826
+//!
+//! Only packets resolving IPv4 addresses to Ethernet addresses are supported.
+
+mod error;
+mod operation;
+mod packet;
+
+pub use error::InvalidARPPacketError;
+pub use operation::ARPOperation;
+pub use packet::ARPPacket;
@@ -0,0 +1,63 @@
+/// The operation of an ARP packet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ARPOperation {
+    /// A request for the hardware address of the target
+    Request,
+
+    /// A reply carrying the hardware address of the sender
+    Reply,
+
+    /// Any other operation
+    Other(u16),
+}
+
+impl ARPOperation {
+    /// Creates the [`ARPOperation`] with the code `code`
+    ///
+    /// ## Parameters
+    ///  * `code` - The numeric operation code
+    ///
+    /// ## Return Value
+    /// Returns the [`ARPOperation`] with `code`
+    pub const fn new(code: u16) -> Self {
+        match code {
+            1 => ARPOperation::Request,
+            2 => ARPOperation::Reply,
+            _ => ARPOperation::Other(code),
+        }
+    }
+
+    /// Gets the numeric code of this operation
+    ///
+    /// ## Return Value
+    /// Returns the code carried in a packet
+    pub const fn code(&self) -> u16 {
+        match self {
+            ARPOperation::Request => 1,
+            ARPOperation::Reply => 2,
+            ARPOperation::Other(code) => *code,
+        }
+    }
+}
+
+impl From<u16> for ARPOperation {
+    fn from(code: u16) -> Self {
+        ARPOperation::new(code)
+    }
+}
+
+impl From<ARPOperation> for u16 {
+    fn from(operation: ARPOperation) -> Self {
+        operation.code()
+    }
+}
+
+impl std::fmt::Display for ARPOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ARPOperation::Request => f.write_str("request"),
+            ARPOperation::Reply => f.write_str("reply"),
+            ARPOperation::Other(code) => write!(f, "operation {}", code),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use super::{ARPOperation, InvalidARPPacketError};
+use crate::{ethernet::EtherType, ip::v4::IPv4Address, mac::MACAddress};
+
+/// The hardware type of Ethernet
+const HARDWARE_ETHERNET: u16 = 1;
+
+/// An ARP packet resolving an IPv4 address to an Ethernet address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ARPPacket {
+    operation: ARPOperation,
+    sender_hardware: MACAddress,
+    sender_protocol: IPv4Address,
+    target_hardware: MACAddress,
+    target_protocol: IPv4Address,
+}
+
+impl ARPPacket {
+    /// The length of an encoded packet
+    pub const LENGTH: usize = 28;
+
+    /// Creates a new [`ARPPacket`]
+    ///
+    /// ## Parameters
+    ///  * `operation` - The operation of the packet
+    ///  * `sender_hardware` - The hardware address of the sender
+    ///  * `sender_protocol` - The IPv4 address of the sender
+    ///  * `target_hardware` - The hardware address of the target
+    ///  * `target_protocol` - The IPv4 address of the target
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ARPPacket`]
+    pub const fn new(
+        operation: ARPOperation,
+        sender_hardware: MACAddress,
+        sender_protocol: IPv4Address,
+        target_hardware: MACAddress,
+        target_protocol: IPv4Address,
+    ) -> Self {
+        ARPPacket {
+            operation,
+            sender_hardware,
+            sender_protocol,
+            target_hardware,
+            target_protocol,
+        }
+    }
+
+    /// Creates a request for the hardware address of `target`
+    ///
+    /// ## Parameters
+    ///  * `sender_hardware` - The hardware address of the sender
+    ///  * `sender_protocol` - The IPv4 address of the sender
+    ///  * `target` - The IPv4 address to resolve
+    ///
+    /// ## Return Value
+    /// Returns the request
+    pub const fn request(
+        sender_hardware: MACAddress,
+        sender_protocol: IPv4Address,
+        target: IPv4Address,
+    ) -> Self {
+        ARPPacket::new(
+            ARPOperation::Request,
+            sender_hardware,
+            sender_protocol,
+            MACAddress::UNSPECIFIED,
+            target,
+        )
+    }
+
+    /// Creates a reply to `request`
+    ///
+    /// ## Parameters
+    ///  * `request` - The request being answered
+    ///  * `hardware` - The hardware address of the requested IPv4 address
+    ///
+    /// ## Return Value
+    /// Returns the reply, sent from the target of `request` to its sender
+    pub const fn reply(request: &ARPPacket, hardware: MACAddress) -> Self {
+        ARPPacket::new(
+            ARPOperation::Reply,
+            hardware,
+            request.target_protocol,
+            request.sender_hardware,
+            request.sender_protocol,
+        )
+    }
+
+    /// Creates a gratuitous ARP request announcing `address`
+    ///
+    /// ## Parameters
+    ///  * `hardware` - The hardware address of the sender
+    ///  * `address` - The IPv4 address being announced
+    ///
+    /// ## Return Value
+    /// Returns the announcement, which has `address` as both the sender and target
+    pub const fn gratuitous(hardware: MACAddress, address: IPv4Address) -> Self {
+        ARPPacket::request(hardware, address, address)
+    }
+
+    /// Creates an ARP probe from RFC 5227 checking if `address` is in use
+    ///
+    /// ## Parameters
+    ///  * `hardware` - The hardware address of the sender
+    ///  * `address` - The IPv4 address being probed
+    ///
+    /// ## Return Value
+    /// Returns the probe, which has an unspecified sender IPv4 address
+    pub const fn probe(hardware: MACAddress, address: IPv4Address) -> Self {
+        ARPPacket::request(hardware, IPv4Address::UNSPECIFIED, address)
+    }
+
+    /// Parses a packet from the start of `bytes`
+    ///
+    /// Any bytes after the packet, such as Ethernet padding, are ignored.
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The packet, starting at the hardware type
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`ARPPacket`]
+    pub fn parse(bytes: &[u8]) -> Result<Self, InvalidARPPacketError> {
+        let bytes = bytes
+            .get(..ARPPacket::LENGTH)
+            .ok_or(InvalidARPPacketError::Truncated)?;
+
+        let hardware = u16::from_be_bytes([bytes[0], bytes[1]]);
+        if hardware != HARDWARE_ETHERNET {
+            return Err(InvalidARPPacketError::UnsupportedHardware(hardware));
+        }
+
+        let protocol = u16::from_be_bytes([bytes[2], bytes[3]]);
+        if protocol != EtherType::IPv4.value() {
+            return Err(InvalidARPPacketError::UnsupportedProtocol(protocol));
+        }
+
+        if bytes[4] != 6 || bytes[5] != 4 {
+            return Err(InvalidARPPacketError::InvalidAddressLength);
+        }
+
+        let mac = |start: usize| MACAddress::new(bytes[start..start + 6].try_into().unwrap());
+        let ip = |start: usize| {
+            IPv4Address::from(<[u8; 4]>::try_from(&bytes[start..start + 4]).unwrap())
+        };
+
+        Ok(ARPPacket::new(
+            ARPOperation::new(u16::from_be_bytes([bytes[6], bytes[7]])),
+            mac(8),
+            ip(14),
+            mac(18),
+            ip(24),
+        ))
+    }
+
+    /// Encodes this packet
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded packet to
+    pub fn write(&self, output: &mut Vec<u8>) {
+        output.reserve(ARPPacket::LENGTH);
+        output.extend_from_slice(&HARDWARE_ETHERNET.to_be_bytes());
+        output.extend_from_slice(&EtherType::IPv4.value().to_be_bytes());
+        output.extend_from_slice(&[6, 4]);
+        output.extend_from_slice(&self.operation.code().to_be_bytes());
+        output.extend_from_slice(self.sender_hardware.as_slice());
+        output.extend_from_slice(&self.sender_protocol.octets());
+        output.extend_from_slice(self.target_hardware.as_slice());
+        output.extend_from_slice(&self.target_protocol.octets());
+    }
+
+    /// Gets the operation of this packet
+    ///
+    /// ## Return Value
+    /// Returns the operation
+    pub const fn operation(&self) -> ARPOperation {
+        self.operation
+    }
+
+    /// Gets the hardware address of the sender
+    ///
+    /// ## Return Value
+    /// Returns the sender hardware address
+    pub const fn sender_hardware(&self) -> MACAddress {
+        self.sender_hardware
+    }
+
+    /// Gets the IPv4 address of the sender
+    ///
+    /// ## Return Value
+    /// Returns the sender IPv4 address
+    pub const fn sender_protocol(&self) -> IPv4Address {
+        self.sender_protocol
+    }
+
+    /// Gets the hardware address of the target
+    ///
+    /// ## Return Value
+    /// Returns the target hardware address, which is unspecified in a request
+    pub const fn target_hardware(&self) -> MACAddress {
+        self.target_hardware
+    }
+
+    /// Gets the IPv4 address of the target
+    ///
+    /// ## Return Value
+    /// Returns the target IPv4 address
+    pub const fn target_protocol(&self) -> IPv4Address {
+        self.target_protocol
+    }
+
+    /// Checks if this packet is a gratuitous ARP announcing the address of the sender
+    ///
+    /// ## Return Value
+    /// Returns true if the sender and target IPv4 addresses are the same
+    pub fn is_gratuitous(&self) -> bool {
+        !self.sender_protocol.is_unspecified() && self.sender_protocol == self.target_protocol
+    }
+
+    /// Checks if this packet is an ARP probe from RFC 5227
+    ///
+    /// ## Return Value
+    /// Returns true if this is a request with an unspecified sender IPv4 address
+    pub fn is_probe(&self) -> bool {
+        self.operation == ARPOperation::Request && self.sender_protocol.is_unspecified()
+    }
+}
@@ -0,0 +1,146 @@
+use super::InvalidASNError;
+use std::str::FromStr;
+
+/// A 4-byte autonomous system number from RFC 6793
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ASN(u32);
+
+impl ASN {
+    /// The number used in place of a 4-byte number by speakers of 2-byte numbers
+    pub const TRANS: ASN = ASN(23456);
+
+    /// The largest 2-byte autonomous system number
+    pub const MAX_16BIT: u32 = u16::MAX as u32;
+
+    /// Creates a new [`ASN`]
+    ///
+    /// ## Parameters
+    ///  * `number` - The autonomous system number
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ASN`]
+    pub const fn new(number: u32) -> Self {
+        ASN(number)
+    }
+
+    /// Gets the autonomous system number
+    ///
+    /// ## Return Value
+    /// Returns the number
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+
+    /// Checks if this number fits in the 2-byte numbers of the original BGP
+    ///
+    /// ## Return Value
+    /// Returns true if this number is 65535 or less
+    pub const fn is_16bit(&self) -> bool {
+        self.0 <= ASN::MAX_16BIT
+    }
+
+    /// Checks if this number is reserved for private use by RFC 6996
+    ///
+    /// ## Return Value
+    /// Returns true if this number is from 64512 to 65534 or from 4200000000 to 4294967294
+    pub const fn is_private(&self) -> bool {
+        matches!(self.0, 64512..=65534 | 4200000000..=4294967294)
+    }
+
+    /// Checks if this number is reserved for documentation by RFC 5398
+    ///
+    /// ## Return Value
+    /// Returns true if this number is from 64496 to 64511 or from 65536 to 65551
+    pub const fn is_documentation(&self) -> bool {
+        matches!(self.0, 64496..=64511 | 65536..=65551)
+    }
+
+    /// Checks if this number is reserved and may not be used
+    ///
+    /// This covers 0 from RFC 7607, 65535 and 4294967295 from RFC 7300, the IANA reserved block
+    /// from 65552 to 131071, and [`ASN::TRANS`].
+    ///
+    /// ## Return Value
+    /// Returns true if this number is reserved
+    pub const fn is_reserved(&self) -> bool {
+        matches!(self.0, 0 | 23456 | 65535 | 65552..=131071 | 4294967295)
+    }
+
+    /// Checks if this number may be used on the public Internet
+    ///
+    /// ## Return Value
+    /// Returns true if this number is not private, documentation, or reserved
+    pub const fn is_global(&self) -> bool {
+        !self.is_private() && !self.is_documentation() && !self.is_reserved()
+    }
+
+    /// Formats this number in the asdot notation from RFC 5396
+    ///
+    /// Numbers which fit in 2 bytes are written in asplain, such as "65000", and larger numbers
+    /// are written as two 16-bit halves, such as "1.15".
+    ///
+    /// ## Return Value
+    /// Returns the asdot form of this number
+    pub fn to_asdot(&self) -> String {
+        if self.is_16bit() {
+            self.0.to_string()
+        } else {
+            format!("{}.{}", self.0 >> 16, self.0 & 0xFFFF)
+        }
+    }
+}
+
+impl From<u32> for ASN {
+    fn from(number: u32) -> Self {
+        ASN::new(number)
+    }
+}
+
+impl From<u16> for ASN {
+    fn from(number: u16) -> Self {
+        ASN::new(number as u32)
+    }
+}
+
+impl From<ASN> for u32 {
+    fn from(asn: ASN) -> Self {
+        asn.0
+    }
+}
+
+impl FromStr for ASN {
+    type Err = InvalidASNError;
+
+    /// Parses the asplain or asdot notation, with an optional "AS" prefix, such as "AS65551" or
+    /// "1.15"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = match s.get(..2) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("as") => &s[2..],
+            _ => s,
+        };
+
+        match s.split_once('.') {
+            Some((high, low)) => {
+                let high = high
+                    .parse::<u16>()
+                    .map_err(InvalidASNError::InvalidNumber)?;
+                let low = low.parse::<u16>().map_err(InvalidASNError::InvalidNumber)?;
+                Ok(ASN(((high as u32) << 16) | low as u32))
+            }
+            None => s.parse().map(ASN).map_err(InvalidASNError::InvalidNumber),
+        }
+    }
+}
+
+impl std::fmt::Display for ASN {
+    /// Writes the asplain notation, such as "65551"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for ASN {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,507 @@
+use super::{InvalidCommunityError, ASN};
+use crate::ip::v4::IPv4Address;
+use std::str::FromStr;
+
+/// A standard BGP community from RFC 1997, written as "asn:value"
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Community(u32);
+
+/// A BGP extended community from RFC 4360
+///
+/// Route targets and route origins are written as "rt:global:local" and "soo:global:local",
+/// where the global administrator is a 2-byte AS number, an IPv4 address, or a 4-byte AS number.
+/// Any other extended community is written as its 8 bytes in hexadecimal, such as
+/// "0x0002FDE800000064".
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExtendedCommunity([u8; 8]);
+
+/// A BGP large community from RFC 8092, written as "global:local1:local2"
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LargeCommunity {
+    global: u32,
+    local1: u32,
+    local2: u32,
+}
+
+/// The names of the well-known communities
+const WELL_KNOWN: &[(Community, &str)] = &[
+    (Community::GRACEFUL_SHUTDOWN, "graceful-shutdown"),
+    (Community::ACCEPT_OWN, "accept-own"),
+    (Community::LLGR_STALE, "llgr-stale"),
+    (Community::NO_LLGR, "no-llgr"),
+    (Community::BLACKHOLE, "blackhole"),
+    (Community::NO_EXPORT, "no-export"),
+    (Community::NO_ADVERTISE, "no-advertise"),
+    (Community::NO_EXPORT_SUBCONFED, "no-export-subconfed"),
+    (Community::NO_PEER, "no-peer"),
+];
+
+/// The type of an extended community with a 2-byte AS number global administrator
+const TYPE_TWO_OCTET_AS: u8 = 0x00;
+
+/// The type of an extended community with an IPv4 address global administrator
+const TYPE_IPV4_ADDRESS: u8 = 0x01;
+
+/// The type of an extended community with a 4-byte AS number global administrator
+const TYPE_FOUR_OCTET_AS: u8 = 0x02;
+
+impl Community {
+    /// Requests that a route is gracefully withdrawn, from RFC 8326
+    pub const GRACEFUL_SHUTDOWN: Community = Community(0xFFFF0000);
+
+    /// Allows a route to be accepted by its originator, from RFC 7611
+    pub const ACCEPT_OWN: Community = Community(0xFFFF0001);
+
+    /// Marks a route retained as stale during long-lived graceful restart
+    pub const LLGR_STALE: Community = Community(0xFFFF0006);
+
+    /// Prevents a route from being retained during long-lived graceful restart
+    pub const NO_LLGR: Community = Community(0xFFFF0007);
+
+    /// Requests that traffic to a route is discarded, from RFC 7999
+    pub const BLACKHOLE: Community = Community(0xFFFF029A);
+
+    /// Prevents a route from being advertised outside of the confederation
+    pub const NO_EXPORT: Community = Community(0xFFFFFF01);
+
+    /// Prevents a route from being advertised to any peer
+    pub const NO_ADVERTISE: Community = Community(0xFFFFFF02);
+
+    /// Prevents a route from being advertised outside of the local AS
+    pub const NO_EXPORT_SUBCONFED: Community = Community(0xFFFFFF03);
+
+    /// Prevents a route from being advertised to bilateral peers, from RFC 3765
+    pub const NO_PEER: Community = Community(0xFFFFFF04);
+
+    /// Creates a new [`Community`]
+    ///
+    /// ## Parameters
+    ///  * `asn` - The AS number in the high 16 bits
+    ///  * `value` - The value in the low 16 bits
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Community`]
+    pub const fn new(asn: u16, value: u16) -> Self {
+        Community(((asn as u32) << 16) | value as u32)
+    }
+
+    /// Creates a [`Community`] from the 32-bit value carried in an update
+    ///
+    /// ## Parameters
+    ///  * `bits` - The encoded community
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Community`]
+    pub const fn from_bits(bits: u32) -> Self {
+        Community(bits)
+    }
+
+    /// Gets the 32-bit value carried in an update
+    ///
+    /// ## Return Value
+    /// Returns the encoded community
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the AS number in the high 16 bits
+    ///
+    /// ## Return Value
+    /// Returns the AS number
+    pub const fn asn(&self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    /// Gets the value in the low 16 bits
+    ///
+    /// ## Return Value
+    /// Returns the value
+    pub const fn value(&self) -> u16 {
+        self.0 as u16
+    }
+
+    /// Gets the name of this community if it is well-known
+    ///
+    /// ## Return Value
+    /// Returns the name, such as "no-export", or [`None`] if this community is not well-known
+    pub fn name(&self) -> Option<&'static str> {
+        WELL_KNOWN
+            .iter()
+            .find(|(community, _)| community == self)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl ExtendedCommunity {
+    /// The subtype of a route target
+    pub const ROUTE_TARGET: u8 = 0x02;
+
+    /// The subtype of a route origin, also known as site of origin
+    pub const ROUTE_ORIGIN: u8 = 0x03;
+
+    /// Creates an [`ExtendedCommunity`] from the 8 bytes carried in an update
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The encoded community
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ExtendedCommunity`]
+    pub const fn from_bytes(bytes: [u8; 8]) -> Self {
+        ExtendedCommunity(bytes)
+    }
+
+    /// Creates an [`ExtendedCommunity`] with a 2-byte AS number global administrator
+    ///
+    /// ## Parameters
+    ///  * `subtype` - The subtype, such as [`ExtendedCommunity::ROUTE_TARGET`]
+    ///  * `asn` - The global administrator
+    ///  * `local` - The local administrator
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ExtendedCommunity`]
+    pub const fn two_octet_as(subtype: u8, asn: u16, local: u32) -> Self {
+        let asn = asn.to_be_bytes();
+        let local = local.to_be_bytes();
+        ExtendedCommunity([
+            TYPE_TWO_OCTET_AS,
+            subtype,
+            asn[0],
+            asn[1],
+            local[0],
+            local[1],
+            local[2],
+            local[3],
+        ])
+    }
+
+    /// Creates an [`ExtendedCommunity`] with an IPv4 address global administrator
+    ///
+    /// ## Parameters
+    ///  * `subtype` - The subtype, such as [`ExtendedCommunity::ROUTE_TARGET`]
+    ///  * `address` - The global administrator
+    ///  * `local` - The local administrator
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ExtendedCommunity`]
+    pub const fn ipv4_address(subtype: u8, address: IPv4Address, local: u16) -> Self {
+        let address = address.octets();
+        let local = local.to_be_bytes();
+        ExtendedCommunity([
+            TYPE_IPV4_ADDRESS,
+            subtype,
+            address[0],
+            address[1],
+            address[2],
+            address[3],
+            local[0],
+            local[1],
+        ])
+    }
+
+    /// Creates an [`ExtendedCommunity`] with a 4-byte AS number global administrator
+    ///
+    /// ## Parameters
+    ///  * `subtype` - The subtype, such as [`ExtendedCommunity::ROUTE_TARGET`]
+    ///  * `asn` - The global administrator
+    ///  * `local` - The local administrator
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ExtendedCommunity`]
+    pub const fn four_octet_as(subtype: u8, asn: ASN, local: u16) -> Self {
+        let asn = asn.get().to_be_bytes();
+        let local = local.to_be_bytes();
+        ExtendedCommunity([
+            TYPE_FOUR_OCTET_AS,
+            subtype,
+            asn[0],
+            asn[1],
+            asn[2],
+            asn[3],
+            local[0],
+            local[1],
+        ])
+    }
+
+    /// Gets the 8 bytes carried in an update
+    ///
+    /// ## Return Value
+    /// Returns the encoded community
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        self.0
+    }
+
+    /// Gets the type, including the IANA authority and transitive bits
+    ///
+    /// ## Return Value
+    /// Returns the high type byte
+    pub const fn kind(&self) -> u8 {
+        self.0[0]
+    }
+
+    /// Gets the subtype
+    ///
+    /// ## Return Value
+    /// Returns the low type byte
+    pub const fn subtype(&self) -> u8 {
+        self.0[1]
+    }
+
+    /// Checks if this community may be passed to other autonomous systems
+    ///
+    /// ## Return Value
+    /// Returns true if the transitive bit of the type is clear
+    pub const fn is_transitive(&self) -> bool {
+        self.0[0] & 0x40 == 0
+    }
+
+    /// Gets the prefix of the conventional notation of this community
+    fn notation(&self) -> Option<&'static str> {
+        if !matches!(
+            self.kind(),
+            TYPE_TWO_OCTET_AS | TYPE_IPV4_ADDRESS | TYPE_FOUR_OCTET_AS
+        ) {
+            return None;
+        }
+
+        match self.subtype() {
+            ExtendedCommunity::ROUTE_TARGET => Some("rt"),
+            ExtendedCommunity::ROUTE_ORIGIN => Some("soo"),
+            _ => None,
+        }
+    }
+}
+
+impl LargeCommunity {
+    /// Creates a new [`LargeCommunity`]
+    ///
+    /// ## Parameters
+    ///  * `global` - The AS number of the global administrator
+    ///  * `local1` - The first local data part
+    ///  * `local2` - The second local data part
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`LargeCommunity`]
+    pub const fn new(global: ASN, local1: u32, local2: u32) -> Self {
+        LargeCommunity {
+            global: global.get(),
+            local1,
+            local2,
+        }
+    }
+
+    /// Gets the AS number of the global administrator
+    ///
+    /// ## Return Value
+    /// Returns the global administrator
+    pub const fn global(&self) -> ASN {
+        ASN::new(self.global)
+    }
+
+    /// Gets the first local data part
+    ///
+    /// ## Return Value
+    /// Returns the first local data part
+    pub const fn local1(&self) -> u32 {
+        self.local1
+    }
+
+    /// Gets the second local data part
+    ///
+    /// ## Return Value
+    /// Returns the second local data part
+    pub const fn local2(&self) -> u32 {
+        self.local2
+    }
+}
+
+impl From<u32> for Community {
+    fn from(bits: u32) -> Self {
+        Community::from_bits(bits)
+    }
+}
+
+impl From<Community> for u32 {
+    fn from(community: Community) -> Self {
+        community.bits()
+    }
+}
+
+impl From<[u8; 8]> for ExtendedCommunity {
+    fn from(bytes: [u8; 8]) -> Self {
+        ExtendedCommunity::from_bytes(bytes)
+    }
+}
+
+impl From<ExtendedCommunity> for [u8; 8] {
+    fn from(community: ExtendedCommunity) -> Self {
+        community.to_bytes()
+    }
+}
+
+impl FromStr for Community {
+    type Err = InvalidCommunityError;
+
+    /// Parses the "asn:value" notation or the name of a well-known community
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((community, _)) = WELL_KNOWN
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(s))
+        {
+            return Ok(*community);
+        }
+
+        let (asn, value) = s
+            .split_once(':')
+            .ok_or(InvalidCommunityError::InvalidFormat)?;
+        Ok(Community::new(parse_number(asn)?, parse_number(value)?))
+    }
+}
+
+impl FromStr for ExtendedCommunity {
+    type Err = InvalidCommunityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            if hex.len() != 16 {
+                return Err(InvalidCommunityError::InvalidFormat);
+            }
+
+            return u64::from_str_radix(hex, 16)
+                .map(|bits| ExtendedCommunity(bits.to_be_bytes()))
+                .map_err(InvalidCommunityError::InvalidNumber);
+        }
+
+        let (prefix, rest) = s
+            .split_once(':')
+            .ok_or(InvalidCommunityError::InvalidFormat)?;
+        let subtype = if prefix.eq_ignore_ascii_case("rt") {
+            ExtendedCommunity::ROUTE_TARGET
+        } else if prefix.eq_ignore_ascii_case("soo") {
+            ExtendedCommunity::ROUTE_ORIGIN
+        } else {
+            return Err(InvalidCommunityError::InvalidFormat);
+        };
+
+        let (global, local) = rest
+            .rsplit_once(':')
+            .ok_or(InvalidCommunityError::InvalidFormat)?;
+
+        if let Ok(address) = global.parse::<IPv4Address>() {
+            return Ok(ExtendedCommunity::ipv4_address(
+                subtype,
+                address,
+                parse_number(local)?,
+            ));
+        }
+
+        let asn: ASN = global
+            .parse()
+            .map_err(|_| InvalidCommunityError::InvalidFormat)?;
+        if asn.is_16bit() {
+            Ok(ExtendedCommunity::two_octet_as(
+                subtype,
+                asn.get() as u16,
+                parse_number(local)?,
+            ))
+        } else {
+            Ok(ExtendedCommunity::four_octet_as(
+                subtype,
+                asn,
+                parse_number(local)?,
+            ))
+        }
+    }
+}
+
+impl FromStr for LargeCommunity {
+    type Err = InvalidCommunityError;
+
+    /// Parses the "global:local1:local2" notation
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(':');
+        let mut next = || parts.next().ok_or(InvalidCommunityError::InvalidFormat);
+
+        let community = LargeCommunity {
+            global: parse_number(next()?)?,
+            local1: parse_number(next()?)?,
+            local2: parse_number(next()?)?,
+        };
+
+        if parts.next().is_some() {
+            return Err(InvalidCommunityError::InvalidFormat);
+        }
+
+        Ok(community)
+    }
+}
+
+impl std::fmt::Display for Community {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.asn(), self.value())
+    }
+}
+
+impl std::fmt::Debug for Community {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for ExtendedCommunity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bytes = &self.0;
+        let prefix = match self.notation() {
+            Some(prefix) => prefix,
+            None => return write!(f, "0x{:016X}", u64::from_be_bytes(*bytes)),
+        };
+
+        match self.kind() {
+            TYPE_TWO_OCTET_AS => write!(
+                f,
+                "{}:{}:{}",
+                prefix,
+                u16::from_be_bytes([bytes[2], bytes[3]]),
+                u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]])
+            ),
+            TYPE_IPV4_ADDRESS => write!(
+                f,
+                "{}:{}:{}",
+                prefix,
+                IPv4Address::new(bytes[2], bytes[3], bytes[4], bytes[5]),
+                u16::from_be_bytes([bytes[6], bytes[7]])
+            ),
+            _ => write!(
+                f,
+                "{}:{}:{}",
+                prefix,
+                u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]),
+                u16::from_be_bytes([bytes[6], bytes[7]])
+            ),
+        }
+    }
+}
+
+impl std::fmt::Debug for ExtendedCommunity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for LargeCommunity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.global, self.local1, self.local2)
+    }
+}
+
+impl std::fmt::Debug for LargeCommunity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Parses one decimal part of a community
+fn parse_number<T: FromStr<Err = std::num::ParseIntError>>(
+    s: &str,
+) -> Result<T, InvalidCommunityError> {
+    s.parse().map_err(InvalidCommunityError::InvalidNumber)
+}
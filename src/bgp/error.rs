@@ -0,0 +1,94 @@
+use crate::ErrorKind;
+use std::num::ParseIntError;
+
+/// An error while parsing an autonomous system number
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidASNError {
+    /// The number or one of its asdot halves could not be parsed
+    InvalidNumber(ParseIntError),
+}
+
+impl InvalidASNError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidASNError::InvalidNumber(_) => ErrorKind::InvalidNumber,
+        }
+    }
+}
+
+impl std::error::Error for InvalidASNError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidASNError::InvalidNumber(error) => Some(error),
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidASNError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidASNError::InvalidNumber(error) => write!(f, "invalid AS number - {}", error),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidASNError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// An error while parsing a BGP community
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidCommunityError {
+    /// The community does not have the expected parts
+    InvalidFormat,
+
+    /// A part of the community could not be parsed
+    InvalidNumber(ParseIntError),
+}
+
+impl InvalidCommunityError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidCommunityError::InvalidFormat => ErrorKind::InvalidFormat,
+            InvalidCommunityError::InvalidNumber(_) => ErrorKind::InvalidNumber,
+        }
+    }
+}
+
+impl std::error::Error for InvalidCommunityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidCommunityError::InvalidNumber(error) => Some(error),
+            InvalidCommunityError::InvalidFormat => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidCommunityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidCommunityError::InvalidFormat => write!(f, "invalid community format"),
+            InvalidCommunityError::InvalidNumber(error) => {
+                write!(f, "invalid community - {}", error)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidCommunityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,12 @@
+//! BGP utilities
+
+mod asn;
+mod community;
+mod error;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+
+pub use asn::ASN;
+pub use community::{Community, ExtendedCommunity, LargeCommunity};
+pub use error::{InvalidASNError, InvalidCommunityError};
@@ -0,0 +1,151 @@
+use super::PseudoHeader;
+use crate::ip::{v4::IPv4Address, v6::IPv6Address};
+
+/// Computes the Internet checksum of data given in pieces
+///
+/// Pieces may have any length. A piece with an odd length is continued by the next piece, so
+/// the result is the same as if all of the data were given at once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Checksum {
+    sum: u64,
+    pending: Option<u8>,
+}
+
+impl Checksum {
+    /// Creates a new [`Checksum`] with nothing added
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Checksum`]
+    pub const fn new() -> Self {
+        Checksum {
+            sum: 0,
+            pending: None,
+        }
+    }
+
+    /// Adds bytes to the checksum
+    ///
+    /// ## Parameters
+    ///  * `data` - The next bytes of the data
+    pub fn add_bytes(&mut self, mut data: &[u8]) {
+        if let Some(high) = self.pending.take() {
+            match data.split_first() {
+                Some((low, rest)) => {
+                    self.sum += u16::from_be_bytes([high, *low]) as u64;
+                    data = rest;
+                }
+                None => {
+                    self.pending = Some(high);
+                    return;
+                }
+            }
+        }
+
+        let mut words = data.chunks_exact(2);
+        for word in &mut words {
+            self.sum += u16::from_be_bytes([word[0], word[1]]) as u64;
+        }
+
+        self.pending = words.remainder().first().copied();
+    }
+
+    /// Adds a 16-bit word to the checksum
+    ///
+    /// ## Parameters
+    ///  * `word` - The word to add, which is added as two big-endian bytes
+    pub fn add_u16(&mut self, word: u16) {
+        self.add_bytes(&word.to_be_bytes());
+    }
+
+    /// Adds a 32-bit word to the checksum
+    ///
+    /// ## Parameters
+    ///  * `word` - The word to add, which is added as four big-endian bytes
+    pub fn add_u32(&mut self, word: u32) {
+        self.add_bytes(&word.to_be_bytes());
+    }
+
+    /// Adds the IPv4 pseudo-header used by the TCP and UDP checksums
+    ///
+    /// ## Parameters
+    ///  * `source` - The source address of the packet
+    ///  * `destination` - The destination address of the packet
+    ///  * `protocol` - The protocol number of the upper-layer header
+    ///  * `length` - The length of the upper-layer header and its data
+    pub fn add_ipv4_pseudo_header(
+        &mut self,
+        source: IPv4Address,
+        destination: IPv4Address,
+        protocol: u8,
+        length: u16,
+    ) {
+        self.add_bytes(&source.octets());
+        self.add_bytes(&destination.octets());
+        self.add_bytes(&[0, protocol]);
+        self.add_u16(length);
+    }
+
+    /// Adds the IPv6 pseudo-header from RFC 8200 used by upper-layer checksums
+    ///
+    /// ## Parameters
+    ///  * `source` - The source address of the packet
+    ///  * `destination` - The final destination address of the packet
+    ///  * `next_header` - The protocol number of the upper-layer header
+    ///  * `length` - The length of the upper-layer header and its data
+    pub fn add_ipv6_pseudo_header(
+        &mut self,
+        source: IPv6Address,
+        destination: IPv6Address,
+        next_header: u8,
+        length: u32,
+    ) {
+        self.add_bytes(&source.octets());
+        self.add_bytes(&destination.octets());
+        self.add_u32(length);
+        self.add_bytes(&[0, 0, 0, next_header]);
+    }
+
+    /// Adds the pseudo-header of either IP version used by upper-layer checksums
+    ///
+    /// ## Parameters
+    ///  * `header` - The addresses of the packet
+    ///  * `protocol` - The protocol number of the upper-layer header
+    ///  * `length` - The length of the upper-layer header and its data
+    pub fn add_pseudo_header(&mut self, header: &PseudoHeader, protocol: u8, length: u32) {
+        match *header {
+            PseudoHeader::IPv4 {
+                source,
+                destination,
+            } => self.add_ipv4_pseudo_header(source, destination, protocol, length as u16),
+            PseudoHeader::IPv6 {
+                source,
+                destination,
+            } => self.add_ipv6_pseudo_header(source, destination, protocol, length),
+        }
+    }
+
+    /// Gets the ones-complement sum of everything added so far, folded to 16 bits
+    ///
+    /// ## Return Value
+    /// Returns the sum before it is complemented
+    pub fn sum(&self) -> u16 {
+        let mut sum = self.sum;
+        if let Some(high) = self.pending {
+            sum += (high as u64) << 8;
+        }
+
+        while sum > 0xFFFF {
+            sum = (sum & 0xFFFF) + (sum >> 16);
+        }
+
+        sum as u16
+    }
+
+    /// Completes the checksum
+    ///
+    /// ## Return Value
+    /// Returns the checksum of everything added, padding an odd length with a zero byte
+    pub fn finish(&self) -> u16 {
+        !self.sum()
+    }
+}
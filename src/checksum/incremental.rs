@@ -0,0 +1,99 @@
+use crate::ip::{v4::IPv4Address, v6::IPv6Address};
+
+/// Updates a checksum after a 16-bit word of the data changes, using equation 3 of RFC 1624
+///
+/// ## Parameters
+///  * `checksum` - The checksum stored before the change
+///  * `old` - The word before the change
+///  * `new` - The word after the change
+///
+/// ## Return Value
+/// Returns the checksum of the changed data
+pub const fn update_u16(checksum: u16, old: u16, new: u16) -> u16 {
+    !fold(!checksum as u32 + !old as u32 + new as u32)
+}
+
+/// Updates a checksum after a 32-bit field of the data changes
+///
+/// ## Parameters
+///  * `checksum` - The checksum stored before the change
+///  * `old` - The field before the change
+///  * `new` - The field after the change
+///
+/// ## Return Value
+/// Returns the checksum of the changed data, which is correct if the field starts at an even
+/// offset
+pub const fn update_u32(checksum: u16, old: u32, new: u32) -> u16 {
+    let checksum = update_u16(checksum, (old >> 16) as u16, (new >> 16) as u16);
+    update_u16(checksum, old as u16, new as u16)
+}
+
+/// Updates a checksum after an IPv4 address in the data changes
+///
+/// ## Parameters
+///  * `checksum` - The checksum stored before the change
+///  * `old` - The address before the change
+///  * `new` - The address after the change
+///
+/// ## Return Value
+/// Returns the checksum of the changed data, which is correct if the address starts at an even
+/// offset
+pub const fn update_ipv4_address(checksum: u16, old: IPv4Address, new: IPv4Address) -> u16 {
+    update_u32(checksum, old.to_bits(), new.to_bits())
+}
+
+/// Updates a checksum after an IPv6 address in the data changes
+///
+/// ## Parameters
+///  * `checksum` - The checksum stored before the change
+///  * `old` - The address before the change
+///  * `new` - The address after the change
+///
+/// ## Return Value
+/// Returns the checksum of the changed data, which is correct if the address starts at an even
+/// offset
+pub const fn update_ipv6_address(checksum: u16, old: IPv6Address, new: IPv6Address) -> u16 {
+    let (old, new) = (old.segments(), new.segments());
+
+    let mut checksum = checksum;
+    let mut i = 0;
+    while i < 8 {
+        checksum = update_u16(checksum, old[i], new[i]);
+        i += 1;
+    }
+    checksum
+}
+
+/// Updates a checksum after a range of bytes in the data changes
+///
+/// ## Parameters
+///  * `checksum` - The checksum stored before the change
+///  * `old` - The bytes before the change
+///  * `new` - The bytes after the change, which must be as long as `old`
+///
+/// ## Return Value
+/// Returns the checksum of the changed data, which is correct if the bytes start at an even
+/// offset
+pub fn update_bytes(checksum: u16, old: &[u8], new: &[u8]) -> u16 {
+    assert!(old.len() == new.len());
+
+    old.chunks(2)
+        .zip(new.chunks(2))
+        .fold(checksum, |checksum, (old, new)| {
+            update_u16(checksum, word(old), word(new))
+        })
+}
+
+/// Folds the carries of a ones-complement sum back into its low 16 bits
+const fn fold(mut sum: u32) -> u16 {
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+
+    sum as u16
+}
+
+/// Gets the big-endian word starting `bytes`, padding a single byte with a zero
+fn word(bytes: &[u8]) -> u16 {
+    u16::from_be_bytes([bytes[0], bytes.get(1).copied().unwrap_or(0)])
+}
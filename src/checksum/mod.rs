@@ -0,0 +1,42 @@
+//! The Internet checksum from RFC 1071
+//!
+//! The checksum is the ones-complement of the ones-complement sum of the data taken as 16-bit
+//! big-endian words, as used by the IPv4, ICMP, TCP, and UDP headers. Data with an odd length is
+//! padded with a zero byte.
+//!
+//! When a single field such as an address or port is rewritten, the `update_*` functions adjust
+//! the stored checksum as described in RFC 1624 without reading the rest of the data.
+
+mod accumulator;
+mod incremental;
+mod pseudo_header;
+
+pub use accumulator::Checksum;
+pub use incremental::{
+    update_bytes, update_ipv4_address, update_ipv6_address, update_u16, update_u32,
+};
+pub use pseudo_header::PseudoHeader;
+
+/// Computes the Internet checksum of `data`
+///
+/// ## Parameters
+///  * `data` - The data to checksum, with any checksum field set to zero
+///
+/// ## Return Value
+/// Returns the checksum to store in the checksum field
+pub fn checksum(data: &[u8]) -> u16 {
+    let mut checksum = Checksum::new();
+    checksum.add_bytes(data);
+    checksum.finish()
+}
+
+/// Verifies the Internet checksum of `data`
+///
+/// ## Parameters
+///  * `data` - The data to verify, including its checksum field
+///
+/// ## Return Value
+/// Returns true if the checksum stored in `data` is correct
+pub fn verify(data: &[u8]) -> bool {
+    checksum(data) == 0
+}
@@ -0,0 +1,72 @@
+use crate::ip::{
+    v4::{IPv4Address, IPv4Header},
+    v6::{IPv6Address, IPv6Header},
+};
+
+/// The addresses of the IP header covered by an upper-layer checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PseudoHeader {
+    /// The addresses of an IPv4 packet
+    IPv4 {
+        /// The address the packet is sent from
+        source: IPv4Address,
+
+        /// The address the packet is sent to
+        destination: IPv4Address,
+    },
+
+    /// The addresses of an IPv6 packet
+    IPv6 {
+        /// The address the packet is sent from
+        source: IPv6Address,
+
+        /// The final address the packet is sent to
+        destination: IPv6Address,
+    },
+}
+
+impl PseudoHeader {
+    /// Checks if this pseudo-header is for an IPv4 packet
+    ///
+    /// ## Return Value
+    /// Returns true if this pseudo-header holds IPv4 addresses
+    pub const fn is_ipv4(&self) -> bool {
+        matches!(self, PseudoHeader::IPv4 { .. })
+    }
+}
+
+impl From<&IPv4Header> for PseudoHeader {
+    fn from(header: &IPv4Header) -> Self {
+        PseudoHeader::IPv4 {
+            source: header.source(),
+            destination: header.destination(),
+        }
+    }
+}
+
+impl From<&IPv6Header> for PseudoHeader {
+    fn from(header: &IPv6Header) -> Self {
+        PseudoHeader::IPv6 {
+            source: header.source(),
+            destination: header.destination(),
+        }
+    }
+}
+
+impl From<(IPv4Address, IPv4Address)> for PseudoHeader {
+    fn from((source, destination): (IPv4Address, IPv4Address)) -> Self {
+        PseudoHeader::IPv4 {
+            source,
+            destination,
+        }
+    }
+}
+
+impl From<(IPv6Address, IPv6Address)> for PseudoHeader {
+    fn from((source, destination): (IPv6Address, IPv6Address)) -> Self {
+        PseudoHeader::IPv6 {
+            source,
+            destination,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Serializes a [`DomainName`] in its ASCII form
+//!
+//! Use with `#[serde(with = "net_utils::dns::a_label")]` on a [`DomainName`] field to replace
+//! each Unicode label with its A-label, both when serializing and after deserializing, so
+//! "bücher.example" is always stored as "xn--bcher-kva.example".
+
+use super::{DomainName, LabelForm};
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+/// Serializes `name` with each label in its ASCII form
+///
+/// ## Parameters
+///  * `name` - The name to serialize
+///  * `serializer` - The serializer to write the name to
+///
+/// ## Return Value
+/// Returns the result of the serializer
+pub fn serialize<S: Serializer>(name: &DomainName, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&name.display(LabelForm::ASCII))
+}
+
+/// Deserializes a name and converts each label to its ASCII form
+///
+/// ## Parameters
+///  * `deserializer` - The deserializer to read the name from
+///
+/// ## Return Value
+/// Returns the deserialized name, or an error if it is invalid or too long once converted
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DomainName, D::Error> {
+    DomainName::deserialize(deserializer)?
+        .display(LabelForm::ASCII)
+        .to_string()
+        .parse()
+        .map_err(D::Error::custom)
+}
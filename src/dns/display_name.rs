@@ -0,0 +1,43 @@
+use super::{DomainName, LabelForm};
+
+/// Displays a [`DomainName`] with each label in a chosen [`LabelForm`]
+#[derive(Clone, Copy)]
+pub struct DisplayName<'a> {
+    name: &'a DomainName,
+    form: LabelForm,
+}
+
+impl<'a> DisplayName<'a> {
+    /// Creates a new [`DisplayName`]
+    pub(crate) fn new(name: &'a DomainName, form: LabelForm) -> Self {
+        DisplayName { name, form }
+    }
+}
+
+impl<'a> std::fmt::Display for DisplayName<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, label) in self.name.labels().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            match self.form {
+                LabelForm::AsWritten => f.write_str(&label)?,
+                LabelForm::ASCII => f.write_str(&label.to_ascii())?,
+                LabelForm::Unicode => f.write_str(&label.to_unicode())?,
+            }
+        }
+
+        if self.name.is_fqdn() {
+            f.write_str(".")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> std::fmt::Debug for DisplayName<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
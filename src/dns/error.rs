@@ -0,0 +1,255 @@
+use crate::ErrorKind;
+
+/// An error while parsing a domain name
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidDomainNameError {
+    /// The name has no labels
+    Empty,
+
+    /// A label between two dots is empty
+    EmptyLabel,
+
+    /// A label is longer than 63 bytes
+    LabelTooLong(usize),
+
+    /// The name is longer than 255 bytes in wire format
+    NameTooLong(usize),
+
+    /// A label contains a control or whitespace character
+    InvalidCharacter(char),
+}
+
+impl InvalidDomainNameError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidDomainNameError::Empty | InvalidDomainNameError::EmptyLabel => {
+                ErrorKind::Missing
+            }
+            InvalidDomainNameError::LabelTooLong(_) | InvalidDomainNameError::NameTooLong(_) => {
+                ErrorKind::TooLong
+            }
+            InvalidDomainNameError::InvalidCharacter(_) => ErrorKind::InvalidCharacter,
+        }
+    }
+}
+
+impl std::error::Error for InvalidDomainNameError {}
+
+impl std::fmt::Display for InvalidDomainNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidDomainNameError::Empty => write!(f, "empty domain name"),
+            InvalidDomainNameError::EmptyLabel => write!(f, "empty label"),
+            InvalidDomainNameError::LabelTooLong(length) => {
+                write!(f, "label length {} exceeds the maximum of 63", length)
+            }
+            InvalidDomainNameError::NameTooLong(length) => {
+                write!(f, "name length {} exceeds the maximum of 255", length)
+            }
+            InvalidDomainNameError::InvalidCharacter(c) => write!(f, "invalid character {:?}", c),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidDomainNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// An error while parsing a host name
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidHostnameError {
+    /// The host name is empty
+    Empty,
+
+    /// A label between two dots is empty
+    EmptyLabel,
+
+    /// A label is longer than 63 characters
+    LabelTooLong(usize),
+
+    /// The host name is longer than 253 characters
+    NameTooLong(usize),
+
+    /// A label contains a character other than a letter, digit, or hyphen
+    InvalidCharacter(char),
+
+    /// A label starts or ends with a hyphen
+    HyphenAtEdge,
+
+    /// The right-most label is entirely numeric
+    NumericTopLevel,
+}
+
+impl InvalidHostnameError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidHostnameError::Empty | InvalidHostnameError::EmptyLabel => ErrorKind::Missing,
+            InvalidHostnameError::LabelTooLong(_) | InvalidHostnameError::NameTooLong(_) => {
+                ErrorKind::TooLong
+            }
+            InvalidHostnameError::InvalidCharacter(_) | InvalidHostnameError::HyphenAtEdge => {
+                ErrorKind::InvalidCharacter
+            }
+            InvalidHostnameError::NumericTopLevel => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidHostnameError {}
+
+impl std::fmt::Display for InvalidHostnameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidHostnameError::Empty => write!(f, "empty host name"),
+            InvalidHostnameError::EmptyLabel => write!(f, "empty label"),
+            InvalidHostnameError::LabelTooLong(length) => {
+                write!(f, "label length {} exceeds the maximum of 63", length)
+            }
+            InvalidHostnameError::NameTooLong(length) => {
+                write!(f, "host name length {} exceeds the maximum of 253", length)
+            }
+            InvalidHostnameError::InvalidCharacter(c) => write!(f, "invalid character {:?}", c),
+            InvalidHostnameError::HyphenAtEdge => write!(f, "label starts or ends with '-'"),
+            InvalidHostnameError::NumericTopLevel => write!(f, "top-level label is numeric"),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidHostnameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// A name is not valid in the position a DNS record places it
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidRecordNameError {
+    /// The name does not start with a "_service" label
+    MissingServiceLabel,
+
+    /// The service label is not followed by a "_proto" label
+    MissingProtocolLabel,
+
+    /// There is no name after the service and protocol labels
+    MissingName,
+
+    /// The name is an IP address rather than a host name
+    IPAddress,
+
+    /// The name contains an underscore
+    Underscore,
+
+    /// The name is not a valid host name
+    InvalidHostname(InvalidHostnameError),
+}
+
+impl InvalidRecordNameError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidRecordNameError::MissingServiceLabel
+            | InvalidRecordNameError::MissingProtocolLabel
+            | InvalidRecordNameError::MissingName => ErrorKind::Missing,
+            InvalidRecordNameError::IPAddress => ErrorKind::InvalidFormat,
+            InvalidRecordNameError::Underscore => ErrorKind::InvalidCharacter,
+            InvalidRecordNameError::InvalidHostname(error) => error.kind(),
+        }
+    }
+}
+
+impl std::error::Error for InvalidRecordNameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidRecordNameError::InvalidHostname(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidRecordNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidRecordNameError::MissingServiceLabel => write!(f, "missing \"_service\" label"),
+            InvalidRecordNameError::MissingProtocolLabel => {
+                write!(f, "missing \"_proto\" label")
+            }
+            InvalidRecordNameError::MissingName => write!(f, "missing name after protocol"),
+            InvalidRecordNameError::IPAddress => write!(f, "target is an IP address"),
+            InvalidRecordNameError::Underscore => write!(f, "target contains '_'"),
+            InvalidRecordNameError::InvalidHostname(error) => {
+                write!(f, "target is not a host name - {}", error)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidRecordNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// An error while converting between a reverse-mapping name and the addresses it covers
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidReverseNameError {
+    /// The name is not in the "in-addr.arpa" or "ip6.arpa" trees
+    NotReverseName,
+
+    /// A label is not a valid octet, nibble, or RFC 2317 range
+    InvalidLabel,
+
+    /// The prefix length can not be represented by a reverse name
+    UnalignedPrefix(u8),
+}
+
+impl InvalidReverseNameError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidReverseNameError::NotReverseName | InvalidReverseNameError::InvalidLabel => {
+                ErrorKind::InvalidFormat
+            }
+            InvalidReverseNameError::UnalignedPrefix(_) => ErrorKind::Unsupported,
+        }
+    }
+}
+
+impl std::error::Error for InvalidReverseNameError {}
+
+impl std::fmt::Display for InvalidReverseNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidReverseNameError::NotReverseName => write!(f, "not a reverse-mapping name"),
+            InvalidReverseNameError::InvalidLabel => write!(f, "invalid reverse-mapping label"),
+            InvalidReverseNameError::UnalignedPrefix(prefix) => {
+                write!(f, "prefix /{} has no reverse-mapping name", prefix)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidReverseNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,25 @@
+use super::DomainName;
+use crate::fuzzing::string;
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+/// The characters labels are made from, including some which are rare in host names
+const LABEL_CHARS: &[&str] = &[
+    "a", "b", "c", "x", "y", "z", "A", "Z", "0", "1", "9", "-", "_", "*", "é", "中",
+];
+
+impl<'a> Arbitrary<'a> for DomainName {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let count = u.int_in_range(0..=8)?;
+        if count == 0 {
+            return Ok(DomainName::root());
+        }
+
+        let mut labels = Vec::with_capacity(count);
+        for _ in 0..count {
+            labels.push(string(u, LABEL_CHARS, 1..=DomainName::MAX_LABEL_LENGTH)?);
+        }
+
+        let name = DomainName::from_labels(labels).map_err(|_| Error::IncorrectFormat)?;
+        Ok(if u.arbitrary()? { name.to_fqdn() } else { name })
+    }
+}
@@ -0,0 +1,138 @@
+use super::{DomainName, InvalidHostnameError};
+use std::{hash::Hash, str::FromStr};
+
+/// A host name following RFC 1123
+///
+/// Every label must contain only ASCII letters, digits, and hyphens, and can not start or end
+/// with a hyphen. The right-most label can not be entirely numeric so a host name is never
+/// confused with an IPv4 address. Host names compare and hash ignoring ASCII case.
+#[derive(Clone)]
+pub struct Hostname {
+    name: String,
+}
+
+impl Hostname {
+    /// The maximum length of a single label
+    pub const MAX_LABEL_LENGTH: usize = 63;
+
+    /// The maximum length of a host name
+    pub const MAX_LENGTH: usize = 253;
+
+    /// Gets the text of this host name
+    ///
+    /// ## Return Value
+    /// Returns this host name as written
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the labels of this host name
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the labels, starting with the left-most
+    pub fn labels(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.name.split('.')
+    }
+}
+
+/// Checks that `label` is a valid host name label
+fn validate_label(label: &str) -> Result<(), InvalidHostnameError> {
+    if label.is_empty() {
+        return Err(InvalidHostnameError::EmptyLabel);
+    }
+
+    if label.len() > Hostname::MAX_LABEL_LENGTH {
+        return Err(InvalidHostnameError::LabelTooLong(label.len()));
+    }
+
+    if let Some(c) = label
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '-'))
+    {
+        return Err(InvalidHostnameError::InvalidCharacter(c));
+    }
+
+    if label.starts_with('-') || label.ends_with('-') {
+        return Err(InvalidHostnameError::HyphenAtEdge);
+    }
+
+    Ok(())
+}
+
+impl FromStr for Hostname {
+    type Err = InvalidHostnameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(InvalidHostnameError::Empty);
+        }
+
+        if s.len() > Hostname::MAX_LENGTH {
+            return Err(InvalidHostnameError::NameTooLong(s.len()));
+        }
+
+        for label in s.split('.') {
+            validate_label(label)?;
+        }
+
+        if s.rsplit('.')
+            .next()
+            .unwrap()
+            .bytes()
+            .all(|byte| byte.is_ascii_digit())
+        {
+            return Err(InvalidHostnameError::NumericTopLevel);
+        }
+
+        Ok(Hostname { name: s.to_owned() })
+    }
+}
+
+impl TryFrom<&DomainName> for Hostname {
+    type Error = InvalidHostnameError;
+
+    fn try_from(name: &DomainName) -> Result<Self, Self::Error> {
+        name.strip_root().to_string().parse()
+    }
+}
+
+impl From<&Hostname> for DomainName {
+    fn from(hostname: &Hostname) -> Self {
+        DomainName::from_labels(hostname.labels()).unwrap()
+    }
+}
+
+impl PartialEq for Hostname {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl Eq for Hostname {}
+
+impl Hash for Hostname {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for byte in self.name.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        state.write_u8(0xFF);
+    }
+}
+
+impl AsRef<str> for Hostname {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Display for Hostname {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl std::fmt::Debug for Hostname {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
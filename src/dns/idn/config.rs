@@ -0,0 +1,121 @@
+/// Settings controlling how UTS #46 processes a name when converting between its ASCII and
+/// Unicode forms
+///
+/// The defaults match [`DomainName::to_ascii`](super::DomainName::to_ascii): non-transitional
+/// processing with every optional check disabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IDNAConfig {
+    transitional: bool,
+    std3_ascii_rules: bool,
+    check_hyphens: bool,
+    verify_dns_length: bool,
+}
+
+impl IDNAConfig {
+    /// Creates a new [`IDNAConfig`] using non-transitional processing with no optional checks
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`IDNAConfig`]
+    pub const fn new() -> Self {
+        IDNAConfig {
+            transitional: false,
+            std3_ascii_rules: false,
+            check_hyphens: false,
+            verify_dns_length: false,
+        }
+    }
+
+    /// Sets if transitional processing is used
+    ///
+    /// Transitional processing maps deviation characters the way IDNA 2003 did, so "ß" becomes
+    /// "ss" and "ς" becomes "σ", which some registries and legacy systems still expect.
+    ///
+    /// ## Parameters
+    ///  * `transitional` - True to use transitional processing
+    ///
+    /// ## Return Value
+    /// Returns the updated [`IDNAConfig`]
+    pub const fn with_transitional(mut self, transitional: bool) -> Self {
+        self.transitional = transitional;
+        self
+    }
+
+    /// Sets if the STD3 ASCII rules are applied, limiting ASCII characters to letters, digits,
+    /// and hyphens
+    ///
+    /// ## Parameters
+    ///  * `std3_ascii_rules` - True to reject other ASCII characters, such as '_'
+    ///
+    /// ## Return Value
+    /// Returns the updated [`IDNAConfig`]
+    pub const fn with_std3_ascii_rules(mut self, std3_ascii_rules: bool) -> Self {
+        self.std3_ascii_rules = std3_ascii_rules;
+        self
+    }
+
+    /// Sets if labels are checked for hyphens at the start, at the end, or in the third and
+    /// fourth positions
+    ///
+    /// ## Parameters
+    ///  * `check_hyphens` - True to reject labels with misplaced hyphens
+    ///
+    /// ## Return Value
+    /// Returns the updated [`IDNAConfig`]
+    pub const fn with_check_hyphens(mut self, check_hyphens: bool) -> Self {
+        self.check_hyphens = check_hyphens;
+        self
+    }
+
+    /// Sets if the ASCII form is checked against the DNS length limits
+    ///
+    /// ## Parameters
+    ///  * `verify_dns_length` - True to reject names whose ASCII form is too long
+    ///
+    /// ## Return Value
+    /// Returns the updated [`IDNAConfig`]
+    pub const fn with_verify_dns_length(mut self, verify_dns_length: bool) -> Self {
+        self.verify_dns_length = verify_dns_length;
+        self
+    }
+
+    /// Checks if transitional processing is used
+    ///
+    /// ## Return Value
+    /// Returns true if deviation characters are mapped
+    pub const fn transitional(&self) -> bool {
+        self.transitional
+    }
+
+    /// Checks if the STD3 ASCII rules are applied
+    ///
+    /// ## Return Value
+    /// Returns true if ASCII characters are limited to letters, digits, and hyphens
+    pub const fn std3_ascii_rules(&self) -> bool {
+        self.std3_ascii_rules
+    }
+
+    /// Checks if labels are checked for misplaced hyphens
+    ///
+    /// ## Return Value
+    /// Returns true if hyphens are checked
+    pub const fn check_hyphens(&self) -> bool {
+        self.check_hyphens
+    }
+
+    /// Checks if the ASCII form is checked against the DNS length limits
+    ///
+    /// ## Return Value
+    /// Returns true if lengths are verified
+    pub const fn verify_dns_length(&self) -> bool {
+        self.verify_dns_length
+    }
+
+    /// Creates the equivalent configuration for the `idna` crate
+    pub(crate) fn to_idna(self) -> ::idna::Config {
+        ::idna::Config::default()
+            .transitional_processing(self.transitional)
+            .use_std3_ascii_rules(self.std3_ascii_rules)
+            .check_hyphens(self.check_hyphens)
+            .verify_dns_length(self.verify_dns_length)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{dns::InvalidDomainNameError, ErrorKind};
+
+/// An error while converting a domain name between its ASCII and Unicode forms
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IDNAError {
+    /// IDNA processing rejected the name, with the description of why
+    Processing(String),
+
+    /// The converted name is not a valid domain name
+    InvalidName(InvalidDomainNameError),
+}
+
+impl From<InvalidDomainNameError> for IDNAError {
+    fn from(error: InvalidDomainNameError) -> Self {
+        IDNAError::InvalidName(error)
+    }
+}
+
+impl IDNAError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            IDNAError::Processing(_) => ErrorKind::InvalidCharacter,
+            IDNAError::InvalidName(error) => error.kind(),
+        }
+    }
+}
+
+impl std::error::Error for IDNAError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IDNAError::InvalidName(error) => Some(error),
+            IDNAError::Processing(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for IDNAError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IDNAError::Processing(error) => write!(f, "IDNA processing failed - {}", error),
+            IDNAError::InvalidName(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::fmt::Debug for IDNAError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,68 @@
+use super::DomainName;
+
+mod config;
+mod error;
+
+pub use config::IDNAConfig;
+pub use error::IDNAError;
+
+impl DomainName {
+    /// Converts this name to its ASCII form, replacing each Unicode label with its A-label
+    ///
+    /// Processing follows UTS #46 with the non-transitional rules, so "bücher.example" becomes
+    /// "xn--bcher-kva.example".
+    ///
+    /// ## Return Value
+    /// Returns the ASCII form of this name
+    pub fn to_ascii(&self) -> Result<DomainName, IDNAError> {
+        self.to_ascii_with(&IDNAConfig::new())
+    }
+
+    /// Converts this name to its ASCII form following the UTS #46 options in `config`
+    ///
+    /// ## Parameters
+    ///  * `config` - The processing options to use
+    ///
+    /// ## Return Value
+    /// Returns the ASCII form of this name
+    pub fn to_ascii_with(&self, config: &IDNAConfig) -> Result<DomainName, IDNAError> {
+        self.convert(config.to_idna().to_ascii(&self.relative_string()))
+    }
+
+    /// Converts this name to its Unicode form, replacing each A-label with its U-label
+    ///
+    /// ## Return Value
+    /// Returns the Unicode form of this name
+    pub fn to_unicode(&self) -> Result<DomainName, IDNAError> {
+        self.to_unicode_with(&IDNAConfig::new())
+    }
+
+    /// Converts this name to its Unicode form following the UTS #46 options in `config`
+    ///
+    /// ## Parameters
+    ///  * `config` - The processing options to use
+    ///
+    /// ## Return Value
+    /// Returns the Unicode form of this name
+    pub fn to_unicode_with(&self, config: &IDNAConfig) -> Result<DomainName, IDNAError> {
+        let (name, result) = config.to_idna().to_unicode(&self.relative_string());
+        self.convert(result.map(|_| name))
+    }
+
+    /// Gets this name as text without the trailing root
+    fn relative_string(&self) -> String {
+        self.strip_root().to_string()
+    }
+
+    /// Parses the result of a conversion, keeping whether this name is fully qualified
+    fn convert(&self, result: Result<String, ::idna::Errors>) -> Result<DomainName, IDNAError> {
+        if self.is_root() {
+            return Ok(self.clone());
+        }
+
+        let name: DomainName = result
+            .map_err(|error| IDNAError::Processing(error.to_string()))?
+            .parse()?;
+        Ok(if self.is_fqdn() { name.to_fqdn() } else { name })
+    }
+}
@@ -0,0 +1,94 @@
+use crate::punycode::{decode_label, encode_label};
+use std::{borrow::Cow, ops::Deref};
+
+/// The prefix marking a label as Punycode encoded
+const ACE_PREFIX: &str = "xn--";
+
+/// A single label of a [`DomainName`](super::DomainName)
+///
+/// A label can be viewed as an A-label, its ASCII form, or as a U-label, its Unicode form. The
+/// views only apply Punycode and do not perform IDNA mapping.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Label<'a> {
+    text: &'a str,
+}
+
+impl<'a> Label<'a> {
+    /// Creates a new [`Label`]
+    pub(crate) fn new(text: &'a str) -> Self {
+        Label { text }
+    }
+
+    /// Gets the text of this label
+    ///
+    /// ## Return Value
+    /// Returns this label as it was written
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Checks if this label is an A-label, a Punycode encoded label starting with "xn--"
+    ///
+    /// ## Return Value
+    /// Returns true if this label starts with "xn--"
+    pub fn is_a_label(&self) -> bool {
+        self.text.len() > ACE_PREFIX.len()
+            && self.text.as_bytes()[..ACE_PREFIX.len()].eq_ignore_ascii_case(ACE_PREFIX.as_bytes())
+    }
+
+    /// Gets the ASCII form of this label
+    ///
+    /// ## Return Value
+    /// Returns this label if it is ASCII, otherwise the "xn--" prefixed Punycode encoding of it
+    pub fn to_ascii(&self) -> Cow<'a, str> {
+        if self.text.is_ascii() {
+            return Cow::Borrowed(self.text);
+        }
+
+        match encode_label(self.text) {
+            Ok(encoded) => Cow::Owned(format!("{}{}", ACE_PREFIX, encoded)),
+            Err(_) => Cow::Borrowed(self.text),
+        }
+    }
+
+    /// Gets the Unicode form of this label
+    ///
+    /// ## Return Value
+    /// Returns the decoded form of this label if it is a valid A-label, otherwise this label
+    pub fn to_unicode(&self) -> Cow<'a, str> {
+        if !self.is_a_label() {
+            return Cow::Borrowed(self.text);
+        }
+
+        match decode_label(&self.text[ACE_PREFIX.len()..]) {
+            Ok(decoded) => Cow::Owned(decoded),
+            Err(_) => Cow::Borrowed(self.text),
+        }
+    }
+}
+
+impl<'a> Deref for Label<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.text
+    }
+}
+
+impl<'a> AsRef<str> for Label<'a> {
+    fn as_ref(&self) -> &str {
+        self.text
+    }
+}
+
+impl<'a> std::fmt::Display for Label<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+impl<'a> std::fmt::Debug for Label<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
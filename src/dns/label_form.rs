@@ -0,0 +1,13 @@
+/// The form each label of a domain name is displayed in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LabelForm {
+    /// Each label as it was written
+    #[default]
+    AsWritten,
+
+    /// Each label as an A-label, for protocols and the DNS wire format
+    ASCII,
+
+    /// Each label as a U-label, for showing to users
+    Unicode,
+}
@@ -0,0 +1,49 @@
+//! DNS utilities
+
+mod display_name;
+mod error;
+mod hostname;
+mod label;
+mod label_form;
+mod name;
+mod reverse;
+mod sd;
+mod set;
+mod validate;
+mod wire;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "idna")]
+mod idn;
+#[cfg(feature = "psl")]
+mod psl;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "proptest")]
+mod strategy;
+
+#[cfg(feature = "serde")]
+pub mod a_label;
+
+pub use display_name::DisplayName;
+pub use error::{
+    InvalidDomainNameError, InvalidHostnameError, InvalidRecordNameError, InvalidReverseNameError,
+};
+pub use hostname::Hostname;
+pub use label::Label;
+pub use label_form::LabelForm;
+pub use name::DomainName;
+pub use reverse::ReverseName;
+pub use sd::{InvalidServiceNameError, ServiceName, ServiceProtocol};
+pub use set::DomainSet;
+pub use wire::{CompressionMap, InvalidWireNameError};
+
+#[cfg(feature = "idna")]
+pub use idn::{IDNAConfig, IDNAError};
+#[cfg(feature = "psl")]
+pub use psl::PublicSuffixList;
+#[cfg(feature = "rand")]
+pub use random::NameGenerator;
@@ -0,0 +1,389 @@
+use super::{DisplayName, InvalidDomainNameError, Label, LabelForm};
+use std::{cmp::Ordering, hash::Hash, str::FromStr};
+
+/// A domain name following the length limits of RFC 1035
+///
+/// Labels may hold any characters other than '.', control characters, and whitespace, so names
+/// like "_acme-challenge.example.com" are accepted.
+///
+/// A name written with a trailing '.' is fully qualified (FQDN), ending at the root, while a name
+/// without one is relative. The two forms are kept distinct.
+///
+/// Names compare and hash ignoring ASCII case, following the DNS rules, but keep the case they
+/// were written in. Names are ordered following the canonical DNS name order from RFC 4034.
+#[derive(Clone)]
+pub struct DomainName {
+    labels: Vec<String>,
+    fqdn: bool,
+}
+
+impl DomainName {
+    /// The maximum length of a single label in bytes
+    pub const MAX_LABEL_LENGTH: usize = 63;
+
+    /// The maximum length of a name in wire format, including the length bytes and the root label
+    pub const MAX_LENGTH: usize = 255;
+
+    /// Creates the root domain name, "."
+    ///
+    /// ## Return Value
+    /// Returns the root [`DomainName`]
+    pub const fn root() -> Self {
+        DomainName {
+            labels: Vec::new(),
+            fqdn: true,
+        }
+    }
+
+    /// Creates a new relative [`DomainName`] from its labels
+    ///
+    /// ## Parameters
+    ///  * `labels` - The labels of the name, starting with the left-most
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`DomainName`] if the labels are valid
+    pub fn from_labels<I: IntoIterator<Item = S>, S: Into<String>>(
+        labels: I,
+    ) -> Result<Self, InvalidDomainNameError> {
+        let labels: Vec<String> = labels.into_iter().map(Into::into).collect();
+        if labels.is_empty() {
+            return Err(InvalidDomainNameError::Empty);
+        }
+
+        let mut length = 1;
+        for label in &labels {
+            validate_label(label)?;
+            length += label.len() + 1;
+        }
+
+        if length > DomainName::MAX_LENGTH {
+            return Err(InvalidDomainNameError::NameTooLong(length));
+        }
+
+        Ok(DomainName {
+            labels,
+            fqdn: false,
+        })
+    }
+
+    /// Gets the labels of this name
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the labels, starting with the left-most
+    pub fn labels(&self) -> impl DoubleEndedIterator<Item = Label<'_>> + ExactSizeIterator {
+        self.labels.iter().map(|label| Label::new(label))
+    }
+
+    /// Displays this name with each label in `form`
+    ///
+    /// ## Parameters
+    ///  * `form` - The form to display each label in
+    ///
+    /// ## Return Value
+    /// Returns a value implementing [`Display`](std::fmt::Display) for this name
+    pub fn display(&self, form: LabelForm) -> DisplayName<'_> {
+        DisplayName::new(self, form)
+    }
+
+    /// Gets the number of labels in this name
+    ///
+    /// ## Return Value
+    /// Returns the number of labels, not counting the root
+    pub fn label_count(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Checks if this name is fully qualified
+    ///
+    /// ## Return Value
+    /// Returns true if this name ends at the root
+    pub fn is_fqdn(&self) -> bool {
+        self.fqdn
+    }
+
+    /// Checks if this name is the root
+    ///
+    /// ## Return Value
+    /// Returns true if this name has no labels
+    pub fn is_root(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Makes this name fully qualified
+    ///
+    /// ## Return Value
+    /// Returns this name ending at the root
+    pub fn to_fqdn(&self) -> Self {
+        DomainName {
+            labels: self.labels.clone(),
+            fqdn: true,
+        }
+    }
+
+    /// Makes this name relative by removing the root
+    ///
+    /// ## Return Value
+    /// Returns this name without the trailing '.', or the root itself as it has no labels to
+    /// keep
+    pub fn strip_root(&self) -> Self {
+        DomainName {
+            labels: self.labels.clone(),
+            fqdn: self.labels.is_empty(),
+        }
+    }
+
+    /// Gets the name directly above this one
+    ///
+    /// ## Return Value
+    /// Returns this name without its left-most label, or [`None`] for the root and for a relative
+    /// name with a single label
+    pub fn parent(&self) -> Option<Self> {
+        if self.labels.is_empty() || (self.labels.len() == 1 && !self.fqdn) {
+            return None;
+        }
+
+        Some(DomainName {
+            labels: self.labels[1..].to_vec(),
+            fqdn: self.fqdn,
+        })
+    }
+
+    /// Gets the top-level label of this name
+    ///
+    /// This only looks at the labels, so the top-level label of "www.example.co.uk" is "uk". Use
+    /// the Public Suffix List to find multi-label suffixes.
+    ///
+    /// ## Return Value
+    /// Returns the right-most label, or [`None`] for the root
+    pub fn tld(&self) -> Option<Label<'_>> {
+        self.labels.last().map(|label| Label::new(label))
+    }
+
+    /// Gets the second-level label of this name
+    ///
+    /// ## Return Value
+    /// Returns the label directly to the left of the top-level label, or [`None`] if this name
+    /// has fewer than two labels
+    pub fn sld(&self) -> Option<Label<'_>> {
+        self.labels
+            .len()
+            .checked_sub(2)
+            .map(|i| Label::new(&self.labels[i]))
+    }
+
+    /// Gets the name made from the right-most `count` labels of this name
+    ///
+    /// The suffix is fully qualified if this name is, so "www.example.com." has the suffix
+    /// "example.com." for a `count` of 2.
+    ///
+    /// ## Parameters
+    ///  * `count` - The number of labels to keep
+    ///
+    /// ## Return Value
+    /// Returns the suffix, or [`None`] if this name has fewer than `count` labels or `count` is
+    /// zero for a relative name
+    pub fn suffix(&self, count: usize) -> Option<Self> {
+        if count > self.labels.len() || (count == 0 && !self.fqdn) {
+            return None;
+        }
+
+        Some(DomainName {
+            labels: self.labels[self.labels.len() - count..].to_vec(),
+            fqdn: self.fqdn,
+        })
+    }
+
+    /// Gets every name above this one
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the ancestors of this name, starting with its parent and ending
+    /// with the root for a fully qualified name
+    pub fn ancestors(&self) -> impl Iterator<Item = DomainName> {
+        std::iter::successors(self.parent(), DomainName::parent)
+    }
+
+    /// Checks if this name is equal to or below `other`
+    ///
+    /// The trailing root is ignored, so "www.example.com" is a subdomain of "example.com.".
+    ///
+    /// ## Parameters
+    ///  * `other` - The potential ancestor
+    ///
+    /// ## Return Value
+    /// Returns true if the right-most labels of this name match every label of `other`
+    pub fn is_subdomain_of(&self, other: &DomainName) -> bool {
+        self.labels.len() >= other.labels.len()
+            && self
+                .labels
+                .iter()
+                .rev()
+                .zip(other.labels.iter().rev())
+                .all(|(a, b)| a.eq_ignore_ascii_case(b))
+    }
+
+    /// Checks if this name matches a certificate-style wildcard `pattern` following RFC 6125
+    ///
+    /// A wildcard may only appear as the entire left-most label, where it matches exactly one
+    /// label. Partial-label wildcards such as "w*.example.com" never match, and neither do
+    /// wildcards directly above a top-level domain such as "*.com". A pattern without a wildcard
+    /// must equal this name. Case and the trailing root are ignored.
+    ///
+    /// ## Parameters
+    ///  * `pattern` - The pattern, such as "*.example.com"
+    ///
+    /// ## Return Value
+    /// Returns true if this name matches `pattern`
+    pub fn matches_wildcard(&self, pattern: &str) -> bool {
+        let pattern = pattern.strip_suffix('.').unwrap_or(pattern);
+        let pattern: Vec<&str> = pattern.split('.').collect();
+        if pattern.len() != self.labels.len() {
+            return false;
+        }
+
+        let (first, rest) = pattern.split_first().unwrap();
+        if rest.iter().any(|label| label.contains('*')) {
+            return false;
+        }
+
+        let first_matches = if first.contains('*') {
+            *first == "*" && rest.len() >= 2
+        } else {
+            first.eq_ignore_ascii_case(&self.labels[0])
+        };
+
+        first_matches
+            && rest
+                .iter()
+                .zip(&self.labels[1..])
+                .all(|(a, b)| a.eq_ignore_ascii_case(b))
+    }
+
+    /// Compares this name with `other` in the canonical DNS name order from RFC 4034 section 6.1
+    ///
+    /// Names are compared label by label starting from the right-most, with each label compared
+    /// as bytes after lowercasing ASCII letters. A name sorts before any of its subdomains. The
+    /// trailing root is ignored.
+    ///
+    /// ## Parameters
+    ///  * `other` - The name to compare against
+    ///
+    /// ## Return Value
+    /// Returns the ordering of this name relative to `other`
+    pub fn canonical_cmp(&self, other: &DomainName) -> Ordering {
+        for (a, b) in self.labels.iter().rev().zip(other.labels.iter().rev()) {
+            let a = a.bytes().map(|byte| byte.to_ascii_lowercase());
+            let b = b.bytes().map(|byte| byte.to_ascii_lowercase());
+
+            match a.cmp(b) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+
+        self.labels.len().cmp(&other.labels.len())
+    }
+}
+
+/// Checks that `label` is a valid label
+fn validate_label(label: &str) -> Result<(), InvalidDomainNameError> {
+    if label.is_empty() {
+        return Err(InvalidDomainNameError::EmptyLabel);
+    }
+
+    if label.len() > DomainName::MAX_LABEL_LENGTH {
+        return Err(InvalidDomainNameError::LabelTooLong(label.len()));
+    }
+
+    match label
+        .chars()
+        .find(|c| c.is_control() || c.is_whitespace() || *c == '.')
+    {
+        Some(c) => Err(InvalidDomainNameError::InvalidCharacter(c)),
+        None => Ok(()),
+    }
+}
+
+impl PartialEq for DomainName {
+    fn eq(&self, other: &Self) -> bool {
+        self.fqdn == other.fqdn
+            && self.labels.len() == other.labels.len()
+            && self
+                .labels
+                .iter()
+                .zip(&other.labels)
+                .all(|(a, b)| a.eq_ignore_ascii_case(b))
+    }
+}
+
+impl Eq for DomainName {}
+
+impl PartialOrd for DomainName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DomainName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.canonical_cmp(other).then(self.fqdn.cmp(&other.fqdn))
+    }
+}
+
+impl Hash for DomainName {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for label in &self.labels {
+            state.write_u8(label.len() as u8);
+            for byte in label.bytes() {
+                state.write_u8(byte.to_ascii_lowercase());
+            }
+        }
+        state.write_u8(0xFF);
+        self.fqdn.hash(state);
+    }
+}
+
+impl FromStr for DomainName {
+    type Err = InvalidDomainNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "." {
+            return Ok(DomainName::root());
+        }
+
+        let (relative, fqdn) = match s.strip_suffix('.') {
+            Some(relative) => (relative, true),
+            None => (s, false),
+        };
+
+        if relative.is_empty() {
+            return Err(InvalidDomainNameError::Empty);
+        }
+
+        let mut name = DomainName::from_labels(relative.split('.'))?;
+        name.fqdn = fqdn;
+        Ok(name)
+    }
+}
+
+impl std::fmt::Display for DomainName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, label) in self.labels.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(label)?;
+        }
+
+        if self.fqdn {
+            f.write_str(".")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for DomainName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
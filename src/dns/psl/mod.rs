@@ -0,0 +1,156 @@
+use super::DomainName;
+use crate::punycode::encode_label;
+use std::collections::HashSet;
+
+/// The Public Suffix List, used to find the registrable part of a domain name
+///
+/// Rules written with Unicode labels are also matched against their A-label forms.
+#[derive(Debug, Clone, Default)]
+pub struct PublicSuffixList {
+    rules: HashSet<String>,
+    wildcards: HashSet<String>,
+    exceptions: HashSet<String>,
+}
+
+impl PublicSuffixList {
+    /// Parses a list in the format of "public_suffix_list.dat"
+    ///
+    /// ## Parameters
+    ///  * `list` - The text of the list
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`PublicSuffixList`]
+    pub fn parse(list: &str) -> Self {
+        let mut psl = PublicSuffixList::default();
+
+        for line in list.lines() {
+            let rule = match line.split_whitespace().next() {
+                Some(rule) if !rule.starts_with("//") => rule.to_lowercase(),
+                _ => continue,
+            };
+
+            for rule in [ascii_form(&rule), Some(rule)].into_iter().flatten() {
+                if let Some(exception) = rule.strip_prefix('!') {
+                    psl.exceptions.insert(exception.to_owned());
+                } else if let Some(parent) = rule.strip_prefix("*.") {
+                    psl.wildcards.insert(parent.to_owned());
+                } else {
+                    psl.rules.insert(rule);
+                }
+            }
+        }
+
+        psl
+    }
+
+    /// Gets the copy of the list embedded in this library
+    ///
+    /// ## Return Value
+    /// Returns the embedded [`PublicSuffixList`], which is parsed on first use
+    #[cfg(feature = "psl-embedded")]
+    pub fn embedded() -> &'static PublicSuffixList {
+        static EMBEDDED: std::sync::OnceLock<PublicSuffixList> = std::sync::OnceLock::new();
+
+        EMBEDDED.get_or_init(|| {
+            PublicSuffixList::parse(include_str!("../../../data/public_suffix_list.dat"))
+        })
+    }
+
+    /// Counts the labels of the public suffix of `labels`
+    ///
+    /// ## Parameters
+    ///  * `labels` - The lowercase labels of a name, starting with the left-most
+    ///
+    /// ## Return Value
+    /// Returns the number of right-most labels which form the public suffix
+    fn suffix_length(&self, labels: &[String]) -> usize {
+        // Without a matching rule, the top-level label is the public suffix
+        let mut length = 1;
+
+        for start in (0..labels.len()).rev() {
+            let suffix = labels[start..].join(".");
+            let count = labels.len() - start;
+
+            if self.exceptions.contains(&suffix) {
+                return count - 1;
+            }
+
+            if self.rules.contains(&suffix) {
+                length = length.max(count);
+            }
+
+            if start > 0 && self.wildcards.contains(&suffix) {
+                length = length.max(count + 1);
+            }
+        }
+
+        length
+    }
+}
+
+impl DomainName {
+    /// Gets the public suffix of this name, such as "co.uk" for "www.example.co.uk"
+    ///
+    /// ## Parameters
+    ///  * `list` - The Public Suffix List to follow
+    ///
+    /// ## Return Value
+    /// Returns the public suffix, or [`None`] for the root
+    pub fn public_suffix(&self, list: &PublicSuffixList) -> Option<DomainName> {
+        self.public_suffix_with(list, 0)
+    }
+
+    /// Gets the registrable domain of this name, the public suffix and one more label, such as
+    /// "example.co.uk" for "www.example.co.uk"
+    ///
+    /// ## Parameters
+    ///  * `list` - The Public Suffix List to follow
+    ///
+    /// ## Return Value
+    /// Returns the registrable domain, or [`None`] if this name is itself a public suffix
+    pub fn registrable_domain(&self, list: &PublicSuffixList) -> Option<DomainName> {
+        self.public_suffix_with(list, 1)
+    }
+
+    /// Gets the public suffix with `extra` more labels
+    fn public_suffix_with(&self, list: &PublicSuffixList, extra: usize) -> Option<DomainName> {
+        let labels: Vec<String> = self.labels().map(|label| label.to_lowercase()).collect();
+        if labels.is_empty() {
+            return None;
+        }
+
+        self.suffix(list.suffix_length(&labels) + extra)
+    }
+}
+
+/// Converts the non-ASCII labels of `rule` into A-labels
+///
+/// ## Return Value
+/// Returns the converted rule if any label was converted
+fn ascii_form(rule: &str) -> Option<String> {
+    if rule.is_ascii() {
+        return None;
+    }
+
+    let mut output = String::with_capacity(rule.len());
+    for (i, label) in rule.split('.').enumerate() {
+        if i > 0 {
+            output.push('.');
+        }
+
+        let (prefix, label) = match label.strip_prefix('!') {
+            Some(label) => ("!", label),
+            None => ("", label),
+        };
+        output.push_str(prefix);
+
+        if label.is_ascii() {
+            output.push_str(label);
+        } else {
+            output.push_str("xn--");
+            output.push_str(&encode_label(label).ok()?);
+        }
+    }
+
+    Some(output)
+}
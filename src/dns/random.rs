@@ -0,0 +1,221 @@
+use super::DomainName;
+use crate::punycode::encode_label;
+use rand::Rng;
+use std::borrow::Cow;
+
+/// Generates random, structurally valid labels and domain names
+///
+/// Generated labels never start or end with a hyphen. When IDN generation is enabled, about half
+/// of the labels are U-labels, and every label and name still fits within the length limits once
+/// converted to A-labels.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NameGenerator {
+    label_lengths: (usize, usize),
+    label_counts: (usize, usize),
+    charset: Cow<'static, [char]>,
+    idn: bool,
+    fqdn: bool,
+}
+
+/// The letters, digits, and hyphen allowed in host names
+const LDH: &[char] = &[
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '-',
+];
+
+/// Lowercase left-to-right letters mixed into U-labels, all of which are unchanged by UTS-46
+/// mapping
+const UNICODE: &[char] = &[
+    'à', 'ä', 'å', 'ç', 'é', 'ñ', 'ö', 'ø', 'ü', 'λ', 'π', 'ж', 'я', '中', '日', '한',
+];
+
+impl NameGenerator {
+    /// Creates a new [`NameGenerator`] producing relative names of 1 to 4 lowercase LDH labels,
+    /// each 1 to 16 characters long
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`NameGenerator`]
+    pub const fn new() -> Self {
+        NameGenerator {
+            label_lengths: (1, 16),
+            label_counts: (1, 4),
+            charset: Cow::Borrowed(LDH),
+            idn: false,
+            fqdn: false,
+        }
+    }
+
+    /// Sets the range of lengths for generated labels, in characters
+    ///
+    /// ## Parameters
+    ///  * `minimum` - The shortest label length, must be at least 1
+    ///  * `maximum` - The longest label length, must be between `minimum` and 63
+    ///
+    /// ## Return Value
+    /// Returns the updated [`NameGenerator`]
+    pub fn with_label_length(mut self, minimum: usize, maximum: usize) -> Self {
+        assert!(minimum >= 1 && minimum <= maximum && maximum <= DomainName::MAX_LABEL_LENGTH);
+        self.label_lengths = (minimum, maximum);
+        self
+    }
+
+    /// Sets the range of label counts for generated names
+    ///
+    /// Names are cut short if they would otherwise be longer than 255 bytes in wire format.
+    ///
+    /// ## Parameters
+    ///  * `minimum` - The fewest labels, must be at least 1
+    ///  * `maximum` - The most labels, must be at least `minimum`
+    ///
+    /// ## Return Value
+    /// Returns the updated [`NameGenerator`]
+    pub fn with_label_count(mut self, minimum: usize, maximum: usize) -> Self {
+        assert!(minimum >= 1 && minimum <= maximum);
+        self.label_counts = (minimum, maximum);
+        self
+    }
+
+    /// Sets the characters labels are made from
+    ///
+    /// ## Parameters
+    ///  * `charset` - The characters to choose from, which must include at least one character
+    ///    other than '-' and can not include '.', control characters, or whitespace
+    ///
+    /// ## Return Value
+    /// Returns the updated [`NameGenerator`]
+    pub fn with_charset<C: Into<Cow<'static, [char]>>>(mut self, charset: C) -> Self {
+        let charset = charset.into();
+        assert!(charset.iter().any(|c| *c != '-'));
+        assert!(charset
+            .iter()
+            .all(|c| *c != '.' && !c.is_control() && !c.is_whitespace()));
+        self.charset = charset;
+        self
+    }
+
+    /// Sets if internationalized labels are generated
+    ///
+    /// ## Parameters
+    ///  * `idn` - True to mix non-ASCII letters into about half of the labels
+    ///
+    /// ## Return Value
+    /// Returns the updated [`NameGenerator`]
+    pub fn with_idn(mut self, idn: bool) -> Self {
+        self.idn = idn;
+        self
+    }
+
+    /// Sets if generated names are fully qualified
+    ///
+    /// ## Parameters
+    ///  * `fqdn` - True to generate names ending at the root
+    ///
+    /// ## Return Value
+    /// Returns the updated [`NameGenerator`]
+    pub fn with_fqdn(mut self, fqdn: bool) -> Self {
+        self.fqdn = fqdn;
+        self
+    }
+
+    /// Generates a random label
+    ///
+    /// ## Parameters
+    ///  * `rng` - The source of randomness
+    ///
+    /// ## Return Value
+    /// Returns the generated label
+    pub fn label<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.generate_label(rng).into_iter().collect()
+    }
+
+    /// Generates a random domain name
+    ///
+    /// ## Parameters
+    ///  * `rng` - The source of randomness
+    ///
+    /// ## Return Value
+    /// Returns the generated name
+    pub fn name<R: Rng + ?Sized>(&self, rng: &mut R) -> DomainName {
+        let count = rng.gen_range(self.label_counts.0..=self.label_counts.1);
+
+        let mut labels = Vec::with_capacity(count);
+        let mut length = 1;
+        while labels.len() < count {
+            let label = self.generate_label(rng);
+            let label_length = longest_form(&label);
+            if length + label_length + 1 > DomainName::MAX_LENGTH {
+                break;
+            }
+
+            length += label_length + 1;
+            labels.push(label.into_iter().collect::<String>());
+        }
+
+        let name = DomainName::from_labels(labels).expect("generated labels are always valid");
+        if self.fqdn {
+            name.to_fqdn()
+        } else {
+            name
+        }
+    }
+
+    /// Generates the characters of a random label
+    fn generate_label<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<char> {
+        let length = rng.gen_range(self.label_lengths.0..=self.label_lengths.1);
+        let unicode = self.idn && rng.gen_bool(0.5);
+
+        let mut label: Vec<char> = (0..length)
+            .map(|i| {
+                if unicode && rng.gen_bool(0.5) {
+                    return choose(rng, UNICODE);
+                }
+
+                loop {
+                    let c = choose(rng, &self.charset);
+                    if c != '-' || (i != 0 && i != length - 1) {
+                        break c;
+                    }
+                }
+            })
+            .collect();
+
+        if unicode && label.iter().all(char::is_ascii) {
+            let i = rng.gen_range(0..label.len());
+            label[i] = choose(rng, UNICODE);
+        }
+
+        while longest_form(&label) > DomainName::MAX_LABEL_LENGTH {
+            label.pop();
+            if label.last() == Some(&'-') {
+                label.pop();
+            }
+        }
+
+        label
+    }
+}
+
+impl Default for NameGenerator {
+    fn default() -> Self {
+        NameGenerator::new()
+    }
+}
+
+/// Chooses a random character from `chars`
+fn choose<R: Rng + ?Sized>(rng: &mut R, chars: &[char]) -> char {
+    chars[rng.gen_range(0..chars.len())]
+}
+
+/// Gets the length of `label` in bytes, either as written or as an A-label, whichever is longer
+fn longest_form(label: &[char]) -> usize {
+    let length = label.iter().map(|c| c.len_utf8()).sum();
+    if label.iter().all(char::is_ascii) {
+        return length;
+    }
+
+    let label: String = label.iter().collect();
+    match encode_label(&label) {
+        Ok(encoded) => length.max(encoded.len() + 4),
+        Err(_) => usize::MAX,
+    }
+}
@@ -0,0 +1,269 @@
+use super::{DomainName, InvalidReverseNameError};
+use crate::ip::{
+    v4::{IPv4Address, IPv4CIDR},
+    v6::{IPv6Address, IPv6CIDR},
+    IPAddress, IPCIDR,
+};
+
+/// A name in the "in-addr.arpa" or "ip6.arpa" reverse-mapping trees, paired with the addresses
+/// it covers
+///
+/// Single addresses and octet or nibble aligned networks map directly onto the trees. IPv4
+/// networks longer than /24 which are not octet aligned use the classless delegation names from
+/// RFC 2317, such as "0/26.2.0.192.in-addr.arpa.".
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ReverseName {
+    name: DomainName,
+    network: IPCIDR,
+}
+
+impl ReverseName {
+    /// Creates the reverse name of a single address
+    ///
+    /// ## Parameters
+    ///  * `address` - The address to map
+    ///
+    /// ## Return Value
+    /// Returns the reverse name of `address`
+    pub fn from_address(address: IPAddress) -> Self {
+        let network = match address {
+            IPAddress::V4(address) => IPCIDR::V4(IPv4CIDR::new(address, 32)),
+            IPAddress::V6(address) => IPCIDR::V6(IPv6CIDR::new(address, 128)),
+        };
+
+        ReverseName::from_cidr(network).unwrap()
+    }
+
+    /// Creates the reverse name of a network
+    ///
+    /// ## Parameters
+    ///  * `network` - The network to map, whose host bits are ignored
+    ///
+    /// ## Return Value
+    /// Returns the reverse name of `network`, or an error if the prefix can not be represented
+    pub fn from_cidr(network: IPCIDR) -> Result<Self, InvalidReverseNameError> {
+        let (labels, network) = match network {
+            IPCIDR::V4(cidr) => {
+                let prefix = cidr.prefix();
+                let address = u32::from(cidr.address()) & mask_v4(prefix);
+                let octets = address.to_be_bytes();
+
+                let mut labels: Vec<String> = octets[..prefix as usize / 8]
+                    .iter()
+                    .rev()
+                    .map(u8::to_string)
+                    .collect();
+
+                if prefix % 8 != 0 {
+                    if prefix < 24 {
+                        return Err(InvalidReverseNameError::UnalignedPrefix(prefix));
+                    }
+
+                    labels.insert(0, format!("{}/{}", octets[3], prefix));
+                }
+
+                labels.extend(["in-addr".to_owned(), "arpa".to_owned()]);
+                (
+                    labels,
+                    IPCIDR::V4(IPv4CIDR::new(IPv4Address::from(address), prefix)),
+                )
+            }
+            IPCIDR::V6(cidr) => {
+                let prefix = cidr.prefix();
+                if prefix % 4 != 0 {
+                    return Err(InvalidReverseNameError::UnalignedPrefix(prefix));
+                }
+
+                let address = u128::from(cidr.address()) & mask_v6(prefix);
+                let mut labels: Vec<String> = (0..prefix as u32 / 4)
+                    .rev()
+                    .map(|nibble| format!("{:x}", (address >> (124 - nibble * 4)) & 0xF))
+                    .collect();
+
+                labels.extend(["ip6".to_owned(), "arpa".to_owned()]);
+                (
+                    labels,
+                    IPCIDR::V6(IPv6CIDR::new(IPv6Address::from(address), prefix)),
+                )
+            }
+        };
+
+        Ok(ReverseName {
+            name: DomainName::from_labels(labels).unwrap().to_fqdn(),
+            network,
+        })
+    }
+
+    /// Gets the domain name
+    ///
+    /// ## Return Value
+    /// Returns the fully qualified name in the reverse-mapping tree
+    pub fn name(&self) -> &DomainName {
+        &self.name
+    }
+
+    /// Gets the addresses covered by this name
+    ///
+    /// ## Return Value
+    /// Returns the network this name maps
+    pub fn network(&self) -> IPCIDR {
+        self.network
+    }
+
+    /// Gets the single address this name maps
+    ///
+    /// ## Return Value
+    /// Returns the address if this name covers exactly one address
+    pub fn address(&self) -> Option<IPAddress> {
+        match self.network {
+            IPCIDR::V4(cidr) if cidr.prefix() == 32 => Some(IPAddress::V4(cidr.address())),
+            IPCIDR::V6(cidr) if cidr.prefix() == 128 => Some(IPAddress::V6(cidr.address())),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the labels of an "in-addr.arpa" name, starting with the left-most
+fn parse_v4(labels: &[String]) -> Result<IPv4CIDR, InvalidReverseNameError> {
+    // An RFC 2317 label is followed by the three octets of the enclosing /24
+    let (classless, octets) = match labels.split_first() {
+        Some((first, rest)) if rest.len() == 3 && first.contains(['/', '-']) => {
+            (Some(first.as_str()), rest)
+        }
+        _ => (None, labels),
+    };
+
+    if octets.len() > 4 {
+        return Err(InvalidReverseNameError::InvalidLabel);
+    }
+
+    let mut address = [0; 4];
+    for (i, label) in octets.iter().rev().enumerate() {
+        address[i] = parse_octet(label)?;
+    }
+
+    let mut prefix = octets.len() as u8 * 8;
+    if let Some(classless) = classless {
+        let (start, end) = classless
+            .split_once(['/', '-'])
+            .ok_or(InvalidReverseNameError::InvalidLabel)?;
+        let start = parse_octet(start)?;
+
+        prefix = if classless.contains('/') {
+            end.parse()
+                .ok()
+                .filter(|prefix| (25..=32).contains(prefix))
+                .ok_or(InvalidReverseNameError::InvalidLabel)?
+        } else {
+            let size = (parse_octet(end)? as u32 + 1)
+                .checked_sub(start as u32)
+                .filter(|size| size.is_power_of_two() && *size <= 128)
+                .ok_or(InvalidReverseNameError::InvalidLabel)?;
+            32 - size.trailing_zeros() as u8
+        };
+
+        if start as u32 & !mask_v4(prefix) != 0 {
+            return Err(InvalidReverseNameError::InvalidLabel);
+        }
+        address[3] = start;
+    }
+
+    Ok(IPv4CIDR::new(IPv4Address::from(address), prefix))
+}
+
+/// Parses the labels of an "ip6.arpa" name, starting with the left-most
+fn parse_v6(labels: &[String]) -> Result<IPv6CIDR, InvalidReverseNameError> {
+    if labels.len() > 32 {
+        return Err(InvalidReverseNameError::InvalidLabel);
+    }
+
+    let mut address = 0u128;
+    for (i, label) in labels.iter().rev().enumerate() {
+        let nibble = match label.as_bytes() {
+            [digit] => (*digit as char)
+                .to_digit(16)
+                .ok_or(InvalidReverseNameError::InvalidLabel)?,
+            _ => return Err(InvalidReverseNameError::InvalidLabel),
+        };
+
+        address |= (nibble as u128) << (124 - i * 4);
+    }
+
+    Ok(IPv6CIDR::new(
+        IPv6Address::from(address),
+        labels.len() as u8 * 4,
+    ))
+}
+
+/// Parses a decimal octet without leading zeros
+fn parse_octet(label: &str) -> Result<u8, InvalidReverseNameError> {
+    if label.is_empty()
+        || (label.len() > 1 && label.starts_with('0'))
+        || !label.bytes().all(|byte| byte.is_ascii_digit())
+    {
+        return Err(InvalidReverseNameError::InvalidLabel);
+    }
+
+    label
+        .parse()
+        .map_err(|_| InvalidReverseNameError::InvalidLabel)
+}
+
+/// Creates the IPv4 network mask for `prefix`
+fn mask_v4(prefix: u8) -> u32 {
+    u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0)
+}
+
+/// Creates the IPv6 network mask for `prefix`
+fn mask_v6(prefix: u8) -> u128 {
+    u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0)
+}
+
+impl TryFrom<&DomainName> for ReverseName {
+    type Error = InvalidReverseNameError;
+
+    fn try_from(name: &DomainName) -> Result<Self, Self::Error> {
+        let mut labels: Vec<String> = name
+            .labels()
+            .map(|label| label.to_ascii_lowercase())
+            .collect();
+        if labels.len() < 2 || labels.pop().unwrap() != "arpa" {
+            return Err(InvalidReverseNameError::NotReverseName);
+        }
+
+        let network = match labels.pop().unwrap().as_str() {
+            "in-addr" => IPCIDR::V4(parse_v4(&labels)?),
+            "ip6" => IPCIDR::V6(parse_v6(&labels)?),
+            _ => return Err(InvalidReverseNameError::NotReverseName),
+        };
+
+        Ok(ReverseName {
+            name: name.to_fqdn(),
+            network,
+        })
+    }
+}
+
+impl From<IPAddress> for ReverseName {
+    fn from(address: IPAddress) -> Self {
+        ReverseName::from_address(address)
+    }
+}
+
+impl From<ReverseName> for DomainName {
+    fn from(name: ReverseName) -> Self {
+        name.name
+    }
+}
+
+impl std::fmt::Display for ReverseName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.name.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for ReverseName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{dns::InvalidDomainNameError, ErrorKind};
+
+/// An error while parsing a DNS-SD service name
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidServiceNameError {
+    /// No "_service._proto" pair was found
+    MissingService,
+
+    /// There is no domain after the protocol
+    MissingDomain,
+
+    /// The instance name is empty, too long, contains control characters, or spans more than
+    /// one label
+    InvalidInstance,
+
+    /// The service name does not follow RFC 6335
+    InvalidService,
+
+    /// The protocol is not "_tcp" or "_udp"
+    InvalidProtocol,
+
+    /// A '\' escape is incomplete or does not produce valid UTF-8
+    InvalidEscape,
+
+    /// The domain is invalid
+    InvalidDomain(InvalidDomainNameError),
+}
+
+impl From<InvalidDomainNameError> for InvalidServiceNameError {
+    fn from(error: InvalidDomainNameError) -> Self {
+        InvalidServiceNameError::InvalidDomain(error)
+    }
+}
+
+impl InvalidServiceNameError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidServiceNameError::MissingService | InvalidServiceNameError::MissingDomain => {
+                ErrorKind::Missing
+            }
+            InvalidServiceNameError::InvalidInstance
+            | InvalidServiceNameError::InvalidService
+            | InvalidServiceNameError::InvalidProtocol
+            | InvalidServiceNameError::InvalidEscape => ErrorKind::InvalidFormat,
+            InvalidServiceNameError::InvalidDomain(error) => error.kind(),
+        }
+    }
+}
+
+impl std::error::Error for InvalidServiceNameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidServiceNameError::InvalidDomain(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidServiceNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidServiceNameError::MissingService => write!(f, "missing service and protocol"),
+            InvalidServiceNameError::MissingDomain => write!(f, "missing domain"),
+            InvalidServiceNameError::InvalidInstance => write!(f, "invalid instance name"),
+            InvalidServiceNameError::InvalidService => write!(f, "invalid service name"),
+            InvalidServiceNameError::InvalidProtocol => write!(f, "invalid protocol"),
+            InvalidServiceNameError::InvalidEscape => write!(f, "invalid escape sequence"),
+            InvalidServiceNameError::InvalidDomain(error) => {
+                write!(f, "invalid domain - {}", error)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidServiceNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
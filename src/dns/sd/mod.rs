@@ -0,0 +1,230 @@
+use super::DomainName;
+use std::str::FromStr;
+
+mod error;
+mod protocol;
+
+pub use error::InvalidServiceNameError;
+pub use protocol::ServiceProtocol;
+
+/// A DNS-SD service name from RFC 6763, such as "My Printer._ipp._tcp.local"
+///
+/// The instance is optional, so service types like "_ipp._tcp.local" used for browsing are also
+/// accepted. In text, '.' and '\' in the instance are escaped with a '\'.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ServiceName {
+    instance: Option<String>,
+    service: String,
+    protocol: ServiceProtocol,
+    domain: DomainName,
+}
+
+impl ServiceName {
+    /// The maximum length of an instance name in bytes
+    pub const MAX_INSTANCE_LENGTH: usize = 63;
+
+    /// The maximum length of a service name, not including the leading '_'
+    pub const MAX_SERVICE_LENGTH: usize = 15;
+
+    /// Creates a new [`ServiceName`]
+    ///
+    /// ## Parameters
+    ///  * `instance` - The unescaped, user-visible instance name, if any
+    ///  * `service` - The service name without the leading '_', such as "ipp"
+    ///  * `protocol` - The transport protocol of the service
+    ///  * `domain` - The domain the service is registered in
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ServiceName`] if the instance and service are valid
+    pub fn new(
+        instance: Option<&str>,
+        service: &str,
+        protocol: ServiceProtocol,
+        domain: DomainName,
+    ) -> Result<Self, InvalidServiceNameError> {
+        if let Some(instance) = instance {
+            if instance.is_empty()
+                || instance.len() > ServiceName::MAX_INSTANCE_LENGTH
+                || instance.chars().any(char::is_control)
+            {
+                return Err(InvalidServiceNameError::InvalidInstance);
+            }
+        }
+
+        // Service names follow RFC 6335 section 5.1
+        if service.is_empty()
+            || service.len() > ServiceName::MAX_SERVICE_LENGTH
+            || !service
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+            || !service.bytes().any(|byte| byte.is_ascii_alphabetic())
+            || service.starts_with('-')
+            || service.ends_with('-')
+            || service.contains("--")
+        {
+            return Err(InvalidServiceNameError::InvalidService);
+        }
+
+        Ok(ServiceName {
+            instance: instance.map(str::to_owned),
+            service: service.to_owned(),
+            protocol,
+            domain,
+        })
+    }
+
+    /// Gets the instance name
+    ///
+    /// ## Return Value
+    /// Returns the unescaped instance name, if there is one
+    pub fn instance(&self) -> Option<&str> {
+        self.instance.as_deref()
+    }
+
+    /// Gets the service name
+    ///
+    /// ## Return Value
+    /// Returns the service name without the leading '_'
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// Gets the transport protocol
+    ///
+    /// ## Return Value
+    /// Returns the protocol of the service
+    pub fn protocol(&self) -> ServiceProtocol {
+        self.protocol
+    }
+
+    /// Gets the domain the service is registered in
+    ///
+    /// ## Return Value
+    /// Returns the domain following the protocol label
+    pub fn domain(&self) -> &DomainName {
+        &self.domain
+    }
+
+    /// Gets the service type, this name without the instance
+    ///
+    /// ## Return Value
+    /// Returns the service type, such as "_ipp._tcp.local"
+    pub fn service_type(&self) -> ServiceName {
+        ServiceName {
+            instance: None,
+            ..self.clone()
+        }
+    }
+}
+
+/// Splits `s` into its unescaped labels
+///
+/// ## Return Value
+/// Returns the labels and whether `s` ends with an unescaped '.'
+fn split_labels(s: &str) -> Result<(Vec<String>, bool), InvalidServiceNameError> {
+    let mut labels = Vec::new();
+    let mut label = Vec::new();
+
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => {
+                let digits = bytes
+                    .get(i + 1..i + 4)
+                    .filter(|digits| digits.iter().all(u8::is_ascii_digit));
+
+                match (digits, bytes.get(i + 1)) {
+                    (Some(digits), _) => {
+                        let value = digits
+                            .iter()
+                            .fold(0u32, |value, digit| value * 10 + (digit - b'0') as u32);
+                        label.push(
+                            u8::try_from(value)
+                                .map_err(|_| InvalidServiceNameError::InvalidEscape)?,
+                        );
+                        i += 4;
+                    }
+                    (None, Some(&byte)) if byte.is_ascii() => {
+                        label.push(byte);
+                        i += 2;
+                    }
+                    _ => return Err(InvalidServiceNameError::InvalidEscape),
+                }
+            }
+            b'.' => {
+                labels.push(std::mem::take(&mut label));
+                i += 1;
+            }
+            byte => {
+                label.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    let fqdn = label.is_empty() && !labels.is_empty();
+    if !fqdn {
+        labels.push(label);
+    }
+
+    labels
+        .into_iter()
+        .map(|label| String::from_utf8(label).map_err(|_| InvalidServiceNameError::InvalidEscape))
+        .collect::<Result<_, _>>()
+        .map(|labels| (labels, fqdn))
+}
+
+impl FromStr for ServiceName {
+    type Err = InvalidServiceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (labels, fqdn) = split_labels(s)?;
+
+        let service_index = labels
+            .windows(2)
+            .position(|pair| pair[0].starts_with('_') && pair[1].parse::<ServiceProtocol>().is_ok())
+            .ok_or(InvalidServiceNameError::MissingService)?;
+
+        let instance = match service_index {
+            0 => None,
+            1 => Some(labels[0].as_str()),
+            _ => return Err(InvalidServiceNameError::InvalidInstance),
+        };
+
+        let domain = &labels[service_index + 2..];
+        if domain.is_empty() {
+            return Err(InvalidServiceNameError::MissingDomain);
+        }
+
+        let domain = DomainName::from_labels(domain)?;
+        ServiceName::new(
+            instance,
+            &labels[service_index][1..],
+            labels[service_index + 1].parse()?,
+            if fqdn { domain.to_fqdn() } else { domain },
+        )
+    }
+}
+
+impl std::fmt::Display for ServiceName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(instance) = &self.instance {
+            for c in instance.chars() {
+                if c == '.' || c == '\\' {
+                    f.write_str("\\")?;
+                }
+                write!(f, "{}", c)?;
+            }
+            f.write_str(".")?;
+        }
+
+        write!(f, "_{}.{}.{}", self.service, self.protocol, self.domain)
+    }
+}
+
+impl std::fmt::Debug for ServiceName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,35 @@
+use super::InvalidServiceNameError;
+use std::str::FromStr;
+
+/// The transport protocol label of a DNS-SD service name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ServiceProtocol {
+    /// "_tcp", for services running over TCP
+    TCP,
+
+    /// "_udp", for services running over any other protocol
+    UDP,
+}
+
+impl FromStr for ServiceProtocol {
+    type Err = InvalidServiceNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("_tcp") {
+            Ok(ServiceProtocol::TCP)
+        } else if s.eq_ignore_ascii_case("_udp") {
+            Ok(ServiceProtocol::UDP)
+        } else {
+            Err(InvalidServiceNameError::InvalidProtocol)
+        }
+    }
+}
+
+impl std::fmt::Display for ServiceProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ServiceProtocol::TCP => "_tcp",
+            ServiceProtocol::UDP => "_udp",
+        })
+    }
+}
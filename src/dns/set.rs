@@ -0,0 +1,131 @@
+use super::DomainName;
+use std::{borrow::Cow, collections::HashMap};
+
+/// A set of domain names matching each name and everything below it
+///
+/// Entries are kept in a tree of labels starting from the right-most, so checking a name takes
+/// one step per label no matter how many entries there are. An entry whose left-most label is
+/// "*", such as "*.example.com", is a wildcard which matches every name below "example.com" but
+/// not "example.com" itself. Case and the trailing root are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct DomainSet {
+    root: Node,
+    len: usize,
+}
+
+/// A label in the tree of a [`DomainSet`]
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// The labels below this one, in lowercase
+    children: HashMap<Box<str>, Node>,
+
+    /// This name and everything below it is in the set
+    suffix: bool,
+
+    /// Everything below this name is in the set
+    wildcard: bool,
+}
+
+impl DomainSet {
+    /// Creates a new, empty [`DomainSet`]
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`DomainSet`]
+    pub fn new() -> Self {
+        DomainSet::default()
+    }
+
+    /// Adds an entry to this set
+    ///
+    /// ## Parameters
+    ///  * `name` - The name to add, with a left-most label of "*" for a wildcard entry
+    ///
+    /// ## Return Value
+    /// Returns true if the entry was not already in this set
+    pub fn insert(&mut self, name: &DomainName) -> bool {
+        let wildcard = name.labels().next().map(|label| label.as_str()) == Some("*");
+
+        let mut node = &mut self.root;
+        for label in name.labels().skip(wildcard as usize).rev() {
+            node = node.children.entry(key(label.as_str()).into()).or_default();
+        }
+
+        let flag = if wildcard {
+            &mut node.wildcard
+        } else {
+            &mut node.suffix
+        };
+
+        let inserted = !*flag;
+        *flag = true;
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Checks if `name` is matched by an entry in this set
+    ///
+    /// ## Parameters
+    ///  * `name` - The name to check
+    ///
+    /// ## Return Value
+    /// Returns true if `name` is equal to or below an entry, or is strictly below a wildcard
+    /// entry
+    pub fn contains(&self, name: &DomainName) -> bool {
+        let mut node = &self.root;
+        for label in name.labels().rev() {
+            if node.suffix || node.wildcard {
+                return true;
+            }
+
+            node = match node.children.get(key(label.as_str()).as_ref()) {
+                Some(child) => child,
+                None => return false,
+            };
+        }
+
+        node.suffix
+    }
+
+    /// Gets the number of entries in this set
+    ///
+    /// ## Return Value
+    /// Returns the number of entries, counting a name and its wildcard separately
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if this set has no entries
+    ///
+    /// ## Return Value
+    /// Returns true if this set is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Extend<DomainName> for DomainSet {
+    fn extend<T: IntoIterator<Item = DomainName>>(&mut self, iter: T) {
+        for name in iter {
+            self.insert(&name);
+        }
+    }
+}
+
+impl FromIterator<DomainName> for DomainSet {
+    fn from_iter<T: IntoIterator<Item = DomainName>>(iter: T) -> Self {
+        let mut set = DomainSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Gets the key of `label` in a [`Node`], its ASCII lowercase form
+fn key(label: &str) -> Cow<'_, str> {
+    if label.bytes().any(|byte| byte.is_ascii_uppercase()) {
+        Cow::Owned(label.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(label)
+    }
+}
@@ -0,0 +1,55 @@
+use super::DomainName;
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::vec,
+    prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+/// Labels which are unusual but valid: underscores, A-labels, uppercase, and the longest label
+const EDGE_LABELS: &[&str] = &[
+    "_acme-challenge",
+    "_tcp",
+    "xn--bcher-kva",
+    "EXAMPLE",
+    "0",
+    "a-b",
+    "a23456789012345678901234567890123456789012345678901234567890123",
+];
+
+/// A name of 255 bytes in wire format, the longest allowed
+const LONGEST_NAME: &str = concat!(
+    "a23456789012345678901234567890123456789012345678901234567890123.",
+    "b23456789012345678901234567890123456789012345678901234567890123.",
+    "c23456789012345678901234567890123456789012345678901234567890123.",
+    "d234567890123456789012345678901234567890123456789012345678901",
+);
+
+impl Arbitrary for DomainName {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        let label = prop_oneof![
+            4 => "[a-z0-9]([a-z0-9-]{0,14}[a-z0-9])?",
+            1 => select(EDGE_LABELS).prop_map(str::to_owned),
+        ];
+
+        let name = (vec(label, 1..=4), any::<bool>()).prop_map(|(labels, fqdn)| {
+            let name = DomainName::from_labels(labels).unwrap();
+            if fqdn {
+                name.to_fqdn()
+            } else {
+                name
+            }
+        });
+
+        prop_oneof![
+            8 => name,
+            1 => Just(DomainName::root()),
+            1 => Just(LONGEST_NAME.parse().unwrap()),
+        ]
+        .boxed()
+    }
+}
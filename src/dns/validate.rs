@@ -0,0 +1,59 @@
+use super::{DomainName, Hostname, InvalidRecordNameError};
+use std::net::IpAddr;
+
+impl DomainName {
+    /// Checks that this name can own an SRV record, following the "_service._proto.name" form
+    /// from RFC 2782
+    ///
+    /// ## Return Value
+    /// Returns an error describing why this name can not own an SRV record
+    pub fn validate_srv_owner(&self) -> Result<(), InvalidRecordNameError> {
+        let mut labels = self.labels().map(|label| label.as_str());
+
+        let is_underscore_label = |label: Option<&str>| {
+            label
+                .map(|label| label.len() > 1 && label.starts_with('_'))
+                .unwrap_or(false)
+        };
+
+        if !is_underscore_label(labels.next()) {
+            return Err(InvalidRecordNameError::MissingServiceLabel);
+        }
+
+        if !is_underscore_label(labels.next()) {
+            return Err(InvalidRecordNameError::MissingProtocolLabel);
+        }
+
+        if labels.next().is_none() {
+            return Err(InvalidRecordNameError::MissingName);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that this name can be the target of an MX or SRV record
+    ///
+    /// Targets must be host names, so IP addresses and labels with underscores are rejected. The
+    /// root is accepted as it marks a null MX record (RFC 7505) or an unavailable SRV service.
+    ///
+    /// ## Return Value
+    /// Returns an error describing why this name can not be a target
+    pub fn validate_target(&self) -> Result<(), InvalidRecordNameError> {
+        if self.is_root() {
+            return Ok(());
+        }
+
+        let name = self.strip_root().to_string();
+        if name.parse::<IpAddr>().is_ok() {
+            return Err(InvalidRecordNameError::IPAddress);
+        }
+
+        if name.contains('_') {
+            return Err(InvalidRecordNameError::Underscore);
+        }
+
+        Hostname::try_from(self)
+            .map(|_| ())
+            .map_err(InvalidRecordNameError::InvalidHostname)
+    }
+}
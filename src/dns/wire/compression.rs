@@ -0,0 +1,44 @@
+use crate::dns::DomainName;
+use std::collections::HashMap;
+
+/// The offsets of names already written into a DNS message, used to emit compression pointers
+#[derive(Debug, Clone, Default)]
+pub struct CompressionMap {
+    offsets: HashMap<DomainName, u16>,
+}
+
+impl CompressionMap {
+    /// Creates a new, empty [`CompressionMap`]
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`CompressionMap`]
+    pub fn new() -> Self {
+        CompressionMap::default()
+    }
+
+    /// Gets the offset `name` was written at
+    ///
+    /// ## Parameters
+    ///  * `name` - The name to find
+    ///
+    /// ## Return Value
+    /// Returns the offset of `name` within the message, if it has been written
+    pub fn get(&self, name: &DomainName) -> Option<u16> {
+        self.offsets.get(&name.to_fqdn()).copied()
+    }
+
+    /// Records that `name` was written at `offset`
+    ///
+    /// ## Parameters
+    ///  * `name` - The name which was written
+    ///  * `offset` - The offset of the name within the message, which must be below 0x4000
+    pub fn insert(&mut self, name: DomainName, offset: u16) {
+        debug_assert!(offset <= 0x3FFF);
+        self.offsets.insert(name.to_fqdn(), offset);
+    }
+
+    /// Removes every recorded name
+    pub fn clear(&mut self) {
+        self.offsets.clear();
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{dns::InvalidDomainNameError, ErrorKind};
+
+/// An error while decoding a domain name from DNS wire format
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidWireNameError {
+    /// The name runs past the end of the packet
+    Truncated,
+
+    /// A length byte uses one of the reserved label types
+    InvalidLabelType(u8),
+
+    /// A compression pointer does not point to earlier data, or too many pointers were followed
+    PointerLoop,
+
+    /// The name is longer than 255 bytes once decompressed
+    NameTooLong,
+
+    /// A label is not valid UTF-8
+    InvalidLabel,
+
+    /// The decoded labels do not form a valid domain name
+    InvalidName(InvalidDomainNameError),
+}
+
+impl InvalidWireNameError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidWireNameError::Truncated => ErrorKind::Truncated,
+            InvalidWireNameError::InvalidLabelType(_) => ErrorKind::Unsupported,
+            InvalidWireNameError::PointerLoop => ErrorKind::InvalidFormat,
+            InvalidWireNameError::NameTooLong => ErrorKind::TooLong,
+            InvalidWireNameError::InvalidLabel => ErrorKind::InvalidCharacter,
+            InvalidWireNameError::InvalidName(error) => error.kind(),
+        }
+    }
+}
+
+impl std::error::Error for InvalidWireNameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidWireNameError::InvalidName(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidWireNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidWireNameError::Truncated => write!(f, "name is truncated"),
+            InvalidWireNameError::InvalidLabelType(byte) => {
+                write!(f, "invalid label type 0x{:02X}", byte)
+            }
+            InvalidWireNameError::PointerLoop => write!(f, "compression pointer loop"),
+            InvalidWireNameError::NameTooLong => {
+                write!(f, "name exceeds the maximum length of 255")
+            }
+            InvalidWireNameError::InvalidLabel => write!(f, "label is not valid UTF-8"),
+            InvalidWireNameError::InvalidName(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidWireNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,170 @@
+use super::DomainName;
+
+mod compression;
+mod error;
+
+pub use compression::CompressionMap;
+pub use error::InvalidWireNameError;
+
+/// The bits marking a length byte as the start of a compression pointer
+const POINTER: u8 = 0xC0;
+
+/// The largest offset a compression pointer can hold
+const MAX_POINTER_OFFSET: usize = 0x3FFF;
+
+/// The most compression pointers followed while decoding a single name
+const MAX_POINTERS: usize = 127;
+
+impl DomainName {
+    /// Gets the length of this name in DNS wire format without compression
+    ///
+    /// ## Return Value
+    /// Returns the number of bytes [`DomainName::to_wire`] would write
+    pub fn wire_len(&self) -> usize {
+        self.labels().map(|label| label.len() + 1).sum::<usize>() + 1
+    }
+
+    /// Gets the length of this name in DNS wire format when compressed against the names already
+    /// written into a message
+    ///
+    /// ## Parameters
+    ///  * `offsets` - The offsets of names already written into the message
+    ///
+    /// ## Return Value
+    /// Returns the number of bytes [`DomainName::to_wire_compressed`] would write
+    pub fn wire_len_compressed(&self, offsets: &CompressionMap) -> usize {
+        let mut length = 0;
+
+        let mut suffix = Some(self.to_fqdn());
+        while let Some(name) = suffix.filter(|name| !name.is_root()) {
+            if offsets.get(&name).is_some() {
+                return length + 2;
+            }
+
+            length += name.labels().next().unwrap().len() + 1;
+            suffix = name.parent();
+        }
+
+        length + 1
+    }
+
+    /// Encodes this name in DNS wire format without compression
+    ///
+    /// The name is always terminated by the root label, so a relative name is encoded as if it
+    /// were fully qualified.
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded name to
+    pub fn to_wire(&self, output: &mut Vec<u8>) {
+        for label in self.labels() {
+            output.push(label.len() as u8);
+            output.extend_from_slice(label.as_bytes());
+        }
+        output.push(0);
+    }
+
+    /// Encodes this name in DNS wire format, replacing the longest suffix already written with a
+    /// compression pointer
+    ///
+    /// Each suffix written in full is recorded in `offsets` so later names can point to it.
+    ///
+    /// ## Parameters
+    ///  * `output` - The message being built, which the encoded name is appended to
+    ///  * `offsets` - The offsets of names already written into `output`
+    pub fn to_wire_compressed(&self, output: &mut Vec<u8>, offsets: &mut CompressionMap) {
+        let mut suffix = Some(self.to_fqdn());
+        while let Some(name) = suffix.filter(|name| !name.is_root()) {
+            if let Some(offset) = offsets.get(&name) {
+                output.extend_from_slice(&(offset | ((POINTER as u16) << 8)).to_be_bytes());
+                return;
+            }
+
+            if output.len() <= MAX_POINTER_OFFSET {
+                offsets.insert(name.clone(), output.len() as u16);
+            }
+
+            let label = name.labels().next().unwrap();
+            output.push(label.len() as u8);
+            output.extend_from_slice(label.as_bytes());
+
+            suffix = name.parent();
+        }
+
+        output.push(0);
+    }
+
+    /// Decodes a name in DNS wire format, following any compression pointers
+    ///
+    /// ## Parameters
+    ///  * `packet` - The entire message, which compression pointers are relative to
+    ///  * `offset` - The offset of the name within `packet`
+    ///
+    /// ## Return Value
+    /// Returns the fully qualified name and the offset of the first byte after it
+    pub fn from_wire(
+        packet: &[u8],
+        offset: usize,
+    ) -> Result<(DomainName, usize), InvalidWireNameError> {
+        let mut labels = Vec::new();
+        let mut length = 1;
+        let mut position = offset;
+        let mut end = None;
+        let mut pointers = 0;
+
+        loop {
+            let byte = *packet
+                .get(position)
+                .ok_or(InvalidWireNameError::Truncated)?;
+
+            match byte & POINTER {
+                0 if byte == 0 => {
+                    end.get_or_insert(position + 1);
+                    break;
+                }
+                0 => {
+                    let start = position + 1;
+                    let label = packet
+                        .get(start..start + byte as usize)
+                        .ok_or(InvalidWireNameError::Truncated)?;
+
+                    length += label.len() + 1;
+                    if length > DomainName::MAX_LENGTH {
+                        return Err(InvalidWireNameError::NameTooLong);
+                    }
+
+                    labels.push(
+                        String::from_utf8(label.to_vec())
+                            .map_err(|_| InvalidWireNameError::InvalidLabel)?,
+                    );
+                    position = start + label.len();
+                }
+                POINTER => {
+                    let low = *packet
+                        .get(position + 1)
+                        .ok_or(InvalidWireNameError::Truncated)?;
+                    let target = (((byte & !POINTER) as usize) << 8) | low as usize;
+
+                    // Only allowing pointers to earlier data prevents loops
+                    pointers += 1;
+                    if target >= position || pointers > MAX_POINTERS {
+                        return Err(InvalidWireNameError::PointerLoop);
+                    }
+
+                    end.get_or_insert(position + 2);
+                    position = target;
+                }
+                _ => return Err(InvalidWireNameError::InvalidLabelType(byte)),
+            }
+        }
+
+        let name = if labels.is_empty() {
+            DomainName::root()
+        } else {
+            DomainName::from_labels(labels)
+                .map_err(InvalidWireNameError::InvalidName)?
+                .to_fqdn()
+        };
+
+        Ok((name, end.unwrap()))
+    }
+}
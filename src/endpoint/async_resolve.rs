@@ -0,0 +1,89 @@
+use super::{Endpoint, Resolve, SystemResolver};
+use crate::ip::IPSocketAddress;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// Asynchronously turns an [`Endpoint`] into the socket addresses it refers to
+///
+/// [`SystemResolver`] implements this by performing its lookup on a new thread, so the executor
+/// polling the future is never blocked.
+pub trait AsyncResolve {
+    /// Resolves `endpoint` into socket addresses
+    ///
+    /// ## Parameters
+    ///  * `endpoint` - The endpoint to resolve, which must have a port
+    ///
+    /// ## Return Value
+    /// Returns a future producing every socket address of the endpoint
+    fn resolve_async(
+        &self,
+        endpoint: &Endpoint,
+    ) -> impl Future<Output = std::io::Result<Vec<IPSocketAddress>>> + Send;
+}
+
+/// A lookup by a [`SystemResolver`] running on its own thread
+///
+/// The thread is started when the future is first polled and wakes the task once it finishes.
+struct ThreadLookup {
+    endpoint: Option<Endpoint>,
+    shared: Arc<Mutex<Shared>>,
+}
+
+/// The state shared between a [`ThreadLookup`] and its thread
+#[derive(Default)]
+struct Shared {
+    result: Option<std::io::Result<Vec<IPSocketAddress>>>,
+    waker: Option<Waker>,
+}
+
+impl AsyncResolve for SystemResolver {
+    fn resolve_async(
+        &self,
+        endpoint: &Endpoint,
+    ) -> impl Future<Output = std::io::Result<Vec<IPSocketAddress>>> + Send {
+        ThreadLookup {
+            endpoint: Some(endpoint.clone()),
+            shared: Arc::new(Mutex::new(Shared::default())),
+        }
+    }
+}
+
+impl Future for ThreadLookup {
+    type Output = std::io::Result<Vec<IPSocketAddress>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        {
+            let mut shared = self.shared.lock().unwrap();
+            if let Some(result) = shared.result.take() {
+                return Poll::Ready(result);
+            }
+            shared.waker = Some(cx.waker().clone());
+        }
+
+        let Some(endpoint) = self.endpoint.take() else {
+            return Poll::Pending;
+        };
+
+        let shared = self.shared.clone();
+        let spawned = std::thread::Builder::new()
+            .name("net-utils-resolver".to_owned())
+            .spawn(move || {
+                let result = SystemResolver.resolve(&endpoint);
+
+                let mut shared = shared.lock().unwrap();
+                shared.result = Some(result);
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            });
+
+        match spawned {
+            Ok(_) => Poll::Pending,
+            Err(error) => Poll::Ready(Err(error)),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::{Endpoint, InvalidBindSpecError};
+use crate::{ip::IPSocketAddress, port::Protocol};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The address a server listens on, such as "0.0.0.0:8080", "[::]:443", "localhost:9000", or
+/// "unix:/run/app.sock"
+///
+/// Addresses are TCP unless prefixed with "udp://", and may also be prefixed with "tcp://".
+/// Unix domain socket paths are prefixed with "unix:" or "unix://". Every TCP and UDP address
+/// must have a port.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BindSpec {
+    /// A TCP listener
+    TCP(Endpoint),
+
+    /// A UDP socket
+    UDP(Endpoint),
+
+    /// A Unix domain socket at a path
+    Unix(PathBuf),
+}
+
+impl BindSpec {
+    /// Gets the transport protocol of this specification
+    ///
+    /// ## Return Value
+    /// Returns the protocol, or [`None`] for a Unix domain socket
+    pub fn protocol(&self) -> Option<Protocol> {
+        match self {
+            BindSpec::TCP(_) => Some(Protocol::TCP),
+            BindSpec::UDP(_) => Some(Protocol::UDP),
+            BindSpec::Unix(_) => None,
+        }
+    }
+
+    /// Gets the endpoint to bind to
+    ///
+    /// ## Return Value
+    /// Returns the endpoint, or [`None`] for a Unix domain socket
+    pub fn endpoint(&self) -> Option<&Endpoint> {
+        match self {
+            BindSpec::TCP(endpoint) | BindSpec::UDP(endpoint) => Some(endpoint),
+            BindSpec::Unix(_) => None,
+        }
+    }
+
+    /// Gets the path of the Unix domain socket to bind to
+    ///
+    /// ## Return Value
+    /// Returns the path, or [`None`] for a TCP or UDP socket
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            BindSpec::Unix(path) => Some(path),
+            BindSpec::TCP(_) | BindSpec::UDP(_) => None,
+        }
+    }
+
+    /// Resolves the socket addresses to bind to using the system resolver
+    ///
+    /// ## Return Value
+    /// Returns every socket address to bind to, which is empty for a Unix domain socket
+    pub fn socket_addrs(&self) -> std::io::Result<Vec<IPSocketAddress>> {
+        match self.endpoint() {
+            Some(endpoint) => endpoint.resolve(),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+impl From<IPSocketAddress> for BindSpec {
+    fn from(address: IPSocketAddress) -> Self {
+        BindSpec::TCP(address.into())
+    }
+}
+
+impl FromStr for BindSpec {
+    type Err = InvalidBindSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(path) = strip_prefix(s, "unix:") {
+            let path = path.strip_prefix("//").unwrap_or(path);
+            if path.is_empty() {
+                return Err(InvalidBindSpecError::EmptyPath);
+            }
+
+            return Ok(BindSpec::Unix(PathBuf::from(path)));
+        }
+
+        let (protocol, endpoint) = split_protocol(s);
+
+        let endpoint: Endpoint = endpoint
+            .parse()
+            .map_err(InvalidBindSpecError::InvalidEndpoint)?;
+        if endpoint.port().is_none() {
+            return Err(InvalidBindSpecError::MissingPort);
+        }
+
+        Ok(match protocol {
+            Protocol::TCP => BindSpec::TCP(endpoint),
+            Protocol::UDP => BindSpec::UDP(endpoint),
+        })
+    }
+}
+
+impl std::fmt::Display for BindSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindSpec::TCP(endpoint) => endpoint.fmt(f),
+            BindSpec::UDP(endpoint) => write!(f, "udp://{}", endpoint),
+            BindSpec::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+impl std::fmt::Debug for BindSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Splits the "tcp://" or "udp://" prefix from `s`
+///
+/// ## Return Value
+/// Returns the protocol, which is TCP if there is no prefix, and the rest of `s`
+pub(super) fn split_protocol(s: &str) -> (Protocol, &str) {
+    match strip_prefix(s, "udp://") {
+        Some(rest) => (Protocol::UDP, rest),
+        None => (Protocol::TCP, strip_prefix(s, "tcp://").unwrap_or(s)),
+    }
+}
+
+/// Removes `prefix` from the start of `s`, ignoring ASCII case
+fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
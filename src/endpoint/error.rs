@@ -0,0 +1,123 @@
+use crate::{host::InvalidHostError, ErrorKind};
+use std::num::ParseIntError;
+
+/// An error while parsing an endpoint
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidEndpointError {
+    /// The host is not valid
+    InvalidHost(InvalidHostError),
+
+    /// The port could not be parsed
+    InvalidPort(ParseIntError),
+
+    /// There is data after an IPv6 address which is not a port
+    ExtraContent,
+}
+
+impl InvalidEndpointError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidEndpointError::InvalidHost(error) => error.kind(),
+            InvalidEndpointError::InvalidPort(_) => ErrorKind::InvalidNumber,
+            InvalidEndpointError::ExtraContent => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidEndpointError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidEndpointError::InvalidHost(error) => Some(error),
+            InvalidEndpointError::InvalidPort(error) => Some(error),
+            InvalidEndpointError::ExtraContent => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidEndpointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidEndpointError::InvalidHost(error) => write!(f, "invalid host - {}", error),
+            InvalidEndpointError::InvalidPort(error) => write!(f, "invalid port - {}", error),
+            InvalidEndpointError::ExtraContent => write!(f, "data beyond IPv6 address"),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidEndpointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// An error while parsing a bind specification
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidBindSpecError {
+    /// The address to bind to is not a valid endpoint
+    InvalidEndpoint(InvalidEndpointError),
+
+    /// A TCP or UDP address does not have a port
+    MissingPort,
+
+    /// A Unix domain socket path is empty
+    EmptyPath,
+
+    /// An interface name is empty
+    EmptyInterface,
+
+    /// The port of an interface could not be parsed
+    InvalidPort(ParseIntError),
+}
+
+impl InvalidBindSpecError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidBindSpecError::InvalidEndpoint(error) => error.kind(),
+            InvalidBindSpecError::MissingPort
+            | InvalidBindSpecError::EmptyPath
+            | InvalidBindSpecError::EmptyInterface => ErrorKind::Missing,
+            InvalidBindSpecError::InvalidPort(_) => ErrorKind::InvalidNumber,
+        }
+    }
+}
+
+impl std::error::Error for InvalidBindSpecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidBindSpecError::InvalidEndpoint(error) => Some(error),
+            InvalidBindSpecError::InvalidPort(error) => Some(error),
+
+            InvalidBindSpecError::MissingPort
+            | InvalidBindSpecError::EmptyPath
+            | InvalidBindSpecError::EmptyInterface => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidBindSpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidBindSpecError::InvalidEndpoint(error) => write!(f, "{}", error),
+            InvalidBindSpecError::MissingPort => write!(f, "missing port"),
+            InvalidBindSpecError::EmptyPath => write!(f, "empty socket path"),
+            InvalidBindSpecError::EmptyInterface => write!(f, "empty interface name"),
+            InvalidBindSpecError::InvalidPort(error) => write!(f, "invalid port - {}", error),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidBindSpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,11 @@
+use super::Endpoint;
+use defmt::{Format, Formatter};
+
+impl Format for Endpoint {
+    fn format(&self, fmt: Formatter) {
+        match self.port {
+            Some(port) => defmt::write!(fmt, "{}:{=u16}", self.host, port),
+            None => self.host.format(fmt),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Error, ErrorKind};
+
+/// An item of a list is not valid
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidItemError<E> {
+    /// The position of the item in the list, starting from 1
+    pub item: usize,
+
+    /// The byte offset of the item within the input
+    pub offset: usize,
+
+    /// The reason the item is invalid
+    pub error: E,
+}
+
+impl InvalidItemError<Box<Error>> {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] of the reason the item is invalid
+    pub const fn kind(&self) -> ErrorKind {
+        (*self.error).kind()
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for InvalidItemError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for InvalidItemError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid item {} at byte {} - {}",
+            self.item, self.offset, self.error
+        )
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Debug for InvalidItemError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,62 @@
+//! Lists of endpoints or socket addresses, as written in configuration
+//!
+//! Items are separated by commas, whitespace, or both, so "10.0.0.1:53, [2001:db8::1]:53" holds
+//! two items. Empty items are skipped.
+
+use super::{Endpoint, InvalidEndpointError};
+use crate::port::Port;
+use std::str::FromStr;
+
+mod error;
+
+pub use error::InvalidItemError;
+
+/// Parses each item of a list
+///
+/// Any type which can be parsed from a string may be used, such as [`Endpoint`] or
+/// [`IPSocketAddress`](crate::ip::IPSocketAddress).
+///
+/// ## Parameters
+///  * `input` - The list to parse
+///
+/// ## Return Value
+/// Returns the result of parsing each item, in order
+pub fn parse<T: FromStr>(input: &str) -> Vec<Result<T, InvalidItemError<T::Err>>> {
+    parse_items(input, str::parse)
+}
+
+/// Parses each endpoint of a list, using `default_port` for endpoints without a port
+///
+/// ## Parameters
+///  * `input` - The list to parse
+///  * `default_port` - The port to use when an endpoint does not have one
+///
+/// ## Return Value
+/// Returns the result of parsing each endpoint, in order
+pub fn parse_with_default(
+    input: &str,
+    default_port: Port,
+) -> Vec<Result<Endpoint, InvalidItemError<InvalidEndpointError>>> {
+    parse_items(input, |item| {
+        Endpoint::parse_with_default(item, default_port)
+    })
+}
+
+/// Splits `input` into its items and parses each with `parse`
+fn parse_items<T, E>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Vec<Result<T, InvalidItemError<E>>> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .enumerate()
+        .map(|(i, item)| {
+            parse(item).map_err(|error| InvalidItemError {
+                item: i + 1,
+                offset: item.as_ptr() as usize - input.as_ptr() as usize,
+                error,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,191 @@
+use super::{bind::split_protocol, BindSpec, InvalidBindSpecError};
+use crate::{
+    ip::IPSocketAddress,
+    port::{Port, Protocol},
+};
+use std::{
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+/// The address a server listens on, which may be every address of a named interface
+///
+/// On top of the forms of a [`BindSpec`], an interface name prefixed with '%' and followed by a
+/// port, such as "%eth0:443", binds to every address assigned to that interface. A single label
+/// host like "eth0:443" is parsed as a host name, but also binds to the interface if one with
+/// that name exists when the addresses are resolved.
+///
+/// Finding the addresses of an interface requires the `os` feature.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ListenerSpec {
+    /// A specific address or Unix domain socket
+    Bind(BindSpec),
+
+    /// Every address of a named interface
+    Interface {
+        /// The name of the interface, such as "eth0"
+        name: String,
+
+        /// The port to listen on
+        port: Port,
+
+        /// The transport protocol to listen with
+        protocol: Protocol,
+    },
+}
+
+impl ListenerSpec {
+    /// Gets the transport protocol of this specification
+    ///
+    /// ## Return Value
+    /// Returns the protocol, or [`None`] for a Unix domain socket
+    pub fn protocol(&self) -> Option<Protocol> {
+        match self {
+            ListenerSpec::Bind(spec) => spec.protocol(),
+            ListenerSpec::Interface { protocol, .. } => Some(*protocol),
+        }
+    }
+
+    /// Gets the port to listen on
+    ///
+    /// ## Return Value
+    /// Returns the port, or [`None`] for a Unix domain socket
+    pub fn port(&self) -> Option<Port> {
+        match self {
+            ListenerSpec::Bind(spec) => spec.endpoint().and_then(|endpoint| endpoint.port()),
+            ListenerSpec::Interface { port, .. } => Some(*port),
+        }
+    }
+
+    /// Resolves the socket addresses to listen on
+    ///
+    /// Link-local IPv6 addresses of an interface are given the scope id of the interface.
+    ///
+    /// ## Return Value
+    /// Returns every socket address to listen on, which is empty for a Unix domain socket
+    pub fn socket_addrs(&self) -> std::io::Result<Vec<IPSocketAddress>> {
+        match self {
+            ListenerSpec::Bind(spec) => match interface_name(spec) {
+                Some((name, port)) => interface_socket_addrs(name, port),
+                None => spec.socket_addrs(),
+            },
+            ListenerSpec::Interface { name, port, .. } => interface_socket_addrs(name, *port),
+        }
+    }
+}
+
+impl From<BindSpec> for ListenerSpec {
+    fn from(spec: BindSpec) -> Self {
+        ListenerSpec::Bind(spec)
+    }
+}
+
+impl FromStr for ListenerSpec {
+    type Err = InvalidBindSpecError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (protocol, rest) = split_protocol(s);
+        let interface = match rest.strip_prefix('%') {
+            Some(interface) => interface,
+            None => return s.parse().map(ListenerSpec::Bind),
+        };
+
+        let (name, port) = interface
+            .rsplit_once(':')
+            .ok_or(InvalidBindSpecError::MissingPort)?;
+        if name.is_empty() {
+            return Err(InvalidBindSpecError::EmptyInterface);
+        }
+
+        Ok(ListenerSpec::Interface {
+            name: name.to_owned(),
+            port: port.parse().map_err(InvalidBindSpecError::InvalidPort)?,
+            protocol,
+        })
+    }
+}
+
+impl std::fmt::Display for ListenerSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListenerSpec::Bind(spec) => spec.fmt(f),
+            ListenerSpec::Interface {
+                name,
+                port,
+                protocol,
+            } => {
+                if *protocol == Protocol::UDP {
+                    f.write_str("udp://")?;
+                }
+
+                write!(f, "%{}:{}", name, port)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for ListenerSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Gets the interface named by the host of `spec`, if it is a single label naming a local
+/// interface
+#[cfg(feature = "os")]
+fn interface_name(spec: &BindSpec) -> Option<(&str, Port)> {
+    use crate::host::Host;
+
+    let endpoint = spec.endpoint()?;
+    let name = match endpoint.host() {
+        Host::Domain(domain) if domain.label_count() == 1 && !domain.is_fqdn() => {
+            domain.labels().next()?.as_str()
+        }
+        _ => return None,
+    };
+
+    crate::os::interface_index(name)?;
+    Some((name, endpoint.port()?))
+}
+
+/// Gets the interface named by the host of `spec`, which is never done without the `os` feature
+#[cfg(not(feature = "os"))]
+fn interface_name(_: &BindSpec) -> Option<(&str, Port)> {
+    None
+}
+
+/// Gets the socket addresses for `port` on every address of the interface `name`
+#[cfg(feature = "os")]
+fn interface_socket_addrs(name: &str, port: Port) -> std::io::Result<Vec<IPSocketAddress>> {
+    use crate::ip::{v6::IPv6SocketAddress, IPAddress};
+
+    let index = crate::os::interface_index(name).unwrap_or(0);
+    let addresses: Vec<_> = crate::os::interface_addresses()?
+        .into_iter()
+        .filter(|address| address.name == name)
+        .map(|address| match address.address {
+            IPAddress::V6(address) if address.segments()[0] & 0xFFC0 == 0xFE80 => {
+                IPSocketAddress::V6(IPv6SocketAddress::new(address, port, 0, index))
+            }
+            address => IPSocketAddress::new(address, port),
+        })
+        .collect();
+
+    if addresses.is_empty() {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!("interface \"{}\" has no addresses", name),
+        ));
+    }
+
+    Ok(addresses)
+}
+
+/// Gets the socket addresses of an interface, which requires the `os` feature
+#[cfg(not(feature = "os"))]
+fn interface_socket_addrs(_: &str, _: Port) -> std::io::Result<Vec<IPSocketAddress>> {
+    Err(Error::new(
+        ErrorKind::Unsupported,
+        "binding to an interface requires the os feature",
+    ))
+}
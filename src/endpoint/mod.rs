@@ -0,0 +1,199 @@
+//! Endpoints made of a host and an optional port
+
+use crate::{host::Host, ip::IPSocketAddress, port::Port};
+use std::str::FromStr;
+
+pub mod list;
+
+mod bind;
+mod error;
+mod listener;
+mod resolve;
+
+#[cfg(feature = "async")]
+mod async_resolve;
+#[cfg(feature = "defmt")]
+mod format;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use bind::BindSpec;
+pub use error::{InvalidBindSpecError, InvalidEndpointError};
+pub use listener::ListenerSpec;
+pub use resolve::{Resolve, SystemResolver};
+
+#[cfg(feature = "async")]
+pub use async_resolve::AsyncResolve;
+
+/// A host with an optional port, such as "example.com:443", "10.0.0.1:80", or "[::1]:8080"
+///
+/// IPv6 addresses with a port must be written in brackets. An IPv6 address without brackets is
+/// accepted when there is no port, so "::1" is parsed as a host alone.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Endpoint {
+    host: Host,
+    port: Option<Port>,
+}
+
+impl Endpoint {
+    /// Creates a new [`Endpoint`]
+    ///
+    /// ## Parameters
+    ///  * `host` - The host to connect to
+    ///  * `port` - The port to connect to, if one is known
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Endpoint`]
+    pub const fn new(host: Host, port: Option<Port>) -> Self {
+        Endpoint { host, port }
+    }
+
+    /// Parses an endpoint, using `default_port` if it does not have a port
+    ///
+    /// ## Parameters
+    ///  * `s` - The endpoint to parse, such as "example.com" or "example.com:8443"
+    ///  * `default_port` - The port to use when `s` does not have one
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`Endpoint`], which always has a port
+    pub fn parse_with_default(s: &str, default_port: Port) -> Result<Self, InvalidEndpointError> {
+        let mut endpoint: Endpoint = s.parse()?;
+        endpoint.port.get_or_insert(default_port);
+        Ok(endpoint)
+    }
+
+    /// Gets the host of this endpoint
+    ///
+    /// ## Return Value
+    /// Returns the host of this endpoint
+    pub const fn host(&self) -> &Host {
+        &self.host
+    }
+
+    /// Gets the port of this endpoint
+    ///
+    /// ## Return Value
+    /// Returns the port of this endpoint, if it has one
+    pub const fn port(&self) -> Option<Port> {
+        self.port
+    }
+
+    /// Sets the host of this endpoint
+    ///
+    /// ## Parameters
+    ///  * `host` - The new host
+    pub fn set_host(&mut self, host: Host) {
+        self.host = host;
+    }
+
+    /// Sets the port of this endpoint
+    ///
+    /// ## Parameters
+    ///  * `port` - The new port, or [`None`] to remove it
+    pub fn set_port(&mut self, port: Option<Port>) {
+        self.port = port;
+    }
+
+    /// Resolves this endpoint into socket addresses using the system resolver
+    ///
+    /// ## Return Value
+    /// Returns every socket address of this endpoint, or an error if it has no port or the
+    /// lookup fails
+    pub fn resolve(&self) -> std::io::Result<Vec<IPSocketAddress>> {
+        self.resolve_with(&SystemResolver)
+    }
+
+    /// Resolves this endpoint into socket addresses using `resolver`
+    ///
+    /// ## Parameters
+    ///  * `resolver` - The resolver to use
+    ///
+    /// ## Return Value
+    /// Returns every socket address of this endpoint
+    pub fn resolve_with<R: Resolve + ?Sized>(
+        &self,
+        resolver: &R,
+    ) -> std::io::Result<Vec<IPSocketAddress>> {
+        resolver.resolve(self)
+    }
+
+    /// Gets the socket address of this endpoint
+    ///
+    /// ## Return Value
+    /// Returns the socket address if the host is an IP address and there is a port
+    pub fn socket_addr(&self) -> Option<IPSocketAddress> {
+        Some(IPSocketAddress::new(self.host.ip()?, self.port?))
+    }
+}
+
+impl From<Host> for Endpoint {
+    fn from(host: Host) -> Self {
+        Endpoint::new(host, None)
+    }
+}
+
+impl From<(Host, Port)> for Endpoint {
+    fn from((host, port): (Host, Port)) -> Self {
+        Endpoint::new(host, Some(port))
+    }
+}
+
+impl From<IPSocketAddress> for Endpoint {
+    fn from(address: IPSocketAddress) -> Self {
+        Endpoint::new(address.ip().into(), Some(address.port()))
+    }
+}
+
+impl FromStr for Endpoint {
+    type Err = InvalidEndpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (host, port) = if s.starts_with('[') {
+            match s.find(']') {
+                Some(end) => match &s[end + 1..] {
+                    "" => (s, None),
+                    rest => (
+                        &s[..end + 1],
+                        Some(
+                            rest.strip_prefix(':')
+                                .ok_or(InvalidEndpointError::ExtraContent)?,
+                        ),
+                    ),
+                },
+                None => (s, None),
+            }
+        } else {
+            match s.split_once(':') {
+                Some((host, port)) if !port.contains(':') => (host, Some(port)),
+                _ => (s, None),
+            }
+        };
+
+        let host = host.parse().map_err(InvalidEndpointError::InvalidHost)?;
+        let port = port
+            .map(str::parse)
+            .transpose()
+            .map_err(InvalidEndpointError::InvalidPort)?;
+
+        Ok(Endpoint::new(host, port))
+    }
+}
+
+impl std::fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.host.fmt(f)?;
+
+        match self.port {
+            Some(port) => write!(f, ":{}", port),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for Endpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
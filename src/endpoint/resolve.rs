@@ -0,0 +1,57 @@
+use super::Endpoint;
+use crate::{dns::LabelForm, host::Host, ip::IPSocketAddress};
+use std::{
+    io::{Error, ErrorKind},
+    net::ToSocketAddrs,
+};
+
+/// Turns an [`Endpoint`] into the socket addresses it refers to
+///
+/// Implement this to supply a custom or mock resolver in place of the system resolver. Closures
+/// taking an endpoint and returning the addresses implement it as well.
+pub trait Resolve {
+    /// Resolves `endpoint` into socket addresses
+    ///
+    /// ## Parameters
+    ///  * `endpoint` - The endpoint to resolve, which must have a port
+    ///
+    /// ## Return Value
+    /// Returns every socket address of the endpoint
+    fn resolve(&self, endpoint: &Endpoint) -> std::io::Result<Vec<IPSocketAddress>>;
+}
+
+/// A [`Resolve`] implementation using the resolver of the operating system
+///
+/// Domain names are looked up in their ASCII form through [`ToSocketAddrs`], while IP addresses
+/// are returned without any lookup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemResolver;
+
+impl Resolve for SystemResolver {
+    fn resolve(&self, endpoint: &Endpoint) -> std::io::Result<Vec<IPSocketAddress>> {
+        endpoint.to_socket_addrs().map(Iterator::collect)
+    }
+}
+
+impl<F: Fn(&Endpoint) -> std::io::Result<Vec<IPSocketAddress>>> Resolve for F {
+    fn resolve(&self, endpoint: &Endpoint) -> std::io::Result<Vec<IPSocketAddress>> {
+        self(endpoint)
+    }
+}
+
+impl ToSocketAddrs for Endpoint {
+    type Iter = std::vec::IntoIter<IPSocketAddress>;
+
+    fn to_socket_addrs(&self) -> std::io::Result<Self::Iter> {
+        let port = self
+            .port
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "endpoint has no port"))?;
+
+        match &self.host {
+            Host::Domain(domain) => {
+                (domain.display(LabelForm::ASCII).to_string(), port).to_socket_addrs()
+            }
+            host => Ok(vec![IPSocketAddress::new(host.ip().unwrap(), port)].into_iter()),
+        }
+    }
+}
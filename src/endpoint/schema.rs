@@ -0,0 +1,21 @@
+use super::Endpoint;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+impl JsonSchema for Endpoint {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Endpoint".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "A host with an optional port, such as \"example.com:443\" or \"[::1]:8080\"",
+            "pattern": "^(\\[[^\\]]+\\](:[0-9]{1,5})?|[^\\[\\]:]+(:[0-9]{1,5})?|[0-9A-Fa-f.]*:[0-9A-Fa-f:.]*)$"
+        })
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{
+    arp::InvalidARPPacketError,
+    bgp::{InvalidASNError, InvalidCommunityError},
+    dns::{
+        InvalidDomainNameError, InvalidHostnameError, InvalidRecordNameError,
+        InvalidReverseNameError, InvalidServiceNameError, InvalidWireNameError,
+    },
+    endpoint::{list::InvalidItemError, InvalidBindSpecError, InvalidEndpointError},
+    ethernet::{InvalidEthernetHeaderError, InvalidVLANIDError},
+    host::InvalidHostError,
+    icmp::InvalidICMPMessageError,
+    ip::{
+        InvalidCIDRError, InvalidIPv4HeaderError, InvalidIPv6HeaderError, InvalidPrefixError,
+        InvalidZonedAddressError,
+    },
+    mac::{InvalidMACAddress, InvalidOUIRegistryError},
+    mtu::InvalidMTUError,
+    port::{InvalidPortRangeError, InvalidProtocolError},
+    punycode::InvalidPunycodeError,
+    rate::InvalidDataRateError,
+    transport::{InvalidTCPHeaderError, InvalidUDPHeaderError},
+    tunnel::InvalidTunnelIDError,
+    uri::{
+        uri_list::InvalidLineError, InvalidComponentError, InvalidSchemeError, InvalidURIError,
+        LimitExceededError,
+    },
+};
+
+/// Generates [`Error`] with a variant and a [`From`] conversion for each error in this crate
+macro_rules! errors {
+    ($($(#[cfg($cfg: meta)])? #[doc = $doc: literal] $variant: ident($error: ty),)*) => {
+        /// Any error from this crate
+        ///
+        /// Every error in this crate converts into this, so code which parses many kinds of
+        /// values can use `?` with a single error type. It displays as, and has the same source
+        /// as, the error it holds.
+        #[derive(Clone, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum Error {
+            $(
+                $(#[cfg($cfg)])?
+                #[doc = $doc]
+                $variant($error),
+            )*
+        }
+
+        impl Error {
+            /// Gets the broad category of this error
+            ///
+            /// ## Return Value
+            /// Returns the [`ErrorKind`] of the error this holds
+            pub const fn kind(&self) -> ErrorKind {
+                match self {
+                    $($(#[cfg($cfg)])? Error::$variant(error) => error.kind(),)*
+                }
+            }
+        }
+
+        $(
+            $(#[cfg($cfg)])?
+            impl From<$error> for Error {
+                fn from(error: $error) -> Self {
+                    Error::$variant(error)
+                }
+            }
+        )*
+
+        impl std::error::Error for Error {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                match self {
+                    $($(#[cfg($cfg)])? Error::$variant(error) => error.source(),)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for Error {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($(#[cfg($cfg)])? Error::$variant(error) => std::fmt::Display::fmt(error, f),)*
+                }
+            }
+        }
+    };
+}
+
+errors! {
+    /// An ARP packet is invalid
+    ARPPacket(InvalidARPPacketError),
+    /// An autonomous system number is invalid
+    ASN(InvalidASNError),
+    /// A BGP community is invalid
+    Community(InvalidCommunityError),
+    /// A domain name is invalid
+    DomainName(InvalidDomainNameError),
+    /// A host name is invalid
+    Hostname(InvalidHostnameError),
+    /// A service record name is invalid
+    RecordName(InvalidRecordNameError),
+    /// A reverse lookup name is invalid
+    ReverseName(InvalidReverseNameError),
+    /// A DNS-SD service instance name is invalid
+    ServiceName(InvalidServiceNameError),
+    /// A domain name in wire format is invalid
+    WireName(InvalidWireNameError),
+    #[cfg(feature = "idna")]
+    /// A domain name could not be converted between its ASCII and Unicode forms
+    IDNA(crate::dns::IDNAError),
+    /// An endpoint is invalid
+    Endpoint(InvalidEndpointError),
+    /// A bind specification is invalid
+    BindSpec(InvalidBindSpecError),
+    /// An item of a list is invalid
+    ListItem(InvalidItemError<Box<Error>>),
+    /// An Ethernet header is invalid
+    EthernetHeader(InvalidEthernetHeaderError),
+    /// A VLAN identifier is invalid
+    VLANID(InvalidVLANIDError),
+    /// A host is invalid
+    Host(InvalidHostError),
+    /// An ICMP message is invalid
+    ICMPMessage(InvalidICMPMessageError),
+    /// A CIDR is invalid
+    CIDR(InvalidCIDRError),
+    /// A CIDR prefix is invalid
+    Prefix(InvalidPrefixError),
+    /// An IPv6 socket address with a zone is invalid
+    ZonedAddress(InvalidZonedAddressError),
+    /// An IPv4 header is invalid
+    IPv4Header(InvalidIPv4HeaderError),
+    /// An IPv6 header is invalid
+    IPv6Header(InvalidIPv6HeaderError),
+    /// A MAC address is invalid
+    MACAddress(InvalidMACAddress),
+    /// An IEEE registry file is invalid
+    OUIRegistry(InvalidOUIRegistryError),
+    /// An MTU is invalid
+    MTU(InvalidMTUError),
+    /// A port range is invalid
+    PortRange(InvalidPortRangeError),
+    /// A transport protocol is invalid
+    Protocol(InvalidProtocolError),
+    /// A punycode label is invalid
+    Punycode(InvalidPunycodeError),
+    /// A data rate is invalid
+    DataRate(InvalidDataRateError),
+    /// A UDP header is invalid
+    UDPHeader(InvalidUDPHeaderError),
+    /// A TCP header is invalid
+    TCPHeader(InvalidTCPHeaderError),
+    /// A tunnel identifier is invalid
+    TunnelID(InvalidTunnelIDError),
+    /// A URI is invalid
+    URI(InvalidURIError),
+    /// A component of a URI is invalid
+    URIComponent(InvalidComponentError),
+    /// A URI scheme is invalid
+    Scheme(InvalidSchemeError),
+    /// A URI parsing limit was exceeded
+    LimitExceeded(LimitExceededError),
+    #[cfg(feature = "serde")]
+    /// Form data could not be serialized or deserialized
+    Form(crate::uri::form::FormError),
+    /// A line of a URI list is invalid
+    URILine(InvalidLineError),
+}
+
+impl<E: Into<Error>> From<InvalidItemError<E>> for Error {
+    fn from(error: InvalidItemError<E>) -> Self {
+        Error::ListItem(InvalidItemError {
+            item: error.item,
+            offset: error.offset,
+            error: Box::new(error.error.into()),
+        })
+    }
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// The broad category of an error from this crate
+///
+/// Every error has a `kind()` which returns one of these, so callers can handle similar problems
+/// the same way without matching every variant of every error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input ends before a complete value was read
+    Truncated,
+
+    /// A required part of the input is missing or empty
+    Missing,
+
+    /// The input contains a character which is not allowed where it appears
+    InvalidCharacter,
+
+    /// A number could not be parsed
+    InvalidNumber,
+
+    /// A value is outside of the range allowed for it
+    OutOfRange,
+
+    /// The input or a part of it is longer or larger than allowed
+    TooLong,
+
+    /// The input does not have the expected structure
+    InvalidFormat,
+
+    /// The input is well formed but describes something which is not supported
+    Unsupported,
+
+    /// An error raised outside of this crate, such as by a serializer
+    Other,
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ErrorKind::Truncated => "truncated",
+            ErrorKind::Missing => "missing",
+            ErrorKind::InvalidCharacter => "invalid character",
+            ErrorKind::InvalidNumber => "invalid number",
+            ErrorKind::OutOfRange => "out of range",
+            ErrorKind::TooLong => "too long",
+            ErrorKind::InvalidFormat => "invalid format",
+            ErrorKind::Unsupported => "unsupported",
+            ErrorKind::Other => "other",
+        })
+    }
+}
+
+/// Fails to compile if any error can not be sent or shared between threads
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    assert_send_sync::<Error>();
+    assert_send_sync::<InvalidARPPacketError>();
+    assert_send_sync::<InvalidASNError>();
+    assert_send_sync::<InvalidCommunityError>();
+    assert_send_sync::<InvalidDomainNameError>();
+    assert_send_sync::<InvalidHostnameError>();
+    assert_send_sync::<InvalidRecordNameError>();
+    assert_send_sync::<InvalidReverseNameError>();
+    assert_send_sync::<InvalidServiceNameError>();
+    assert_send_sync::<InvalidWireNameError>();
+    #[cfg(feature = "idna")]
+    assert_send_sync::<crate::dns::IDNAError>();
+    assert_send_sync::<InvalidEndpointError>();
+    assert_send_sync::<InvalidBindSpecError>();
+    assert_send_sync::<InvalidItemError<InvalidEndpointError>>();
+    assert_send_sync::<InvalidEthernetHeaderError>();
+    assert_send_sync::<InvalidVLANIDError>();
+    assert_send_sync::<InvalidHostError>();
+    assert_send_sync::<InvalidICMPMessageError>();
+    assert_send_sync::<InvalidCIDRError>();
+    assert_send_sync::<InvalidPrefixError>();
+    assert_send_sync::<InvalidZonedAddressError>();
+    assert_send_sync::<InvalidIPv4HeaderError>();
+    assert_send_sync::<InvalidIPv6HeaderError>();
+    assert_send_sync::<InvalidMACAddress>();
+    assert_send_sync::<InvalidOUIRegistryError>();
+    assert_send_sync::<InvalidMTUError>();
+    assert_send_sync::<InvalidPortRangeError>();
+    assert_send_sync::<InvalidProtocolError>();
+    assert_send_sync::<InvalidPunycodeError>();
+    assert_send_sync::<InvalidDataRateError>();
+    assert_send_sync::<InvalidUDPHeaderError>();
+    assert_send_sync::<InvalidTCPHeaderError>();
+    assert_send_sync::<InvalidTunnelIDError>();
+    assert_send_sync::<InvalidURIError>();
+    assert_send_sync::<InvalidComponentError>();
+    assert_send_sync::<InvalidSchemeError>();
+    assert_send_sync::<LimitExceededError>();
+    #[cfg(feature = "serde")]
+    assert_send_sync::<crate::uri::form::FormError>();
+    assert_send_sync::<InvalidLineError>();
+};
@@ -0,0 +1,103 @@
+use crate::ErrorKind;
+
+/// An error while parsing an Ethernet frame header
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidEthernetHeaderError {
+    /// The frame ends before the header does
+    Truncated,
+
+    /// The frame has more VLAN tags than a header can hold
+    TooManyTags,
+}
+
+impl InvalidEthernetHeaderError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidEthernetHeaderError::Truncated => ErrorKind::Truncated,
+            InvalidEthernetHeaderError::TooManyTags => ErrorKind::Unsupported,
+        }
+    }
+}
+
+impl std::error::Error for InvalidEthernetHeaderError {}
+
+impl std::fmt::Display for InvalidEthernetHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidEthernetHeaderError::Truncated => write!(f, "Ethernet header is truncated"),
+            InvalidEthernetHeaderError::TooManyTags => write!(
+                f,
+                "Ethernet header has more than {} VLAN tags",
+                super::EthernetHeader::MAX_TAGS
+            ),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidEthernetHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// An error while parsing a VLAN identifier
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidVLANIDError {
+    /// The identifier is not a number
+    InvalidNumber(std::num::ParseIntError),
+
+    /// The identifier is larger than 4095
+    OutOfRange(u16),
+
+    /// A stacked pair is missing the '.' and inner identifier
+    MissingInnerID,
+}
+
+impl InvalidVLANIDError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidVLANIDError::InvalidNumber(_) => ErrorKind::InvalidNumber,
+            InvalidVLANIDError::OutOfRange(_) => ErrorKind::OutOfRange,
+            InvalidVLANIDError::MissingInnerID => ErrorKind::Missing,
+        }
+    }
+}
+
+impl std::error::Error for InvalidVLANIDError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidVLANIDError::InvalidNumber(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidVLANIDError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidVLANIDError::InvalidNumber(error) => {
+                write!(f, "invalid VLAN identifier - {}", error)
+            }
+            InvalidVLANIDError::OutOfRange(id) => {
+                write!(f, "VLAN identifier {} exceeds the maximum of 4095", id)
+            }
+            InvalidVLANIDError::MissingInnerID => write!(f, "missing inner VLAN identifier"),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidVLANIDError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
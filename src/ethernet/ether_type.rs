@@ -0,0 +1,173 @@
+/// The protocol of the payload of an Ethernet frame
+///
+/// Values of 1500 or less are not EtherTypes but the payload length of an IEEE 802.3 frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EtherType {
+    /// Internet Protocol version 4
+    IPv4,
+
+    /// Address Resolution Protocol
+    ARP,
+
+    /// Wake-on-LAN magic packet
+    WakeOnLAN,
+
+    /// Reverse Address Resolution Protocol
+    RARP,
+
+    /// IEEE 802.1Q VLAN tag, also used as the customer tag of stacked VLANs
+    VLAN,
+
+    /// Internet Protocol version 6
+    IPv6,
+
+    /// MPLS unicast
+    MPLS,
+
+    /// MPLS multicast
+    MPLSMulticast,
+
+    /// PPP over Ethernet discovery stage
+    PPPoEDiscovery,
+
+    /// PPP over Ethernet session stage
+    PPPoESession,
+
+    /// IEEE 802.1X EAP over LAN
+    EAPOL,
+
+    /// IEEE 802.1ad service VLAN tag
+    ServiceVLAN,
+
+    /// Link Layer Discovery Protocol
+    LLDP,
+
+    /// IEEE 802.1AE MAC security
+    MACsec,
+
+    /// Precision Time Protocol
+    PTP,
+
+    /// Service VLAN tag used before IEEE 802.1ad
+    LegacyServiceVLAN,
+
+    /// Any other EtherType
+    Other(u16),
+}
+
+impl EtherType {
+    /// Creates the [`EtherType`] with the value `value`
+    ///
+    /// ## Parameters
+    ///  * `value` - The numeric EtherType
+    ///
+    /// ## Return Value
+    /// Returns the well-known [`EtherType`] with `value`, or [`EtherType::Other`]
+    pub const fn new(value: u16) -> Self {
+        match value {
+            0x0800 => EtherType::IPv4,
+            0x0806 => EtherType::ARP,
+            0x0842 => EtherType::WakeOnLAN,
+            0x8035 => EtherType::RARP,
+            0x8100 => EtherType::VLAN,
+            0x86DD => EtherType::IPv6,
+            0x8847 => EtherType::MPLS,
+            0x8848 => EtherType::MPLSMulticast,
+            0x8863 => EtherType::PPPoEDiscovery,
+            0x8864 => EtherType::PPPoESession,
+            0x888E => EtherType::EAPOL,
+            0x88A8 => EtherType::ServiceVLAN,
+            0x88CC => EtherType::LLDP,
+            0x88E5 => EtherType::MACsec,
+            0x88F7 => EtherType::PTP,
+            0x9100 => EtherType::LegacyServiceVLAN,
+            _ => EtherType::Other(value),
+        }
+    }
+
+    /// Gets the numeric value of this EtherType
+    ///
+    /// ## Return Value
+    /// Returns the value carried in a frame
+    pub const fn value(&self) -> u16 {
+        match self {
+            EtherType::IPv4 => 0x0800,
+            EtherType::ARP => 0x0806,
+            EtherType::WakeOnLAN => 0x0842,
+            EtherType::RARP => 0x8035,
+            EtherType::VLAN => 0x8100,
+            EtherType::IPv6 => 0x86DD,
+            EtherType::MPLS => 0x8847,
+            EtherType::MPLSMulticast => 0x8848,
+            EtherType::PPPoEDiscovery => 0x8863,
+            EtherType::PPPoESession => 0x8864,
+            EtherType::EAPOL => 0x888E,
+            EtherType::ServiceVLAN => 0x88A8,
+            EtherType::LLDP => 0x88CC,
+            EtherType::MACsec => 0x88E5,
+            EtherType::PTP => 0x88F7,
+            EtherType::LegacyServiceVLAN => 0x9100,
+            EtherType::Other(value) => *value,
+        }
+    }
+
+    /// Gets the name of this EtherType
+    ///
+    /// ## Return Value
+    /// Returns the name of a well-known EtherType, or [`None`] for [`EtherType::Other`]
+    pub const fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            EtherType::IPv4 => "IPv4",
+            EtherType::ARP => "ARP",
+            EtherType::WakeOnLAN => "Wake-on-LAN",
+            EtherType::RARP => "RARP",
+            EtherType::VLAN => "802.1Q",
+            EtherType::IPv6 => "IPv6",
+            EtherType::MPLS => "MPLS",
+            EtherType::MPLSMulticast => "MPLS multicast",
+            EtherType::PPPoEDiscovery => "PPPoE discovery",
+            EtherType::PPPoESession => "PPPoE session",
+            EtherType::EAPOL => "EAPOL",
+            EtherType::ServiceVLAN => "802.1ad",
+            EtherType::LLDP => "LLDP",
+            EtherType::MACsec => "MACsec",
+            EtherType::PTP => "PTP",
+            EtherType::LegacyServiceVLAN => "QinQ",
+            EtherType::Other(_) => return None,
+        })
+    }
+
+    /// Checks if this EtherType marks a VLAN tag
+    ///
+    /// ## Return Value
+    /// Returns true for [`EtherType::VLAN`], [`EtherType::ServiceVLAN`], and
+    /// [`EtherType::LegacyServiceVLAN`]
+    pub const fn is_vlan_tag(&self) -> bool {
+        matches!(
+            self,
+            EtherType::VLAN | EtherType::ServiceVLAN | EtherType::LegacyServiceVLAN
+        )
+    }
+}
+
+impl From<u16> for EtherType {
+    fn from(value: u16) -> Self {
+        EtherType::new(value)
+    }
+}
+
+impl From<EtherType> for u16 {
+    fn from(ether_type: EtherType) -> Self {
+        ether_type.value()
+    }
+}
+
+impl std::fmt::Display for EtherType {
+    /// Writes the name and value, such as "IPv4 (0x0800)", or only the value if there is no name
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{} (0x{:04X})", name, self.value()),
+            None => write!(f, "0x{:04X}", self.value()),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+/// The reflected CRC-32 polynomial from IEEE 802.3
+const POLYNOMIAL: u32 = 0xEDB88320;
+
+/// The remainder of each byte value, used to process a byte at a time
+const TABLE: [u32; 256] = generate_table();
+
+/// Computes the frame check sequence of Ethernet frames given in pieces
+///
+/// The frame check sequence is the CRC-32 from IEEE 802.3, calculated over the frame from the
+/// destination address to the end of the payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FCS {
+    crc: u32,
+}
+
+impl FCS {
+    /// Creates a new [`FCS`] with nothing added
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`FCS`]
+    pub const fn new() -> Self {
+        FCS { crc: 0xFFFFFFFF }
+    }
+
+    /// Adds bytes of the frame
+    ///
+    /// ## Parameters
+    ///  * `data` - The next bytes of the frame
+    pub fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.crc = TABLE[((self.crc ^ *byte as u32) & 0xFF) as usize] ^ (self.crc >> 8);
+        }
+    }
+
+    /// Completes the frame check sequence
+    ///
+    /// ## Return Value
+    /// Returns the frame check sequence of everything added, which is transmitted in
+    /// little-endian order
+    pub const fn finish(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl Default for FCS {
+    fn default() -> Self {
+        FCS::new()
+    }
+}
+
+/// Computes the frame check sequence of `frame`
+///
+/// ## Parameters
+///  * `frame` - The frame from the destination address to the end of the payload
+///
+/// ## Return Value
+/// Returns the frame check sequence, which is transmitted in little-endian order
+pub fn fcs(frame: &[u8]) -> u32 {
+    let mut fcs = FCS::new();
+    fcs.update(frame);
+    fcs.finish()
+}
+
+/// Verifies the frame check sequence at the end of `frame`
+///
+/// ## Parameters
+///  * `frame` - The frame from the destination address to the end of the frame check sequence
+///
+/// ## Return Value
+/// Returns true if `frame` ends in a correct frame check sequence
+pub fn verify_fcs(frame: &[u8]) -> bool {
+    if frame.len() < 4 {
+        return false;
+    }
+
+    let (frame, sequence) = frame.split_at(frame.len() - 4);
+    fcs(frame) == u32::from_le_bytes([sequence[0], sequence[1], sequence[2], sequence[3]])
+}
+
+/// Generates the remainder of each byte value
+const fn generate_table() -> [u32; 256] {
+    let mut table = [0; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+}
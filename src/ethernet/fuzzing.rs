@@ -0,0 +1,8 @@
+use super::VLANID;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for VLANID {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.int_in_range(0..=VLANID::MAX).map(VLANID::from_bits)
+    }
+}
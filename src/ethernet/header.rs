@@ -0,0 +1,188 @@
+use super::{EtherType, InvalidEthernetHeaderError, QinQ, VLANTag, VLANID};
+use crate::mac::MACAddress;
+
+/// The header of an Ethernet II frame, with up to two IEEE 802.1Q tags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EthernetHeader {
+    destination: MACAddress,
+    source: MACAddress,
+    tags: [VLANTag; EthernetHeader::MAX_TAGS],
+    tag_count: usize,
+    ether_type: EtherType,
+}
+
+impl EthernetHeader {
+    /// The most VLAN tags a header can hold
+    pub const MAX_TAGS: usize = 2;
+
+    /// The length of a header without any VLAN tags
+    pub const MIN_LENGTH: usize = 14;
+
+    /// The length of each VLAN tag
+    pub const TAG_LENGTH: usize = 4;
+
+    /// Creates a new untagged [`EthernetHeader`]
+    ///
+    /// ## Parameters
+    ///  * `destination` - The address the frame is sent to
+    ///  * `source` - The address the frame is sent from
+    ///  * `ether_type` - The protocol of the payload
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`EthernetHeader`]
+    pub const fn new(destination: MACAddress, source: MACAddress, ether_type: EtherType) -> Self {
+        EthernetHeader {
+            destination,
+            source,
+            tags: [VLANTag::new(VLANID::NULL); EthernetHeader::MAX_TAGS],
+            tag_count: 0,
+            ether_type,
+        }
+    }
+
+    /// Sets a single VLAN tag, replacing any existing tags
+    ///
+    /// ## Parameters
+    ///  * `tag` - The VLAN tag
+    ///
+    /// ## Return Value
+    /// Returns the modified [`EthernetHeader`]
+    pub const fn with_tag(mut self, tag: VLANTag) -> Self {
+        self.tags = [tag, VLANTag::new(VLANID::NULL)];
+        self.tag_count = 1;
+        self
+    }
+
+    /// Sets a pair of stacked VLAN tags, replacing any existing tags
+    ///
+    /// ## Parameters
+    ///  * `outer` - The outer tag, usually a service tag
+    ///  * `inner` - The inner tag, usually a customer tag
+    ///
+    /// ## Return Value
+    /// Returns the modified [`EthernetHeader`]
+    pub const fn with_tags(mut self, outer: VLANTag, inner: VLANTag) -> Self {
+        self.tags = [outer, inner];
+        self.tag_count = 2;
+        self
+    }
+
+    /// Sets a service tag and a customer tag carrying a pair of stacked VLAN identifiers,
+    /// replacing any existing tags
+    ///
+    /// ## Parameters
+    ///  * `ids` - The VLAN identifiers of the outer and inner tags
+    ///
+    /// ## Return Value
+    /// Returns the modified [`EthernetHeader`]
+    pub const fn with_qinq(self, ids: QinQ) -> Self {
+        self.with_tags(
+            VLANTag::new(ids.outer()).with_protocol(EtherType::ServiceVLAN),
+            VLANTag::new(ids.inner()),
+        )
+    }
+
+    /// Parses the header at the start of `frame`
+    ///
+    /// The payload is borrowed from `frame` rather than copied. Any frame check sequence is left
+    /// at the end of the payload.
+    ///
+    /// ## Parameters
+    ///  * `frame` - The frame, starting at the destination address
+    ///
+    /// ## Return Value
+    /// Returns the parsed header and the payload following it
+    pub fn parse(frame: &[u8]) -> Result<(Self, &[u8]), InvalidEthernetHeaderError> {
+        let (addresses, mut rest) = split(frame, 12)?;
+        let destination = MACAddress::new(addresses[..6].try_into().unwrap());
+        let source = MACAddress::new(addresses[6..].try_into().unwrap());
+
+        let mut header = EthernetHeader::new(destination, source, EtherType::Other(0));
+        loop {
+            let (ether_type, after) = split(rest, 2)?;
+            let ether_type = EtherType::new(u16::from_be_bytes([ether_type[0], ether_type[1]]));
+            rest = after;
+
+            if !ether_type.is_vlan_tag() {
+                header.ether_type = ether_type;
+                return Ok((header, rest));
+            }
+
+            if header.tag_count == EthernetHeader::MAX_TAGS {
+                return Err(InvalidEthernetHeaderError::TooManyTags);
+            }
+
+            let (control, after) = split(rest, 2)?;
+            header.tags[header.tag_count] =
+                VLANTag::from_control(ether_type, u16::from_be_bytes([control[0], control[1]]));
+            header.tag_count += 1;
+            rest = after;
+        }
+    }
+
+    /// Encodes this header
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded header to
+    pub fn write(&self, output: &mut Vec<u8>) {
+        output.reserve(self.wire_len());
+        output.extend_from_slice(self.destination.as_slice());
+        output.extend_from_slice(self.source.as_slice());
+
+        for tag in self.tags() {
+            output.extend_from_slice(&tag.protocol().value().to_be_bytes());
+            output.extend_from_slice(&tag.control().to_be_bytes());
+        }
+
+        output.extend_from_slice(&self.ether_type.value().to_be_bytes());
+    }
+
+    /// Gets the length of this header once encoded
+    ///
+    /// ## Return Value
+    /// Returns the number of bytes [`EthernetHeader::write`] would write
+    pub const fn wire_len(&self) -> usize {
+        EthernetHeader::MIN_LENGTH + self.tag_count * EthernetHeader::TAG_LENGTH
+    }
+
+    /// Gets the address the frame is sent to
+    ///
+    /// ## Return Value
+    /// Returns the destination address
+    pub const fn destination(&self) -> MACAddress {
+        self.destination
+    }
+
+    /// Gets the address the frame is sent from
+    ///
+    /// ## Return Value
+    /// Returns the source address
+    pub const fn source(&self) -> MACAddress {
+        self.source
+    }
+
+    /// Gets the VLAN tags of the frame
+    ///
+    /// ## Return Value
+    /// Returns the tags, starting with the outermost
+    pub fn tags(&self) -> &[VLANTag] {
+        &self.tags[..self.tag_count]
+    }
+
+    /// Gets the protocol of the payload
+    ///
+    /// ## Return Value
+    /// Returns the EtherType following any VLAN tags
+    pub const fn ether_type(&self) -> EtherType {
+        self.ether_type
+    }
+}
+
+/// Splits the first `length` bytes from `bytes`
+fn split(bytes: &[u8], length: usize) -> Result<(&[u8], &[u8]), InvalidEthernetHeaderError> {
+    if bytes.len() < length {
+        return Err(InvalidEthernetHeaderError::Truncated);
+    }
+
+    Ok(bytes.split_at(length))
+}
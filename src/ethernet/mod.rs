@@ -0,0 +1,20 @@
+//! Ethernet frame utilities
+
+mod error;
+mod ether_type;
+mod fcs;
+mod header;
+mod packed;
+mod tag;
+mod vlan;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+
+pub use error::{InvalidEthernetHeaderError, InvalidVLANIDError};
+pub use ether_type::EtherType;
+pub use fcs::{fcs, verify_fcs, FCS};
+pub use header::EthernetHeader;
+pub use packed::PackedEthernetHeader;
+pub use tag::VLANTag;
+pub use vlan::{QinQ, VLANID};
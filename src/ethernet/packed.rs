@@ -0,0 +1,120 @@
+use super::EtherType;
+use crate::mac::MACAddress;
+
+/// The header of an untagged Ethernet frame with the fixed layout it has on the wire
+///
+/// For a tagged frame, the EtherType of the packed header is the protocol of the outer tag.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PackedEthernetHeader {
+    destination: MACAddress,
+    source: MACAddress,
+    ether_type: [u8; 2],
+}
+
+impl PackedEthernetHeader {
+    /// The length of a packed header
+    pub const LENGTH: usize = 14;
+
+    /// Creates a new [`PackedEthernetHeader`]
+    ///
+    /// ## Parameters
+    ///  * `destination` - The address the frame is sent to
+    ///  * `source` - The address the frame is sent from
+    ///  * `ether_type` - The protocol of the payload
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedEthernetHeader`]
+    pub const fn new(destination: MACAddress, source: MACAddress, ether_type: EtherType) -> Self {
+        PackedEthernetHeader {
+            destination,
+            source,
+            ether_type: ether_type.value().to_be_bytes(),
+        }
+    }
+
+    /// Creates a [`PackedEthernetHeader`] from its bytes
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The first 14 bytes of the frame
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedEthernetHeader`]
+    pub const fn from_bytes(bytes: [u8; 14]) -> Self {
+        PackedEthernetHeader {
+            destination: MACAddress::new([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5],
+            ]),
+            source: MACAddress::new([bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11]]),
+            ether_type: [bytes[12], bytes[13]],
+        }
+    }
+
+    /// Gets the bytes of this packed header
+    ///
+    /// ## Return Value
+    /// Returns the 14 bytes of the header as they appear on the wire
+    pub const fn to_bytes(self) -> [u8; 14] {
+        let destination = self.destination.octets();
+        let source = self.source.octets();
+
+        let mut bytes = [0; 14];
+        let mut i = 0;
+        while i < 6 {
+            bytes[i] = destination[i];
+            bytes[6 + i] = source[i];
+            i += 1;
+        }
+        bytes[12] = self.ether_type[0];
+        bytes[13] = self.ether_type[1];
+        bytes
+    }
+
+    /// Gets the destination address
+    ///
+    /// ## Return Value
+    /// Returns the address the frame is sent to
+    pub const fn destination(&self) -> MACAddress {
+        self.destination
+    }
+
+    /// Gets the source address
+    ///
+    /// ## Return Value
+    /// Returns the address the frame is sent from
+    pub const fn source(&self) -> MACAddress {
+        self.source
+    }
+
+    /// Gets the EtherType
+    ///
+    /// ## Return Value
+    /// Returns the protocol of the payload, or the protocol of the outer tag of a tagged frame
+    pub const fn ether_type(&self) -> EtherType {
+        EtherType::new(u16::from_be_bytes(self.ether_type))
+    }
+}
+
+impl std::fmt::Debug for PackedEthernetHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackedEthernetHeader")
+            .field("destination", &self.destination)
+            .field("source", &self.source)
+            .field("ether_type", &self.ether_type())
+            .finish()
+    }
+}
@@ -0,0 +1,109 @@
+use super::{EtherType, VLANID};
+
+/// An IEEE 802.1Q tag placing a frame on a virtual LAN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VLANTag {
+    protocol: EtherType,
+    priority: u8,
+    drop_eligible: bool,
+    id: VLANID,
+}
+
+impl VLANTag {
+    /// Creates a new customer [`VLANTag`] with the default priority
+    ///
+    /// ## Parameters
+    ///  * `id` - The VLAN identifier
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`VLANTag`]
+    pub const fn new(id: VLANID) -> Self {
+        VLANTag {
+            protocol: EtherType::VLAN,
+            priority: 0,
+            drop_eligible: false,
+            id,
+        }
+    }
+
+    /// Sets the tag protocol identifier
+    ///
+    /// ## Parameters
+    ///  * `protocol` - The tag protocol identifier, such as [`EtherType::ServiceVLAN`]
+    ///
+    /// ## Return Value
+    /// Returns the modified [`VLANTag`]
+    pub const fn with_protocol(mut self, protocol: EtherType) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Sets the priority code point
+    ///
+    /// ## Parameters
+    ///  * `priority` - The priority, of which only the low 3 bits are kept
+    ///
+    /// ## Return Value
+    /// Returns the modified [`VLANTag`]
+    pub const fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority & 0x07;
+        self
+    }
+
+    /// Sets whether the frame may be dropped under congestion
+    ///
+    /// ## Parameters
+    ///  * `drop_eligible` - The value of the drop eligible indicator
+    ///
+    /// ## Return Value
+    /// Returns the modified [`VLANTag`]
+    pub const fn with_drop_eligible(mut self, drop_eligible: bool) -> Self {
+        self.drop_eligible = drop_eligible;
+        self
+    }
+
+    /// Gets the tag protocol identifier
+    ///
+    /// ## Return Value
+    /// Returns the tag protocol identifier
+    pub const fn protocol(&self) -> EtherType {
+        self.protocol
+    }
+
+    /// Gets the priority code point
+    ///
+    /// ## Return Value
+    /// Returns the priority, from 0 to 7
+    pub const fn priority(&self) -> u8 {
+        self.priority
+    }
+
+    /// Gets whether the frame may be dropped under congestion
+    ///
+    /// ## Return Value
+    /// Returns the value of the drop eligible indicator
+    pub const fn drop_eligible(&self) -> bool {
+        self.drop_eligible
+    }
+
+    /// Gets the VLAN identifier
+    ///
+    /// ## Return Value
+    /// Returns the VLAN identifier
+    pub const fn id(&self) -> VLANID {
+        self.id
+    }
+
+    /// Decodes the tag control information following the tag protocol identifier
+    pub(super) const fn from_control(protocol: EtherType, control: u16) -> Self {
+        VLANTag::new(VLANID::from_bits(control))
+            .with_protocol(protocol)
+            .with_priority((control >> 13) as u8)
+            .with_drop_eligible(control & 0x1000 != 0)
+    }
+
+    /// Encodes the tag control information
+    pub(super) const fn control(&self) -> u16 {
+        ((self.priority as u16) << 13) | ((self.drop_eligible as u16) << 12) | self.id.get()
+    }
+}
@@ -0,0 +1,164 @@
+use super::InvalidVLANIDError;
+use std::str::FromStr;
+
+/// An IEEE 802.1Q VLAN identifier, from 0 to 4095
+///
+/// The identifiers 0 and 4095 are reserved. A tag with the identifier 0 carries only a priority
+/// and does not place the frame on a VLAN.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VLANID(u16);
+
+/// A pair of stacked VLAN identifiers from IEEE 802.1ad
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QinQ {
+    outer: VLANID,
+    inner: VLANID,
+}
+
+impl VLANID {
+    /// The identifier of a tag which carries only a priority
+    pub const NULL: VLANID = VLANID(0);
+
+    /// The identifier of the default VLAN of most bridges
+    pub const DEFAULT: VLANID = VLANID(1);
+
+    /// The identifier reserved for implementation use
+    pub const RESERVED: VLANID = VLANID(4095);
+
+    /// The largest VLAN identifier
+    pub const MAX: u16 = 4095;
+
+    /// Creates a new [`VLANID`]
+    ///
+    /// ## Parameters
+    ///  * `id` - The numeric identifier
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`VLANID`], or [`None`] if `id` is larger than 4095
+    pub const fn new(id: u16) -> Option<Self> {
+        if id > VLANID::MAX {
+            None
+        } else {
+            Some(VLANID(id))
+        }
+    }
+
+    /// Creates a [`VLANID`] from the low 12 bits of `bits`
+    pub(super) const fn from_bits(bits: u16) -> Self {
+        VLANID(bits & VLANID::MAX)
+    }
+
+    /// Gets the numeric identifier
+    ///
+    /// ## Return Value
+    /// Returns the identifier, from 0 to 4095
+    pub const fn get(&self) -> u16 {
+        self.0
+    }
+
+    /// Checks if this is one of the reserved identifiers, 0 or 4095
+    ///
+    /// ## Return Value
+    /// Returns true if this identifier may not be assigned to a VLAN
+    pub const fn is_reserved(&self) -> bool {
+        self.0 == VLANID::NULL.0 || self.0 == VLANID::RESERVED.0
+    }
+
+    /// Checks if this identifier marks a tag which carries only a priority
+    ///
+    /// ## Return Value
+    /// Returns true if this identifier is 0
+    pub const fn is_null(&self) -> bool {
+        self.0 == VLANID::NULL.0
+    }
+}
+
+impl QinQ {
+    /// Creates a new [`QinQ`]
+    ///
+    /// ## Parameters
+    ///  * `outer` - The service VLAN identifier
+    ///  * `inner` - The customer VLAN identifier
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`QinQ`]
+    pub const fn new(outer: VLANID, inner: VLANID) -> Self {
+        QinQ { outer, inner }
+    }
+
+    /// Gets the service VLAN identifier, carried in the outer tag
+    ///
+    /// ## Return Value
+    /// Returns the outer identifier
+    pub const fn outer(&self) -> VLANID {
+        self.outer
+    }
+
+    /// Gets the customer VLAN identifier, carried in the inner tag
+    ///
+    /// ## Return Value
+    /// Returns the inner identifier
+    pub const fn inner(&self) -> VLANID {
+        self.inner
+    }
+}
+
+impl TryFrom<u16> for VLANID {
+    type Error = InvalidVLANIDError;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        VLANID::new(id).ok_or(InvalidVLANIDError::OutOfRange(id))
+    }
+}
+
+impl From<VLANID> for u16 {
+    fn from(id: VLANID) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for VLANID {
+    type Err = InvalidVLANIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u16>()
+            .map_err(InvalidVLANIDError::InvalidNumber)?
+            .try_into()
+    }
+}
+
+impl FromStr for QinQ {
+    type Err = InvalidVLANIDError;
+
+    /// Parses the outer and inner identifiers separated by a '.', such as "100.200"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (outer, inner) = s
+            .split_once('.')
+            .ok_or(InvalidVLANIDError::MissingInnerID)?;
+        Ok(QinQ::new(outer.parse()?, inner.parse()?))
+    }
+}
+
+impl std::fmt::Display for VLANID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for VLANID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for QinQ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.outer, self.inner)
+    }
+}
+
+impl std::fmt::Debug for QinQ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
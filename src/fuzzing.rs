@@ -0,0 +1,25 @@
+use arbitrary::{Result, Unstructured};
+use std::ops::RangeInclusive;
+
+/// Generates a string by joining pieces chosen from `pieces`
+///
+/// ## Parameters
+///  * `u` - The fuzzer input to draw from
+///  * `pieces` - The characters or short strings to choose from
+///  * `lengths` - The range of lengths for the string, in pieces
+///
+/// ## Return Value
+/// Returns the generated string
+pub(crate) fn string(
+    u: &mut Unstructured,
+    pieces: &[&str],
+    lengths: RangeInclusive<usize>,
+) -> Result<String> {
+    let length = u.int_in_range(lengths)?;
+
+    let mut string = String::with_capacity(length);
+    for _ in 0..length {
+        string.push_str(u.choose(pieces)?);
+    }
+    Ok(string)
+}
@@ -0,0 +1,87 @@
+//! Hex dump formatting for packet bytes
+
+/// The number of bytes shown on each line
+const BYTES_PER_LINE: usize = 16;
+
+/// Formats bytes as a hex dump in the style of Wireshark and tcpdump
+///
+/// Each line holds an offset column, 16 bytes in hexadecimal split into two groups of 8, and the
+/// same bytes as ASCII with unprintable bytes shown as '.', such as:
+///
+/// ```text
+/// 0000  45 00 00 1c 00 00 40 00  40 01 00 00 7f 00 00 01  E.....@.@.......
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> HexDump<'a> {
+    /// Creates a new [`HexDump`]
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The bytes to format
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`HexDump`], with offsets starting from zero
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        HexDump { bytes, offset: 0 }
+    }
+
+    /// Sets the offset shown for the first byte
+    ///
+    /// ## Parameters
+    ///  * `offset` - The offset of the first byte, such as its position in the whole packet
+    ///
+    /// ## Return Value
+    /// Returns the modified [`HexDump`]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<'a> std::fmt::Display for HexDump<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The offset column widens when 4 digits are not enough for the last offset
+        let last = self.offset + self.bytes.len().saturating_sub(1);
+        let width = (last.checked_ilog(16).unwrap_or(0) as usize + 1).max(4);
+
+        for (i, line) in self.bytes.chunks(BYTES_PER_LINE).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            write!(
+                f,
+                "{:0width$x} ",
+                self.offset + i * BYTES_PER_LINE,
+                width = width
+            )?;
+
+            for column in 0..BYTES_PER_LINE {
+                if column == BYTES_PER_LINE / 2 {
+                    f.write_str(" ")?;
+                }
+
+                match line.get(column) {
+                    Some(byte) => write!(f, " {:02x}", byte)?,
+                    None => f.write_str("   ")?,
+                }
+            }
+
+            f.write_str("  ")?;
+            for byte in line {
+                let c = if byte.is_ascii_graphic() || *byte == b' ' {
+                    *byte as char
+                } else {
+                    '.'
+                };
+                write!(f, "{}", c)?;
+            }
+        }
+
+        Ok(())
+    }
+}
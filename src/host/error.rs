@@ -0,0 +1,59 @@
+use crate::{dns::InvalidDomainNameError, ErrorKind};
+use std::net::AddrParseError;
+
+/// An error while parsing a host
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidHostError {
+    /// A '[' opening an IPv6 address is not closed by a ']'
+    UnclosedBracket,
+
+    /// The IPv6 address could not be parsed
+    InvalidIPv6(AddrParseError),
+
+    /// The domain name is not valid
+    InvalidDomainName(InvalidDomainNameError),
+}
+
+impl InvalidHostError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidHostError::UnclosedBracket | InvalidHostError::InvalidIPv6(_) => {
+                ErrorKind::InvalidFormat
+            }
+            InvalidHostError::InvalidDomainName(error) => error.kind(),
+        }
+    }
+}
+
+impl std::error::Error for InvalidHostError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidHostError::UnclosedBracket => None,
+            InvalidHostError::InvalidIPv6(error) => Some(error),
+            InvalidHostError::InvalidDomainName(error) => Some(error),
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidHostError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidHostError::UnclosedBracket => write!(f, "missing closing ']'"),
+            InvalidHostError::InvalidIPv6(error) => write!(f, "invalid IPv6 address - {}", error),
+            InvalidHostError::InvalidDomainName(error) => {
+                write!(f, "invalid domain name - {}", error)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidHostError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,29 @@
+use super::Host;
+use defmt::{Display2Format, Format, Formatter};
+
+impl Format for Host {
+    fn format(&self, fmt: Formatter) {
+        match self {
+            Host::Domain(domain) => defmt::write!(fmt, "{}", Display2Format(domain)),
+            Host::IPv4(address) => {
+                let [a, b, c, d] = address.octets();
+                defmt::write!(fmt, "{=u8}.{=u8}.{=u8}.{=u8}", a, b, c, d)
+            }
+            Host::IPv6(address) => {
+                let [a, b, c, d, e, f, g, h] = address.segments();
+                defmt::write!(
+                    fmt,
+                    "[{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}]",
+                    a,
+                    b,
+                    c,
+                    d,
+                    e,
+                    f,
+                    g,
+                    h
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Hosts which may be either a domain name or an IP address
+
+use crate::{
+    dns::DomainName,
+    ip::{v4::IPv4Address, v6::IPv6Address, IPAddress},
+};
+use std::str::FromStr;
+
+mod error;
+
+#[cfg(feature = "defmt")]
+mod format;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use error::InvalidHostError;
+
+/// A host which can be connected to, either a domain name or an IP address
+///
+/// This is the host of a [`URI`](crate::uri::URI) once interpreted. When parsed, a string which
+/// is a valid IPv4 address is always taken as one. IPv6 addresses may be written with or without
+/// the surrounding brackets, but are always displayed with them so the result can be written
+/// directly in front of a port or into a URI.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Host {
+    /// A domain name
+    Domain(DomainName),
+
+    /// An IPv4 address
+    IPv4(IPv4Address),
+
+    /// An IPv6 address
+    IPv6(IPv6Address),
+}
+
+impl Host {
+    /// Gets the domain name of this host
+    ///
+    /// ## Return Value
+    /// Returns the name if this host is a domain name
+    pub fn domain(&self) -> Option<&DomainName> {
+        match self {
+            Host::Domain(domain) => Some(domain),
+            Host::IPv4(_) | Host::IPv6(_) => None,
+        }
+    }
+
+    /// Gets the IP address of this host
+    ///
+    /// ## Return Value
+    /// Returns the address if this host is an IP address
+    pub fn ip(&self) -> Option<IPAddress> {
+        match self {
+            Host::Domain(_) => None,
+            Host::IPv4(address) => Some(IPAddress::V4(*address)),
+            Host::IPv6(address) => Some(IPAddress::V6(*address)),
+        }
+    }
+
+    /// Checks if this host is a domain name
+    ///
+    /// ## Return Value
+    /// Returns true if this host is a domain name rather than an IP address
+    pub fn is_domain(&self) -> bool {
+        matches!(self, Host::Domain(_))
+    }
+
+    /// Checks if this host is an IP address
+    ///
+    /// ## Return Value
+    /// Returns true if this host is an IPv4 or IPv6 address
+    pub fn is_ip(&self) -> bool {
+        !self.is_domain()
+    }
+}
+
+impl From<DomainName> for Host {
+    fn from(domain: DomainName) -> Self {
+        Host::Domain(domain)
+    }
+}
+
+impl From<IPAddress> for Host {
+    fn from(address: IPAddress) -> Self {
+        match address {
+            IPAddress::V4(address) => Host::IPv4(address),
+            IPAddress::V6(address) => Host::IPv6(address),
+        }
+    }
+}
+
+impl From<IPv4Address> for Host {
+    fn from(address: IPv4Address) -> Self {
+        Host::IPv4(address)
+    }
+}
+
+impl From<IPv6Address> for Host {
+    fn from(address: IPv6Address) -> Self {
+        Host::IPv6(address)
+    }
+}
+
+impl FromStr for Host {
+    type Err = InvalidHostError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(literal) = s.strip_prefix('[') {
+            let literal = literal
+                .strip_suffix(']')
+                .ok_or(InvalidHostError::UnclosedBracket)?;
+            return literal
+                .parse()
+                .map(Host::IPv6)
+                .map_err(InvalidHostError::InvalidIPv6);
+        }
+
+        if let Ok(address) = s.parse() {
+            return Ok(Host::IPv4(address));
+        }
+
+        if s.contains(':') {
+            return s
+                .parse()
+                .map(Host::IPv6)
+                .map_err(InvalidHostError::InvalidIPv6);
+        }
+
+        s.parse()
+            .map(Host::Domain)
+            .map_err(InvalidHostError::InvalidDomainName)
+    }
+}
+
+impl std::fmt::Display for Host {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Host::Domain(domain) => domain.fmt(f),
+            Host::IPv4(address) => address.fmt(f),
+            Host::IPv6(address) => write!(f, "[{}]", address),
+        }
+    }
+}
+
+impl std::fmt::Debug for Host {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
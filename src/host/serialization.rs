@@ -0,0 +1,15 @@
+use super::Host;
+use crate::serialization::ParseVisitor;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Host {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Host {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor::new("a domain name or IP address"))
+    }
+}
@@ -0,0 +1,37 @@
+use crate::ErrorKind;
+
+/// An error while parsing an ICMP message
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidICMPMessageError {
+    /// The message ends before its header does
+    Truncated,
+}
+
+impl InvalidICMPMessageError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidICMPMessageError::Truncated => ErrorKind::Truncated,
+        }
+    }
+}
+
+impl std::error::Error for InvalidICMPMessageError {}
+
+impl std::fmt::Display for InvalidICMPMessageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidICMPMessageError::Truncated => write!(f, "ICMP message is truncated"),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidICMPMessageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
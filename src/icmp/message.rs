@@ -0,0 +1,256 @@
+use super::{InvalidICMPMessageError, ICMPV6_PROTOCOL};
+use crate::{
+    checksum::{checksum, Checksum},
+    ip::v6::IPv6Address,
+};
+
+/// An ICMP or ICMPv6 message, borrowing its data from the packet it was parsed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ICMPMessage<'a> {
+    /// A request for an echo reply
+    EchoRequest {
+        /// The identifier matching replies to requests
+        identifier: u16,
+
+        /// The sequence number matching replies to requests
+        sequence: u16,
+
+        /// The data to be echoed
+        data: &'a [u8],
+    },
+
+    /// A reply to an echo request
+    EchoReply {
+        /// The identifier of the request
+        identifier: u16,
+
+        /// The sequence number of the request
+        sequence: u16,
+
+        /// The data of the request
+        data: &'a [u8],
+    },
+
+    /// A packet could not be delivered
+    DestinationUnreachable {
+        /// The reason the packet could not be delivered
+        code: u8,
+
+        /// The start of the packet which could not be delivered
+        packet: &'a [u8],
+    },
+
+    /// A packet was discarded because its hop limit or reassembly time ran out
+    TimeExceeded {
+        /// 0 if the hop limit ran out, or 1 if reassembly time ran out
+        code: u8,
+
+        /// The start of the packet which was discarded
+        packet: &'a [u8],
+    },
+
+    /// Any other message
+    Other {
+        /// The type of the message
+        kind: u8,
+
+        /// The code of the message
+        code: u8,
+
+        /// The body of the message following the checksum
+        body: &'a [u8],
+    },
+}
+
+/// The type numbers of the messages for each version
+struct Types {
+    echo_request: u8,
+    echo_reply: u8,
+    destination_unreachable: u8,
+    time_exceeded: u8,
+}
+
+/// The type numbers of ICMPv4 messages
+const V4: Types = Types {
+    echo_request: 8,
+    echo_reply: 0,
+    destination_unreachable: 3,
+    time_exceeded: 11,
+};
+
+/// The type numbers of ICMPv6 messages
+const V6: Types = Types {
+    echo_request: 128,
+    echo_reply: 129,
+    destination_unreachable: 1,
+    time_exceeded: 3,
+};
+
+/// The length of the type, code, and checksum
+const HEADER_LENGTH: usize = 4;
+
+impl<'a> ICMPMessage<'a> {
+    /// Parses an ICMPv4 message
+    ///
+    /// The checksum is not verified.
+    ///
+    /// ## Parameters
+    ///  * `message` - The message, starting at the type
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`ICMPMessage`]
+    pub fn parse_v4(message: &'a [u8]) -> Result<Self, InvalidICMPMessageError> {
+        ICMPMessage::parse(message, &V4)
+    }
+
+    /// Parses an ICMPv6 message
+    ///
+    /// The checksum is not verified.
+    ///
+    /// ## Parameters
+    ///  * `message` - The message, starting at the type
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`ICMPMessage`]
+    pub fn parse_v6(message: &'a [u8]) -> Result<Self, InvalidICMPMessageError> {
+        ICMPMessage::parse(message, &V6)
+    }
+
+    /// Encodes this message as ICMPv4 with a correct checksum
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded message to
+    pub fn write_v4(&self, output: &mut Vec<u8>) {
+        let start = output.len();
+        self.write(output, &V4);
+
+        let checksum = checksum(&output[start..]);
+        output[start + 2..start + 4].copy_from_slice(&checksum.to_be_bytes());
+    }
+
+    /// Encodes this message as ICMPv6 with a correct checksum
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded message to
+    ///  * `source` - The address the message is sent from
+    ///  * `destination` - The address the message is sent to
+    pub fn write_v6(&self, output: &mut Vec<u8>, source: IPv6Address, destination: IPv6Address) {
+        let start = output.len();
+        self.write(output, &V6);
+
+        let checksum = checksum_v6(&output[start..], source, destination);
+        output[start + 2..start + 4].copy_from_slice(&checksum.to_be_bytes());
+    }
+
+    /// Verifies the checksum of an encoded ICMPv4 message
+    ///
+    /// ## Parameters
+    ///  * `message` - The message, starting at the type
+    ///
+    /// ## Return Value
+    /// Returns true if the checksum carried in `message` is correct
+    pub fn verify_v4(message: &[u8]) -> bool {
+        message.len() >= HEADER_LENGTH && checksum(message) == 0
+    }
+
+    /// Verifies the checksum of an encoded ICMPv6 message
+    ///
+    /// ## Parameters
+    ///  * `message` - The message, starting at the type
+    ///  * `source` - The address the message was sent from
+    ///  * `destination` - The address the message was sent to
+    ///
+    /// ## Return Value
+    /// Returns true if the checksum carried in `message` is correct
+    pub fn verify_v6(message: &[u8], source: IPv6Address, destination: IPv6Address) -> bool {
+        message.len() >= HEADER_LENGTH && checksum_v6(message, source, destination) == 0
+    }
+
+    /// Parses a message using the type numbers of `types`
+    fn parse(message: &'a [u8], types: &Types) -> Result<Self, InvalidICMPMessageError> {
+        if message.len() < HEADER_LENGTH {
+            return Err(InvalidICMPMessageError::Truncated);
+        }
+
+        let (kind, code, body) = (message[0], message[1], &message[HEADER_LENGTH..]);
+
+        let echo = || {
+            if body.len() < 4 {
+                return Err(InvalidICMPMessageError::Truncated);
+            }
+
+            Ok((
+                u16::from_be_bytes([body[0], body[1]]),
+                u16::from_be_bytes([body[2], body[3]]),
+                &body[4..],
+            ))
+        };
+        let error = || body.get(4..).ok_or(InvalidICMPMessageError::Truncated);
+
+        Ok(if kind == types.echo_request {
+            let (identifier, sequence, data) = echo()?;
+            ICMPMessage::EchoRequest {
+                identifier,
+                sequence,
+                data,
+            }
+        } else if kind == types.echo_reply {
+            let (identifier, sequence, data) = echo()?;
+            ICMPMessage::EchoReply {
+                identifier,
+                sequence,
+                data,
+            }
+        } else if kind == types.destination_unreachable {
+            ICMPMessage::DestinationUnreachable {
+                code,
+                packet: error()?,
+            }
+        } else if kind == types.time_exceeded {
+            ICMPMessage::TimeExceeded {
+                code,
+                packet: error()?,
+            }
+        } else {
+            ICMPMessage::Other { kind, code, body }
+        })
+    }
+
+    /// Encodes this message using the type numbers of `types`, leaving the checksum zero
+    fn write(&self, output: &mut Vec<u8>, types: &Types) {
+        let (kind, code, first, second, data) = match *self {
+            ICMPMessage::EchoRequest {
+                identifier,
+                sequence,
+                data,
+            } => (types.echo_request, 0, identifier, sequence, data),
+            ICMPMessage::EchoReply {
+                identifier,
+                sequence,
+                data,
+            } => (types.echo_reply, 0, identifier, sequence, data),
+            ICMPMessage::DestinationUnreachable { code, packet } => {
+                (types.destination_unreachable, code, 0, 0, packet)
+            }
+            ICMPMessage::TimeExceeded { code, packet } => (types.time_exceeded, code, 0, 0, packet),
+            ICMPMessage::Other { kind, code, body } => {
+                output.extend_from_slice(&[kind, code, 0, 0]);
+                output.extend_from_slice(body);
+                return;
+            }
+        };
+
+        output.extend_from_slice(&[kind, code, 0, 0]);
+        output.extend_from_slice(&first.to_be_bytes());
+        output.extend_from_slice(&second.to_be_bytes());
+        output.extend_from_slice(data);
+    }
+}
+
+/// Computes the ICMPv6 checksum of `message`, which covers the IPv6 pseudo-header
+fn checksum_v6(message: &[u8], source: IPv6Address, destination: IPv6Address) -> u16 {
+    let mut checksum = Checksum::new();
+    checksum.add_ipv6_pseudo_header(source, destination, ICMPV6_PROTOCOL, message.len() as u32);
+    checksum.add_bytes(message);
+    checksum.finish()
+}
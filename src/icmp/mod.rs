@@ -0,0 +1,16 @@
+//! ICMP and ICMPv6 message utilities from RFC 792 and RFC 4443
+//!
+//! The same messages are carried by both versions with different type numbers. An ICMPv4
+//! checksum covers only the message, while an ICMPv6 checksum also covers the IPv6 pseudo-header.
+
+mod error;
+mod message;
+
+pub use error::InvalidICMPMessageError;
+pub use message::ICMPMessage;
+
+/// The protocol number of ICMP
+pub const ICMP_PROTOCOL: u8 = 1;
+
+/// The protocol number of ICMPv6
+pub const ICMPV6_PROTOCOL: u8 = 58;
@@ -0,0 +1,122 @@
+//! Local network interface utilities
+//!
+//! Interfaces are listed using `getifaddrs` on Linux, Android, and the BSDs, including macOS, and
+//! using `GetAdaptersAddresses` on Windows, where they are named by their friendly names, such as
+//! "Ethernet". Listing interfaces returns an error of kind
+//! [`Unsupported`](std::io::ErrorKind::Unsupported) on other platforms.
+
+use crate::{
+    ip::{v4::IPv4CIDR, v6::IPv6CIDR, IPAddress, IPCIDR},
+    mac::MACAddress,
+};
+
+/// A network interface of the running system
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Interface {
+    name: String,
+    index: Option<u32>,
+    mac_address: Option<MACAddress>,
+    addresses: Vec<IPCIDR>,
+}
+
+impl Interface {
+    /// Gets the name of this interface
+    ///
+    /// ## Return Value
+    /// Returns the name, such as "eth0"
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the index of this interface
+    ///
+    /// ## Return Value
+    /// Returns the index, which is used as the scope of link-local IPv6 addresses, if it is
+    /// available
+    pub const fn index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// Gets the hardware address of this interface
+    ///
+    /// ## Return Value
+    /// Returns the MAC address, if this interface has one
+    pub const fn mac_address(&self) -> Option<MACAddress> {
+        self.mac_address
+    }
+
+    /// Gets the addresses assigned to this interface
+    ///
+    /// ## Return Value
+    /// Returns each assigned address with the prefix length of its network
+    pub fn addresses(&self) -> &[IPCIDR] {
+        &self.addresses
+    }
+
+    /// Creates an [`Interface`] with nothing but a name
+    fn new(name: String) -> Self {
+        Interface {
+            index: crate::os::interface_index(&name),
+            name,
+            mac_address: None,
+            addresses: Vec::new(),
+        }
+    }
+}
+
+/// Lists the network interfaces of the running system
+///
+/// ## Return Value
+/// Returns the interfaces in the order the system lists them
+pub fn interfaces() -> std::io::Result<Vec<Interface>> {
+    let mut interfaces: Vec<Interface> = Vec::new();
+    let mut get = |name: String| -> usize {
+        match interfaces
+            .iter()
+            .position(|interface| interface.name == name)
+        {
+            Some(index) => index,
+            None => {
+                interfaces.push(Interface::new(name));
+                interfaces.len() - 1
+            }
+        }
+    };
+
+    let mut hardware = Vec::new();
+    for address in crate::os::hardware_addresses()? {
+        hardware.push((get(address.name), address.address));
+    }
+
+    let mut addresses = Vec::new();
+    for address in crate::os::interface_addresses()? {
+        let cidr = match address.address {
+            IPAddress::V4(ip) => IPCIDR::V4(IPv4CIDR::new(ip, address.prefix.min(32))),
+            IPAddress::V6(ip) => IPCIDR::V6(IPv6CIDR::new(ip, address.prefix.min(128))),
+        };
+        addresses.push((get(address.name), cidr));
+    }
+
+    for (index, address) in hardware {
+        interfaces[index].mac_address = Some(address);
+    }
+
+    for (index, address) in addresses {
+        interfaces[index].addresses.push(address);
+    }
+
+    Ok(interfaces)
+}
+
+/// Finds the network interface `name`
+///
+/// ## Parameters
+///  * `name` - The name of the interface, such as "eth0"
+///
+/// ## Return Value
+/// Returns the interface, or [`None`] if there is no interface with that name
+pub fn interface(name: &str) -> std::io::Result<Option<Interface>> {
+    Ok(interfaces()?
+        .into_iter()
+        .find(|interface| interface.name == name))
+}
@@ -11,6 +11,77 @@ pub enum IPCIDR {
     V6(IPv6CIDR),
 }
 
+impl IPCIDR {
+    /// Parses a CIDR in "address/prefix" form in a const context
+    ///
+    /// [`FromStr`] can not be used to initialize a const item, so this parses the same form
+    /// without reporting why the text is invalid. An address containing a ':' is parsed as IPv6.
+    ///
+    /// ## Parameters
+    ///  * `s` - The text to parse
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`IPCIDR`], or [`None`] if `s` is not a valid CIDR
+    pub const fn parse_const(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+
+        let mut i = 0;
+        while i < bytes.len() && bytes[i] != b':' {
+            i += 1;
+        }
+
+        if i == bytes.len() {
+            match IPv4CIDR::parse_const(s) {
+                Some(cidr) => Some(IPCIDR::V4(cidr)),
+                None => None,
+            }
+        } else {
+            match IPv6CIDR::parse_const(s) {
+                Some(cidr) => Some(IPCIDR::V6(cidr)),
+                None => None,
+            }
+        }
+    }
+
+    /// Checks if `address` is in the network of this CIDR
+    ///
+    /// ## Parameters
+    ///  * `address` - The address to check
+    ///
+    /// ## Return Value
+    /// Returns true if `address` is of the same family and its first `prefix` bits match this
+    /// CIDR
+    pub const fn contains(&self, address: IPAddress) -> bool {
+        match (self, address) {
+            (IPCIDR::V4(cidr), IPAddress::V4(address)) => cidr.contains(address),
+            (IPCIDR::V6(cidr), IPAddress::V6(address)) => cidr.contains(address),
+            _ => false,
+        }
+    }
+
+    /// Gets the first address in the network of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this CIDR with every host bit cleared
+    pub const fn first_address(&self) -> IPAddress {
+        match self {
+            IPCIDR::V4(cidr) => IPAddress::V4(cidr.network()),
+            IPCIDR::V6(cidr) => IPAddress::V6(cidr.first_address()),
+        }
+    }
+
+    /// Gets the last address in the network of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this CIDR with every host bit set
+    pub const fn last_address(&self) -> IPAddress {
+        match self {
+            IPCIDR::V4(cidr) => IPAddress::V4(cidr.broadcast()),
+            IPCIDR::V6(cidr) => IPAddress::V6(cidr.last_address()),
+        }
+    }
+}
+
 impl From<IPv4CIDR> for IPCIDR {
     fn from(cidr: IPv4CIDR) -> Self {
         IPCIDR::V4(cidr)
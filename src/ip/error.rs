@@ -1,6 +1,9 @@
+use crate::ErrorKind;
 use std::{net::AddrParseError, num::ParseIntError};
 
 /// An error while parsing a CIDR address
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum InvalidCIDRError {
     /// The address is missing
     MissingAddress,
@@ -22,11 +25,82 @@ pub enum InvalidCIDRError {
 }
 
 /// The CIDR prefix is invalid
+#[derive(Clone, PartialEq, Eq)]
 pub struct InvalidPrefixError {
     /// The invalid prefix
     pub prefix: u8,
 }
 
+/// An error while parsing an IPv6 socket address with a zone
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidZonedAddressError {
+    /// The address is not surrounded by brackets
+    MissingBrackets,
+
+    /// The address could not be parsed
+    InvalidAddress(AddrParseError),
+
+    /// The zone after the '%' is empty
+    EmptyZone,
+
+    /// The port is missing
+    MissingPort,
+
+    /// The port could not be parsed
+    InvalidPort(ParseIntError),
+}
+
+/// An error while parsing or building an IPv4 header
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidIPv4HeaderError {
+    /// The packet ends before the header or the total length does
+    Truncated,
+
+    /// The version is not 4
+    InvalidVersion(u8),
+
+    /// The internet header length is less than 5 words
+    InvalidHeaderLength(u8),
+
+    /// The total length is less than the header length
+    InvalidTotalLength(u16),
+
+    /// The options are longer than 40 bytes
+    OptionsTooLong(usize),
+}
+
+/// An error while parsing an IPv6 header or its extension headers
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidIPv6HeaderError {
+    /// The packet ends before a header or the payload length does
+    Truncated,
+
+    /// The version is not 6
+    InvalidVersion(u8),
+}
+
+impl InvalidCIDRError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidCIDRError::MissingAddress | InvalidCIDRError::MissingPrefix => {
+                ErrorKind::Missing
+            }
+            InvalidCIDRError::AddressParseError(_) | InvalidCIDRError::ExtraContent => {
+                ErrorKind::InvalidFormat
+            }
+            InvalidCIDRError::PrefixParseError(_) => ErrorKind::InvalidNumber,
+            InvalidCIDRError::InvalidPrefix(error) => error.kind(),
+        }
+    }
+}
+
 impl std::error::Error for InvalidCIDRError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -60,6 +134,16 @@ impl std::fmt::Debug for InvalidCIDRError {
     }
 }
 
+impl InvalidPrefixError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        ErrorKind::OutOfRange
+    }
+}
+
 impl std::error::Error for InvalidPrefixError {}
 
 impl std::fmt::Display for InvalidPrefixError {
@@ -73,3 +157,129 @@ impl std::fmt::Debug for InvalidPrefixError {
         std::fmt::Display::fmt(self, f)
     }
 }
+
+impl InvalidZonedAddressError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidZonedAddressError::MissingBrackets
+            | InvalidZonedAddressError::InvalidAddress(_) => ErrorKind::InvalidFormat,
+            InvalidZonedAddressError::EmptyZone | InvalidZonedAddressError::MissingPort => {
+                ErrorKind::Missing
+            }
+            InvalidZonedAddressError::InvalidPort(_) => ErrorKind::InvalidNumber,
+        }
+    }
+}
+
+impl std::error::Error for InvalidZonedAddressError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidZonedAddressError::InvalidAddress(error) => Some(error),
+            InvalidZonedAddressError::InvalidPort(error) => Some(error),
+
+            InvalidZonedAddressError::MissingBrackets
+            | InvalidZonedAddressError::EmptyZone
+            | InvalidZonedAddressError::MissingPort => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidZonedAddressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidZonedAddressError::MissingBrackets => write!(f, "missing brackets"),
+            InvalidZonedAddressError::InvalidAddress(error) => write!(f, "{}", error),
+            InvalidZonedAddressError::EmptyZone => write!(f, "empty zone"),
+            InvalidZonedAddressError::MissingPort => write!(f, "missing port"),
+            InvalidZonedAddressError::InvalidPort(error) => write!(f, "invalid port - {}", error),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidZonedAddressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl InvalidIPv4HeaderError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidIPv4HeaderError::Truncated => ErrorKind::Truncated,
+            InvalidIPv4HeaderError::InvalidVersion(_)
+            | InvalidIPv4HeaderError::InvalidHeaderLength(_)
+            | InvalidIPv4HeaderError::InvalidTotalLength(_) => ErrorKind::InvalidFormat,
+            InvalidIPv4HeaderError::OptionsTooLong(_) => ErrorKind::TooLong,
+        }
+    }
+}
+
+impl std::error::Error for InvalidIPv4HeaderError {}
+
+impl std::fmt::Display for InvalidIPv4HeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidIPv4HeaderError::Truncated => write!(f, "IPv4 packet is truncated"),
+            InvalidIPv4HeaderError::InvalidVersion(version) => {
+                write!(f, "invalid IP version {}", version)
+            }
+            InvalidIPv4HeaderError::InvalidHeaderLength(ihl) => {
+                write!(f, "invalid IPv4 header length {}", ihl)
+            }
+            InvalidIPv4HeaderError::InvalidTotalLength(length) => {
+                write!(f, "invalid IPv4 total length {}", length)
+            }
+            InvalidIPv4HeaderError::OptionsTooLong(length) => write!(
+                f,
+                "IPv4 options length {} exceeds the maximum of 40",
+                length
+            ),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidIPv4HeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl InvalidIPv6HeaderError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidIPv6HeaderError::Truncated => ErrorKind::Truncated,
+            InvalidIPv6HeaderError::InvalidVersion(_) => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidIPv6HeaderError {}
+
+impl std::fmt::Display for InvalidIPv6HeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidIPv6HeaderError::Truncated => write!(f, "IPv6 packet is truncated"),
+            InvalidIPv6HeaderError::InvalidVersion(version) => {
+                write!(f, "invalid IP version {}", version)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidIPv6HeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
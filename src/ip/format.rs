@@ -0,0 +1,47 @@
+use super::{v4::IPv4CIDR, v6::IPv6CIDR, IPCIDR};
+use defmt::{Format, Formatter};
+
+impl Format for IPv4CIDR {
+    fn format(&self, fmt: Formatter) {
+        let [a, b, c, d] = self.address().octets();
+        defmt::write!(
+            fmt,
+            "{=u8}.{=u8}.{=u8}.{=u8}/{=u8}",
+            a,
+            b,
+            c,
+            d,
+            self.prefix()
+        );
+    }
+}
+
+impl Format for IPv6CIDR {
+    /// Formats the address without compressing runs of zeros, so every segment is sent as a
+    /// number instead of as text
+    fn format(&self, fmt: Formatter) {
+        let [a, b, c, d, e, f, g, h] = self.address().segments();
+        defmt::write!(
+            fmt,
+            "{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}:{=u16:x}/{=u8}",
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+            g,
+            h,
+            self.prefix()
+        );
+    }
+}
+
+impl Format for IPCIDR {
+    fn format(&self, fmt: Formatter) {
+        match self {
+            IPCIDR::V4(cidr) => cidr.format(fmt),
+            IPCIDR::V6(cidr) => cidr.format(fmt),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use super::{
+    v4::{IPv4Address, IPv4CIDR},
+    v6::{IPv6Address, IPv6CIDR},
+    IPCIDR,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for IPv4CIDR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let address = IPv4Address::from(u.arbitrary::<[u8; 4]>()?);
+        Ok(IPv4CIDR::new(address, u.int_in_range(0..=32)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for IPv6CIDR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let address = IPv6Address::from(u.arbitrary::<[u8; 16]>()?);
+        Ok(IPv6CIDR::new(address, u.int_in_range(0..=128)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for IPCIDR {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.arbitrary()? {
+            IPCIDR::V6(u.arbitrary()?)
+        } else {
+            IPCIDR::V4(u.arbitrary()?)
+        })
+    }
+}
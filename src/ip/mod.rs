@@ -5,9 +5,28 @@ pub mod v6;
 
 mod cidr;
 mod error;
+mod parse;
+mod version;
+
+#[cfg(feature = "defmt")]
+mod format;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(any(feature = "postgres", feature = "sqlx"))]
+mod sql;
+#[cfg(feature = "proptest")]
+mod strategy;
 
 pub use cidr::IPCIDR;
-pub use error::{InvalidCIDRError, InvalidPrefixError};
+pub use error::{
+    InvalidCIDRError, InvalidIPv4HeaderError, InvalidIPv6HeaderError, InvalidPrefixError,
+    InvalidZonedAddressError,
+};
+pub use version::IPVersion;
 
 pub use std::net::IpAddr as IPAddress;
 pub use std::net::SocketAddr as IPSocketAddress;
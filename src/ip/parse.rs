@@ -0,0 +1,235 @@
+//! Address parsers which can run in a const context
+//!
+//! These work on byte ranges rather than slices because slices can not be indexed by a range in a
+//! const fn.
+
+use super::{v4::IPv4Address, v6::IPv6Address};
+
+/// Parses the prefix after the '/' of a CIDR
+///
+/// ## Parameters
+///  * `bytes` - The entire CIDR
+///  * `maximum` - The largest prefix allowed
+///
+/// ## Return Value
+/// Returns the index of the '/' and the prefix, if `bytes` has exactly one '/' followed by a
+/// valid prefix
+pub(crate) const fn parse_prefix(bytes: &[u8], maximum: u8) -> Option<(usize, u8)> {
+    let mut slash = 0;
+    while slash < bytes.len() && bytes[slash] != b'/' {
+        slash += 1;
+    }
+
+    if slash == bytes.len() {
+        return None;
+    }
+
+    match parse_decimal(bytes, slash + 1, bytes.len(), maximum as u16) {
+        Some(prefix) => Some((slash, prefix as u8)),
+        None => None,
+    }
+}
+
+/// Parses a dotted-quad IPv4 address from `bytes[start..end]`
+///
+/// Like the standard library, octets with leading zeros are rejected.
+///
+/// ## Parameters
+///  * `bytes` - The text containing the address
+///  * `start` - The index the address starts at
+///  * `end` - The index the address ends at
+///
+/// ## Return Value
+/// Returns the parsed address, if the range holds one
+pub(crate) const fn parse_ipv4(bytes: &[u8], start: usize, end: usize) -> Option<IPv4Address> {
+    let mut octets = [0; 4];
+    let mut index = start;
+    let mut i = 0;
+    while i < 4 {
+        let mut octet_end = index;
+        while octet_end < end && bytes[octet_end] != b'.' {
+            octet_end += 1;
+        }
+
+        if octet_end - index > 1 && bytes[index] == b'0' {
+            return None;
+        }
+
+        octets[i] = match parse_decimal(bytes, index, octet_end, 255) {
+            Some(octet) => octet as u8,
+            None => return None,
+        };
+
+        if i < 3 && octet_end == end {
+            return None;
+        }
+
+        index = octet_end + 1;
+        i += 1;
+    }
+
+    if index - 1 == end {
+        Some(IPv4Address::new(octets[0], octets[1], octets[2], octets[3]))
+    } else {
+        None
+    }
+}
+
+/// Parses an IPv6 address from `bytes[start..end]`
+///
+/// One "::" may stand in for a run of zero segments, and the last two segments may be written as
+/// a dotted-quad IPv4 address.
+///
+/// ## Parameters
+///  * `bytes` - The text containing the address
+///  * `start` - The index the address starts at
+///  * `end` - The index the address ends at
+///
+/// ## Return Value
+/// Returns the parsed address, if the range holds one
+pub(crate) const fn parse_ipv6(bytes: &[u8], start: usize, end: usize) -> Option<IPv6Address> {
+    // Segments before the "::" are in `head`, those after it in `tail`
+    let mut head = [0; 8];
+    let mut head_length = 0;
+    let mut tail = [0; 8];
+    let mut tail_length = 0;
+    let mut gap = false;
+
+    let mut index = start;
+    if end - start >= 2 && bytes[start] == b':' && bytes[start + 1] == b':' {
+        gap = true;
+        index += 2;
+    }
+
+    while index < end {
+        if head_length + tail_length == 8 {
+            return None;
+        }
+
+        let mut segment_end = index;
+        let mut embedded = false;
+        while segment_end < end && bytes[segment_end] != b':' {
+            embedded |= bytes[segment_end] == b'.';
+            segment_end += 1;
+        }
+
+        let mut segments = [0; 2];
+        let count = if embedded {
+            if segment_end != end || head_length + tail_length > 6 {
+                return None;
+            }
+
+            let octets = match parse_ipv4(bytes, index, end) {
+                Some(address) => address.octets(),
+                None => return None,
+            };
+            segments[0] = u16::from_be_bytes([octets[0], octets[1]]);
+            segments[1] = u16::from_be_bytes([octets[2], octets[3]]);
+            2
+        } else {
+            segments[0] = match parse_hex(bytes, index, segment_end) {
+                Some(segment) => segment,
+                None => return None,
+            };
+            1
+        };
+
+        let mut i = 0;
+        while i < count {
+            if gap {
+                tail[tail_length] = segments[i];
+                tail_length += 1;
+            } else {
+                head[head_length] = segments[i];
+                head_length += 1;
+            }
+            i += 1;
+        }
+
+        if segment_end == end {
+            break;
+        }
+
+        // Skip the ':' and check for the "::"
+        index = segment_end + 1;
+        if index < end && bytes[index] == b':' {
+            if gap {
+                return None;
+            }
+
+            gap = true;
+            index += 1;
+        } else if index == end {
+            return None;
+        }
+    }
+
+    let length = head_length + tail_length;
+    if (gap && length > 7) || (!gap && length != 8) {
+        return None;
+    }
+
+    let mut segments = head;
+    let mut i = 0;
+    while i < tail_length {
+        segments[8 - tail_length + i] = tail[i];
+        i += 1;
+    }
+
+    Some(IPv6Address::new(
+        segments[0],
+        segments[1],
+        segments[2],
+        segments[3],
+        segments[4],
+        segments[5],
+        segments[6],
+        segments[7],
+    ))
+}
+
+/// Parses a non-empty decimal number from `bytes[start..end]`
+const fn parse_decimal(bytes: &[u8], start: usize, end: usize, maximum: u16) -> Option<u16> {
+    if start >= end {
+        return None;
+    }
+
+    let mut value: u16 = 0;
+    let mut i = start;
+    while i < end {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+
+        value = value * 10 + (bytes[i] - b'0') as u16;
+        if value > maximum {
+            return None;
+        }
+        i += 1;
+    }
+
+    Some(value)
+}
+
+/// Parses a non-empty hexadecimal number of at most 4 digits from `bytes[start..end]`
+const fn parse_hex(bytes: &[u8], start: usize, end: usize) -> Option<u16> {
+    if start >= end || end - start > 4 {
+        return None;
+    }
+
+    let mut value = 0;
+    let mut i = start;
+    while i < end {
+        let digit = match bytes[i] {
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => return None,
+        };
+
+        value = (value << 4) | digit as u16;
+        i += 1;
+    }
+
+    Some(value)
+}
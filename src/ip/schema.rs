@@ -0,0 +1,59 @@
+use super::{v4::IPv4CIDR, v6::IPv6CIDR, IPCIDR};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use std::borrow::Cow;
+
+impl JsonSchema for IPv4CIDR {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "IPv4CIDR".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "An IPv4 address and prefix length, such as \"10.0.0.0/8\"",
+            "pattern": "^((25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])\\.){3}(25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])/(3[0-2]|[12]?[0-9])$"
+        })
+    }
+}
+
+impl JsonSchema for IPv6CIDR {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "IPv6CIDR".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "An IPv6 address and prefix length, such as \"2001:db8::/32\"",
+            "pattern": "^[0-9A-Fa-f.]*:[0-9A-Fa-f:.]*/(12[0-8]|1[01][0-9]|[1-9]?[0-9])$"
+        })
+    }
+}
+
+impl JsonSchema for IPCIDR {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "IPCIDR".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "An IPv4 or IPv6 address and prefix length",
+            "anyOf": [
+                generator.subschema_for::<IPv4CIDR>(),
+                generator.subschema_for::<IPv6CIDR>(),
+            ]
+        })
+    }
+}
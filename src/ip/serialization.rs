@@ -0,0 +1,78 @@
+use super::{
+    v4::{IPv4CIDR, PackedIPv4CIDR},
+    v6::{IPv6CIDR, PackedIPv6CIDR},
+    IPCIDR,
+};
+use crate::serialization::{deserialize_compact, serialize_compact};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl IPv4CIDR {
+    /// Gets the compact form of this CIDR, the bytes of its packed form
+    fn to_compact(self) -> [u8; 5] {
+        PackedIPv4CIDR::from(self).to_bytes()
+    }
+
+    /// Creates an [`IPv4CIDR`] from its compact form
+    fn from_compact(bytes: &[u8]) -> Option<Self> {
+        PackedIPv4CIDR::from_bytes(bytes.try_into().ok()?)
+            .try_into()
+            .ok()
+    }
+}
+
+impl IPv6CIDR {
+    /// Gets the compact form of this CIDR, the bytes of its packed form
+    fn to_compact(self) -> [u8; 17] {
+        PackedIPv6CIDR::from(self).to_bytes()
+    }
+
+    /// Creates an [`IPv6CIDR`] from its compact form
+    fn from_compact(bytes: &[u8]) -> Option<Self> {
+        PackedIPv6CIDR::from_bytes(bytes.try_into().ok()?)
+            .try_into()
+            .ok()
+    }
+}
+
+impl Serialize for IPv4CIDR {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_compact(self, &self.to_compact(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IPv4CIDR {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer, "an IPv4 CIDR", IPv4CIDR::from_compact)
+    }
+}
+
+impl Serialize for IPv6CIDR {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_compact(self, &self.to_compact(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IPv6CIDR {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer, "an IPv6 CIDR", IPv6CIDR::from_compact)
+    }
+}
+
+impl Serialize for IPCIDR {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            IPCIDR::V4(cidr) => serialize_compact(self, &cidr.to_compact(), serializer),
+            IPCIDR::V6(cidr) => serialize_compact(self, &cidr.to_compact(), serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for IPCIDR {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // The length of the compact form identifies the IP version
+        deserialize_compact(deserializer, "an IP CIDR", |bytes| match bytes.len() {
+            5 => IPv4CIDR::from_compact(bytes).map(IPCIDR::V4),
+            _ => IPv6CIDR::from_compact(bytes).map(IPCIDR::V6),
+        })
+    }
+}
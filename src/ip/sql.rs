@@ -0,0 +1,159 @@
+use super::{v4::IPv4CIDR, v6::IPv6CIDR, IPAddress, IPCIDR};
+
+/// The address family of an IPv4 value in the PostgreSQL binary format
+const PGSQL_AF_INET: u8 = 2;
+
+/// The address family of an IPv6 value in the PostgreSQL binary format
+const PGSQL_AF_INET6: u8 = 3;
+
+impl IPCIDR {
+    /// Writes this CIDR in the PostgreSQL binary format of INET and CIDR values
+    ///
+    /// ## Parameters
+    ///  * `is_cidr` - True if the value is written to a CIDR column, which rejects host bits so
+    ///    only the network is written
+    ///  * `output` - The buffer to write the value into
+    fn write_inet<E: Extend<u8>>(&self, is_cidr: bool, output: &mut E) {
+        match self {
+            IPCIDR::V4(cidr) => {
+                output.extend([PGSQL_AF_INET, cidr.prefix(), is_cidr as u8, 4]);
+                let address = if is_cidr {
+                    cidr.network()
+                } else {
+                    cidr.address()
+                };
+                output.extend(address.octets());
+            }
+            IPCIDR::V6(cidr) => {
+                output.extend([PGSQL_AF_INET6, cidr.prefix(), is_cidr as u8, 16]);
+                let address = if is_cidr {
+                    cidr.first_address()
+                } else {
+                    cidr.address()
+                };
+                output.extend(address.octets());
+            }
+        }
+    }
+
+    /// Reads a CIDR from the PostgreSQL binary format of INET and CIDR values
+    ///
+    /// ## Parameters
+    ///  * `raw` - The value to read
+    ///
+    /// ## Return Value
+    /// Returns the read CIDR, or [`None`] if `raw` is not a valid value
+    fn read_inet(raw: &[u8]) -> Option<Self> {
+        let (&[family, prefix, _, length], address) = raw.split_first_chunk()?;
+
+        match (family, length) {
+            (PGSQL_AF_INET, 4) => {
+                let address: [u8; 4] = address.try_into().ok()?;
+                IPv4CIDR::try_new(address.into(), prefix)
+                    .ok()
+                    .map(IPCIDR::V4)
+            }
+            (PGSQL_AF_INET6, 16) => {
+                let address: [u8; 16] = address.try_into().ok()?;
+                IPv6CIDR::try_new(address.into(), prefix)
+                    .ok()
+                    .map(IPCIDR::V6)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses a CIDR from the PostgreSQL text format of INET and CIDR values, where the prefix is
+    /// left off of single hosts
+    ///
+    /// ## Parameters
+    ///  * `text` - The value to parse
+    ///
+    /// ## Return Value
+    /// Returns the parsed CIDR, or [`None`] if `text` is not a valid value
+    #[cfg_attr(not(feature = "sqlx"), allow(dead_code))]
+    fn parse_inet(text: &str) -> Option<Self> {
+        if text.contains('/') {
+            return text.parse().ok();
+        }
+
+        Some(match text.parse().ok()? {
+            IPAddress::V4(address) => IPCIDR::V4(IPv4CIDR::new(address, 32)),
+            IPAddress::V6(address) => IPCIDR::V6(IPv6CIDR::new(address, 128)),
+        })
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres {
+    use crate::ip::IPCIDR;
+    use bytes::BytesMut;
+    use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+    use std::error::Error;
+
+    impl<'a> FromSql<'a> for IPCIDR {
+        fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            IPCIDR::read_inet(raw).ok_or_else(|| "invalid INET or CIDR value".into())
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            *ty == Type::INET || *ty == Type::CIDR
+        }
+    }
+
+    impl ToSql for IPCIDR {
+        fn to_sql(
+            &self,
+            ty: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            self.write_inet(*ty == Type::CIDR, out);
+            Ok(IsNull::No)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            *ty == Type::INET || *ty == Type::CIDR
+        }
+
+        to_sql_checked!();
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use crate::ip::IPCIDR;
+    use sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres},
+        Decode, Encode, Type,
+    };
+
+    impl Type<Postgres> for IPCIDR {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("inet")
+        }
+
+        fn compatible(ty: &PgTypeInfo) -> bool {
+            *ty == PgTypeInfo::with_name("inet") || *ty == PgTypeInfo::with_name("cidr")
+        }
+    }
+
+    impl Encode<'_, Postgres> for IPCIDR {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            // The server ignores the CIDR flag when receiving a value
+            self.write_inet(false, &mut **buf);
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for IPCIDR {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => IPCIDR::read_inet(value.as_bytes()?),
+                PgValueFormat::Text => IPCIDR::parse_inet(value.as_str()?),
+            }
+            .ok_or_else(|| "invalid INET or CIDR value".into())
+        }
+    }
+}
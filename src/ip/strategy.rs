@@ -0,0 +1,81 @@
+use super::{
+    v4::{IPv4Address, IPv4CIDR},
+    v6::{IPv6Address, IPv6CIDR},
+    IPCIDR,
+};
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+/// Edge case IPv4 CIDRs: the whole space, single hosts at either end, and special-use ranges
+const IPV4_EDGE_CASES: &[IPv4CIDR] = &[
+    IPv4CIDR::new(IPv4Address::UNSPECIFIED, 0),
+    IPv4CIDR::new(IPv4Address::UNSPECIFIED, 32),
+    IPv4CIDR::new(IPv4Address::BROADCAST, 32),
+    IPv4CIDR::new(IPv4Address::LOCALHOST, 8),
+    IPv4CIDR::new(IPv4Address::new(10, 0, 0, 0), 8),
+    IPv4CIDR::new(IPv4Address::new(169, 254, 0, 0), 16),
+    IPv4CIDR::new(IPv4Address::new(192, 168, 1, 1), 31),
+    IPv4CIDR::new(IPv4Address::new(224, 0, 0, 0), 4),
+];
+
+/// Edge case IPv6 CIDRs: the whole space, single hosts, and special-use ranges
+const IPV6_EDGE_CASES: &[IPv6CIDR] = &[
+    IPv6CIDR::new(IPv6Address::UNSPECIFIED, 0),
+    IPv6CIDR::new(IPv6Address::UNSPECIFIED, 128),
+    IPv6CIDR::new(IPv6Address::LOCALHOST, 128),
+    IPv6CIDR::new(
+        IPv6Address::new(
+            0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF,
+        ),
+        128,
+    ),
+    IPv6CIDR::new(IPv6Address::new(0, 0, 0, 0, 0, 0xFFFF, 0, 0), 96),
+    IPv6CIDR::new(IPv6Address::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 0), 32),
+    IPv6CIDR::new(IPv6Address::new(0xFE80, 0, 0, 0, 0, 0, 0, 0), 10),
+    IPv6CIDR::new(IPv6Address::new(0xFF00, 0, 0, 0, 0, 0, 0, 0), 8),
+];
+
+impl Arbitrary for IPv4CIDR {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            3 => (any::<[u8; 4]>(), 0..=32u8)
+                .prop_map(|(octets, prefix)| IPv4CIDR::new(IPv4Address::from(octets), prefix)),
+            1 => select(IPV4_EDGE_CASES),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for IPv6CIDR {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            3 => (any::<[u8; 16]>(), 0..=128u8)
+                .prop_map(|(octets, prefix)| IPv6CIDR::new(IPv6Address::from(octets), prefix)),
+            1 => select(IPV6_EDGE_CASES),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for IPCIDR {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            any::<IPv4CIDR>().prop_map(IPCIDR::V4),
+            any::<IPv6CIDR>().prop_map(IPCIDR::V6),
+        ]
+        .boxed()
+    }
+}
@@ -1,5 +1,11 @@
 use super::IPv4Address;
-use crate::ip::{InvalidCIDRError, InvalidPrefixError, IPCIDR};
+use crate::{
+    ip::{
+        parse::{parse_ipv4, parse_prefix},
+        InvalidCIDRError, InvalidPrefixError, IPCIDR,
+    },
+    padding::pad,
+};
 use std::{cmp::Ordering, str::FromStr};
 
 /// An IPv4 Classless Inter-Domain Routing (CIDR) address
@@ -52,6 +58,29 @@ impl IPv4CIDR {
         IPv4CIDR { address, prefix }
     }
 
+    /// Parses a CIDR in "address/prefix" form in a const context
+    ///
+    /// [`FromStr`] can not be used to initialize a const item, so this parses the same form
+    /// without reporting why the text is invalid.
+    ///
+    /// ## Parameters
+    ///  * `s` - The text to parse
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`IPv4CIDR`], or [`None`] if `s` is not a valid IPv4 CIDR
+    pub const fn parse_const(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        let (slash, prefix) = match parse_prefix(bytes, 32) {
+            Some(prefix) => prefix,
+            None => return None,
+        };
+
+        match parse_ipv4(bytes, 0, slash) {
+            Some(address) => Some(IPv4CIDR { address, prefix }),
+            None => None,
+        }
+    }
+
     /// Gets the address of this CIDR
     ///
     /// ## Return Value
@@ -68,6 +97,67 @@ impl IPv4CIDR {
         self.prefix
     }
 
+    /// Checks if `address` is in the network of this CIDR
+    ///
+    /// ## Parameters
+    ///  * `address` - The address to check
+    ///
+    /// ## Return Value
+    /// Returns true if the first `prefix` bits of `address` match this CIDR
+    pub const fn contains(&self, address: IPv4Address) -> bool {
+        (self.address.to_bits() ^ address.to_bits()) & mask(self.prefix) == 0
+    }
+
+    /// Gets the network address of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this CIDR with every host bit cleared
+    pub const fn network(&self) -> IPv4Address {
+        IPv4Address::from_bits(self.address.to_bits() & mask(self.prefix))
+    }
+
+    /// Gets the broadcast address of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this CIDR with every host bit set
+    pub const fn broadcast(&self) -> IPv4Address {
+        IPv4Address::from_bits(self.address.to_bits() | !mask(self.prefix))
+    }
+
+    /// Checks if the address of this CIDR is its network address
+    ///
+    /// ## Return Value
+    /// Returns true if every host bit of the address is cleared, which is never the case for a
+    /// /31 or /32 as every address of those is a host address
+    pub const fn is_network_address(&self) -> bool {
+        self.prefix < 31 && self.address.to_bits() == self.network().to_bits()
+    }
+
+    /// Checks if the address of this CIDR is its broadcast address
+    ///
+    /// ## Return Value
+    /// Returns true if every host bit of the address is set, which is never the case for a /31
+    /// or /32 as every address of those is a host address
+    pub const fn is_broadcast_address(&self) -> bool {
+        self.prefix < 31 && self.address.to_bits() == self.broadcast().to_bits()
+    }
+
+    /// Gets the netmask of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the mask with the first `prefix` bits set
+    pub const fn netmask(&self) -> IPv4Address {
+        IPv4Address::from_bits(mask(self.prefix))
+    }
+
+    /// Gets the wildcard mask of this CIDR, as used by access lists
+    ///
+    /// ## Return Value
+    /// Returns the mask with every bit after the first `prefix` bits set
+    pub const fn wildcard_mask(&self) -> IPv4Address {
+        IPv4Address::from_bits(!mask(self.prefix))
+    }
+
     /// Sets the address for this CIDR
     ///
     /// ## Parameters
@@ -140,7 +230,7 @@ impl FromStr for IPv4CIDR {
 
 impl std::fmt::Display for IPv4CIDR {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}/{}", self.address, self.prefix)
+        pad(f, format_args!("{}/{}", self.address, self.prefix))
     }
 }
 
@@ -173,3 +263,11 @@ impl PartialOrd<IPCIDR> for IPv4CIDR {
         }
     }
 }
+
+/// Gets the mask with the first `prefix` bits set
+const fn mask(prefix: u8) -> u32 {
+    match u32::MAX.checked_shl(32 - prefix as u32) {
+        Some(mask) => mask,
+        None => 0,
+    }
+}
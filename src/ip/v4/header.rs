@@ -0,0 +1,436 @@
+use super::IPv4Address;
+use crate::{checksum::checksum, ip::InvalidIPv4HeaderError};
+
+/// The header of an IPv4 packet from RFC 791
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IPv4Header {
+    dscp: u8,
+    ecn: u8,
+    total_length: u16,
+    identification: u16,
+    flags: u8,
+    fragment_offset: u16,
+    ttl: u8,
+    protocol: u8,
+    checksum: u16,
+    source: IPv4Address,
+    destination: IPv4Address,
+    options: [u8; IPv4Header::MAX_OPTIONS_LENGTH],
+    options_length: usize,
+}
+
+/// The flag marking a packet which may not be fragmented
+const DONT_FRAGMENT: u8 = 0b010;
+
+/// The flag marking a fragment which is followed by more fragments
+const MORE_FRAGMENTS: u8 = 0b001;
+
+impl IPv4Header {
+    /// The length of a header without options
+    pub const MIN_LENGTH: usize = 20;
+
+    /// The length of a header with the most options
+    pub const MAX_LENGTH: usize = 60;
+
+    /// The most bytes of options a header can hold
+    pub const MAX_OPTIONS_LENGTH: usize = IPv4Header::MAX_LENGTH - IPv4Header::MIN_LENGTH;
+
+    /// The time to live of a newly created header
+    pub const DEFAULT_TTL: u8 = 64;
+
+    /// Creates a new [`IPv4Header`] without options
+    ///
+    /// ## Parameters
+    ///  * `source` - The address the packet is sent from
+    ///  * `destination` - The address the packet is sent to
+    ///  * `protocol` - The protocol number of the payload
+    ///  * `payload_length` - The length of the payload following the header
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`IPv4Header`]
+    pub const fn new(
+        source: IPv4Address,
+        destination: IPv4Address,
+        protocol: u8,
+        payload_length: u16,
+    ) -> Self {
+        IPv4Header {
+            dscp: 0,
+            ecn: 0,
+            total_length: payload_length.saturating_add(IPv4Header::MIN_LENGTH as u16),
+            identification: 0,
+            flags: 0,
+            fragment_offset: 0,
+            ttl: IPv4Header::DEFAULT_TTL,
+            protocol,
+            checksum: 0,
+            source,
+            destination,
+            options: [0; IPv4Header::MAX_OPTIONS_LENGTH],
+            options_length: 0,
+        }
+    }
+
+    /// Sets the differentiated services code point
+    ///
+    /// ## Parameters
+    ///  * `dscp` - The code point, of which only the low 6 bits are kept
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`]
+    pub const fn with_dscp(mut self, dscp: u8) -> Self {
+        self.dscp = dscp & 0x3F;
+        self
+    }
+
+    /// Sets the explicit congestion notification bits
+    ///
+    /// ## Parameters
+    ///  * `ecn` - The notification, of which only the low 2 bits are kept
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`]
+    pub const fn with_ecn(mut self, ecn: u8) -> Self {
+        self.ecn = ecn & 0x03;
+        self
+    }
+
+    /// Sets the identification used to reassemble fragments
+    ///
+    /// ## Parameters
+    ///  * `identification` - The identification of the packet
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`]
+    pub const fn with_identification(mut self, identification: u16) -> Self {
+        self.identification = identification;
+        self
+    }
+
+    /// Sets whether the packet may be fragmented
+    ///
+    /// ## Parameters
+    ///  * `dont_fragment` - The value of the don't fragment flag
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`]
+    pub const fn with_dont_fragment(mut self, dont_fragment: bool) -> Self {
+        self.flags = set_flag(self.flags, DONT_FRAGMENT, dont_fragment);
+        self
+    }
+
+    /// Sets whether more fragments follow this one
+    ///
+    /// ## Parameters
+    ///  * `more_fragments` - The value of the more fragments flag
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`]
+    pub const fn with_more_fragments(mut self, more_fragments: bool) -> Self {
+        self.flags = set_flag(self.flags, MORE_FRAGMENTS, more_fragments);
+        self
+    }
+
+    /// Sets the offset of this fragment in the original payload
+    ///
+    /// ## Parameters
+    ///  * `offset` - The offset in units of 8 bytes, of which only the low 13 bits are kept
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`]
+    pub const fn with_fragment_offset(mut self, offset: u16) -> Self {
+        self.fragment_offset = offset & 0x1FFF;
+        self
+    }
+
+    /// Sets the time to live
+    ///
+    /// ## Parameters
+    ///  * `ttl` - The number of hops the packet may take
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`]
+    pub const fn with_ttl(mut self, ttl: u8) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the options, replacing any existing options
+    ///
+    /// The options are padded with zeros, the end of options list, to a multiple of 4 bytes. The
+    /// total length grows or shrinks with the header.
+    ///
+    /// ## Parameters
+    ///  * `options` - The encoded options
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv4Header`], or an error if `options` is longer than 40 bytes
+    pub fn with_options(mut self, options: &[u8]) -> Result<Self, InvalidIPv4HeaderError> {
+        if options.len() > IPv4Header::MAX_OPTIONS_LENGTH {
+            return Err(InvalidIPv4HeaderError::OptionsTooLong(options.len()));
+        }
+
+        let payload_length = self.payload_len();
+
+        self.options = [0; IPv4Header::MAX_OPTIONS_LENGTH];
+        self.options[..options.len()].copy_from_slice(options);
+        self.options_length = options.len().div_ceil(4) * 4;
+        self.total_length = (self.header_len() + payload_length) as u16;
+
+        Ok(self)
+    }
+
+    /// Parses the header at the start of `packet`
+    ///
+    /// The payload is borrowed from `packet` and ends where the total length says the packet
+    /// ends, so any link-layer padding is dropped. The checksum is not verified.
+    ///
+    /// ## Parameters
+    ///  * `packet` - The packet, starting at the version
+    ///
+    /// ## Return Value
+    /// Returns the parsed header and the payload following it
+    pub fn parse(packet: &[u8]) -> Result<(Self, &[u8]), InvalidIPv4HeaderError> {
+        if packet.len() < IPv4Header::MIN_LENGTH {
+            return Err(InvalidIPv4HeaderError::Truncated);
+        }
+
+        let version = packet[0] >> 4;
+        if version != 4 {
+            return Err(InvalidIPv4HeaderError::InvalidVersion(version));
+        }
+
+        let ihl = packet[0] & 0x0F;
+        let header_length = ihl as usize * 4;
+        if header_length < IPv4Header::MIN_LENGTH {
+            return Err(InvalidIPv4HeaderError::InvalidHeaderLength(ihl));
+        }
+
+        let total_length = u16::from_be_bytes([packet[2], packet[3]]);
+        if (total_length as usize) < header_length {
+            return Err(InvalidIPv4HeaderError::InvalidTotalLength(total_length));
+        }
+
+        let packet = packet
+            .get(..total_length as usize)
+            .ok_or(InvalidIPv4HeaderError::Truncated)?;
+
+        let fragment = u16::from_be_bytes([packet[6], packet[7]]);
+        let options = &packet[IPv4Header::MIN_LENGTH..header_length];
+
+        let mut header = IPv4Header {
+            dscp: packet[1] >> 2,
+            ecn: packet[1] & 0x03,
+            total_length,
+            identification: u16::from_be_bytes([packet[4], packet[5]]),
+            flags: (fragment >> 13) as u8,
+            fragment_offset: fragment & 0x1FFF,
+            ttl: packet[8],
+            protocol: packet[9],
+            checksum: u16::from_be_bytes([packet[10], packet[11]]),
+            source: IPv4Address::new(packet[12], packet[13], packet[14], packet[15]),
+            destination: IPv4Address::new(packet[16], packet[17], packet[18], packet[19]),
+            options: [0; IPv4Header::MAX_OPTIONS_LENGTH],
+            options_length: options.len(),
+        };
+        header.options[..options.len()].copy_from_slice(options);
+
+        Ok((header, &packet[header_length..]))
+    }
+
+    /// Encodes this header with a correct checksum
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded header to
+    pub fn write(&self, output: &mut Vec<u8>) {
+        self.write_with_checksum(output, self.compute_checksum());
+    }
+
+    /// Computes the correct checksum of this header
+    ///
+    /// ## Return Value
+    /// Returns the checksum of this header with the checksum field taken as zero
+    pub fn compute_checksum(&self) -> u16 {
+        let mut header = Vec::with_capacity(self.header_len());
+        self.write_with_checksum(&mut header, 0);
+        checksum(&header)
+    }
+
+    /// Verifies the checksum carried in this header
+    ///
+    /// ## Return Value
+    /// Returns true if the checksum carried in this header is correct
+    pub fn verify_checksum(&self) -> bool {
+        self.checksum == self.compute_checksum()
+    }
+
+    /// Gets the internet header length
+    ///
+    /// ## Return Value
+    /// Returns the length of this header in 32-bit words
+    pub const fn ihl(&self) -> u8 {
+        (self.header_len() / 4) as u8
+    }
+
+    /// Gets the length of this header
+    ///
+    /// ## Return Value
+    /// Returns the length of this header in bytes, including options
+    pub const fn header_len(&self) -> usize {
+        IPv4Header::MIN_LENGTH + self.options_length
+    }
+
+    /// Gets the length of the payload
+    ///
+    /// ## Return Value
+    /// Returns the total length less the length of this header
+    pub const fn payload_len(&self) -> usize {
+        self.total_length as usize - self.header_len()
+    }
+
+    /// Gets the differentiated services code point
+    ///
+    /// ## Return Value
+    /// Returns the code point, from 0 to 63
+    pub const fn dscp(&self) -> u8 {
+        self.dscp
+    }
+
+    /// Gets the explicit congestion notification bits
+    ///
+    /// ## Return Value
+    /// Returns the notification, from 0 to 3
+    pub const fn ecn(&self) -> u8 {
+        self.ecn
+    }
+
+    /// Gets the total length of the packet
+    ///
+    /// ## Return Value
+    /// Returns the length of this header and the payload in bytes
+    pub const fn total_length(&self) -> u16 {
+        self.total_length
+    }
+
+    /// Gets the identification used to reassemble fragments
+    ///
+    /// ## Return Value
+    /// Returns the identification of the packet
+    pub const fn identification(&self) -> u16 {
+        self.identification
+    }
+
+    /// Gets the flags
+    ///
+    /// ## Return Value
+    /// Returns the 3 flag bits, starting with the reserved bit as the most significant
+    pub const fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Gets whether the packet may be fragmented
+    ///
+    /// ## Return Value
+    /// Returns true if the don't fragment flag is set
+    pub const fn dont_fragment(&self) -> bool {
+        self.flags & DONT_FRAGMENT != 0
+    }
+
+    /// Gets whether more fragments follow this one
+    ///
+    /// ## Return Value
+    /// Returns true if the more fragments flag is set
+    pub const fn more_fragments(&self) -> bool {
+        self.flags & MORE_FRAGMENTS != 0
+    }
+
+    /// Gets the offset of this fragment in the original payload
+    ///
+    /// ## Return Value
+    /// Returns the offset in units of 8 bytes
+    pub const fn fragment_offset(&self) -> u16 {
+        self.fragment_offset
+    }
+
+    /// Checks if this packet is a fragment of a larger packet
+    ///
+    /// ## Return Value
+    /// Returns true if more fragments follow or the fragment offset is not zero
+    pub const fn is_fragment(&self) -> bool {
+        self.more_fragments() || self.fragment_offset != 0
+    }
+
+    /// Gets the time to live
+    ///
+    /// ## Return Value
+    /// Returns the number of hops the packet may still take
+    pub const fn ttl(&self) -> u8 {
+        self.ttl
+    }
+
+    /// Gets the protocol of the payload
+    ///
+    /// ## Return Value
+    /// Returns the protocol number of the payload
+    pub const fn protocol(&self) -> u8 {
+        self.protocol
+    }
+
+    /// Gets the checksum carried in this header
+    ///
+    /// ## Return Value
+    /// Returns the parsed checksum, or zero for a header which was not parsed
+    pub const fn checksum(&self) -> u16 {
+        self.checksum
+    }
+
+    /// Gets the address the packet is sent from
+    ///
+    /// ## Return Value
+    /// Returns the source address
+    pub const fn source(&self) -> IPv4Address {
+        self.source
+    }
+
+    /// Gets the address the packet is sent to
+    ///
+    /// ## Return Value
+    /// Returns the destination address
+    pub const fn destination(&self) -> IPv4Address {
+        self.destination
+    }
+
+    /// Gets the encoded options
+    ///
+    /// ## Return Value
+    /// Returns the options, including any padding
+    pub fn options(&self) -> &[u8] {
+        &self.options[..self.options_length]
+    }
+
+    /// Encodes this header with `checksum` in the checksum field
+    fn write_with_checksum(&self, output: &mut Vec<u8>, checksum: u16) {
+        output.reserve(self.header_len());
+        output.push(0x40 | self.ihl());
+        output.push((self.dscp << 2) | self.ecn);
+        output.extend_from_slice(&self.total_length.to_be_bytes());
+        output.extend_from_slice(&self.identification.to_be_bytes());
+        output
+            .extend_from_slice(&(((self.flags as u16) << 13) | self.fragment_offset).to_be_bytes());
+        output.push(self.ttl);
+        output.push(self.protocol);
+        output.extend_from_slice(&checksum.to_be_bytes());
+        output.extend_from_slice(&self.source.octets());
+        output.extend_from_slice(&self.destination.octets());
+        output.extend_from_slice(self.options());
+    }
+}
+
+/// Sets or clears `flag` in `flags`
+const fn set_flag(flags: u8, flag: u8, value: bool) -> u8 {
+    if value {
+        flags | flag
+    } else {
+        flags & !flag
+    }
+}
@@ -1,8 +1,12 @@
 //! IPv4 address utilities
 
 mod cidr;
+mod header;
+mod packed;
 
 pub use cidr::IPv4CIDR;
+pub use header::IPv4Header;
+pub use packed::{PackedIPv4CIDR, PackedIPv4Header};
 
 pub use std::net::Ipv4Addr as IPv4Address;
 pub use std::net::SocketAddrV4 as IPv4SocketAddress;
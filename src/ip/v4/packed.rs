@@ -0,0 +1,307 @@
+use super::{IPv4Address, IPv4CIDR};
+use crate::ip::InvalidPrefixError;
+
+/// An [`IPv4CIDR`] with a fixed layout of the address octets followed by the prefix
+///
+/// Any 5 bytes form a packed CIDR, so the prefix is only checked when converting back into an
+/// [`IPv4CIDR`].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PackedIPv4CIDR {
+    address: [u8; 4],
+    prefix: u8,
+}
+
+/// The header of an IPv4 packet with the fixed layout it has on the wire
+///
+/// Only the first 20 bytes are covered, so any options follow the packed header.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PackedIPv4Header {
+    version_ihl: u8,
+    dscp_ecn: u8,
+    total_length: [u8; 2],
+    identification: [u8; 2],
+    flags_fragment_offset: [u8; 2],
+    ttl: u8,
+    protocol: u8,
+    checksum: [u8; 2],
+    source: [u8; 4],
+    destination: [u8; 4],
+}
+
+impl PackedIPv4CIDR {
+    /// The length of a packed CIDR
+    pub const LENGTH: usize = 5;
+
+    /// Creates a [`PackedIPv4CIDR`] from its bytes
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The address octets followed by the prefix
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedIPv4CIDR`]
+    pub const fn from_bytes(bytes: [u8; 5]) -> Self {
+        PackedIPv4CIDR {
+            address: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            prefix: bytes[4],
+        }
+    }
+
+    /// Gets the bytes of this packed CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address octets followed by the prefix
+    pub const fn to_bytes(self) -> [u8; 5] {
+        let [a, b, c, d] = self.address;
+        [a, b, c, d, self.prefix]
+    }
+
+    /// Gets the address of this packed CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this packed CIDR
+    pub const fn address(&self) -> IPv4Address {
+        let [a, b, c, d] = self.address;
+        IPv4Address::new(a, b, c, d)
+    }
+
+    /// Gets the prefix of this packed CIDR
+    ///
+    /// ## Return Value
+    /// Returns the prefix, which may be greater than 32
+    pub const fn prefix(&self) -> u8 {
+        self.prefix
+    }
+}
+
+impl From<IPv4CIDR> for PackedIPv4CIDR {
+    fn from(cidr: IPv4CIDR) -> Self {
+        PackedIPv4CIDR {
+            address: cidr.address().octets(),
+            prefix: cidr.prefix(),
+        }
+    }
+}
+
+impl TryFrom<PackedIPv4CIDR> for IPv4CIDR {
+    type Error = InvalidPrefixError;
+
+    fn try_from(packed: PackedIPv4CIDR) -> Result<Self, Self::Error> {
+        IPv4CIDR::try_new(packed.address(), packed.prefix)
+    }
+}
+
+impl std::fmt::Debug for PackedIPv4CIDR {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address(), self.prefix)
+    }
+}
+
+impl PackedIPv4Header {
+    /// The length of a packed header
+    pub const LENGTH: usize = 20;
+
+    /// Creates a [`PackedIPv4Header`] from its bytes
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The first 20 bytes of the packet
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedIPv4Header`]
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        PackedIPv4Header {
+            version_ihl: bytes[0],
+            dscp_ecn: bytes[1],
+            total_length: [bytes[2], bytes[3]],
+            identification: [bytes[4], bytes[5]],
+            flags_fragment_offset: [bytes[6], bytes[7]],
+            ttl: bytes[8],
+            protocol: bytes[9],
+            checksum: [bytes[10], bytes[11]],
+            source: [bytes[12], bytes[13], bytes[14], bytes[15]],
+            destination: [bytes[16], bytes[17], bytes[18], bytes[19]],
+        }
+    }
+
+    /// Gets the bytes of this packed header
+    ///
+    /// ## Return Value
+    /// Returns the 20 bytes of the header as they appear on the wire
+    pub const fn to_bytes(self) -> [u8; 20] {
+        let mut bytes = [0; 20];
+        bytes[0] = self.version_ihl;
+        bytes[1] = self.dscp_ecn;
+        bytes[2] = self.total_length[0];
+        bytes[3] = self.total_length[1];
+        bytes[4] = self.identification[0];
+        bytes[5] = self.identification[1];
+        bytes[6] = self.flags_fragment_offset[0];
+        bytes[7] = self.flags_fragment_offset[1];
+        bytes[8] = self.ttl;
+        bytes[9] = self.protocol;
+        bytes[10] = self.checksum[0];
+        bytes[11] = self.checksum[1];
+        let mut i = 0;
+        while i < 4 {
+            bytes[12 + i] = self.source[i];
+            bytes[16 + i] = self.destination[i];
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Gets the IP version
+    ///
+    /// ## Return Value
+    /// Returns the version, which is 4 for a valid header
+    pub const fn version(&self) -> u8 {
+        self.version_ihl >> 4
+    }
+
+    /// Gets the internet header length
+    ///
+    /// ## Return Value
+    /// Returns the length of the complete header in 32-bit words
+    pub const fn ihl(&self) -> u8 {
+        self.version_ihl & 0x0F
+    }
+
+    /// Gets the differentiated services code point
+    ///
+    /// ## Return Value
+    /// Returns the 6-bit DSCP
+    pub const fn dscp(&self) -> u8 {
+        self.dscp_ecn >> 2
+    }
+
+    /// Gets the explicit congestion notification
+    ///
+    /// ## Return Value
+    /// Returns the 2-bit ECN
+    pub const fn ecn(&self) -> u8 {
+        self.dscp_ecn & 0b11
+    }
+
+    /// Gets the total length
+    ///
+    /// ## Return Value
+    /// Returns the length of the header and payload in bytes
+    pub const fn total_length(&self) -> u16 {
+        u16::from_be_bytes(self.total_length)
+    }
+
+    /// Gets the identification
+    ///
+    /// ## Return Value
+    /// Returns the value shared by the fragments of a packet
+    pub const fn identification(&self) -> u16 {
+        u16::from_be_bytes(self.identification)
+    }
+
+    /// Gets the flags
+    ///
+    /// ## Return Value
+    /// Returns the 3-bit flags
+    pub const fn flags(&self) -> u8 {
+        self.flags_fragment_offset[0] >> 5
+    }
+
+    /// Gets the fragment offset
+    ///
+    /// ## Return Value
+    /// Returns the offset of this fragment in units of 8 bytes
+    pub const fn fragment_offset(&self) -> u16 {
+        u16::from_be_bytes(self.flags_fragment_offset) & 0x1FFF
+    }
+
+    /// Gets the time to live
+    ///
+    /// ## Return Value
+    /// Returns the time to live
+    pub const fn ttl(&self) -> u8 {
+        self.ttl
+    }
+
+    /// Gets the protocol number of the payload
+    ///
+    /// ## Return Value
+    /// Returns the protocol number
+    pub const fn protocol(&self) -> u8 {
+        self.protocol
+    }
+
+    /// Gets the checksum
+    ///
+    /// ## Return Value
+    /// Returns the header checksum as written
+    pub const fn checksum(&self) -> u16 {
+        u16::from_be_bytes(self.checksum)
+    }
+
+    /// Gets the source address
+    ///
+    /// ## Return Value
+    /// Returns the address the packet is sent from
+    pub const fn source(&self) -> IPv4Address {
+        let [a, b, c, d] = self.source;
+        IPv4Address::new(a, b, c, d)
+    }
+
+    /// Gets the destination address
+    ///
+    /// ## Return Value
+    /// Returns the address the packet is sent to
+    pub const fn destination(&self) -> IPv4Address {
+        let [a, b, c, d] = self.destination;
+        IPv4Address::new(a, b, c, d)
+    }
+}
+
+impl std::fmt::Debug for PackedIPv4Header {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackedIPv4Header")
+            .field("version", &self.version())
+            .field("ihl", &self.ihl())
+            .field("dscp", &self.dscp())
+            .field("ecn", &self.ecn())
+            .field("total_length", &self.total_length())
+            .field("identification", &self.identification())
+            .field("flags", &self.flags())
+            .field("fragment_offset", &self.fragment_offset())
+            .field("ttl", &self.ttl)
+            .field("protocol", &self.protocol)
+            .field("checksum", &self.checksum())
+            .field("source", &self.source())
+            .field("destination", &self.destination())
+            .finish()
+    }
+}
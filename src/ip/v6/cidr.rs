@@ -1,11 +1,18 @@
 use super::IPv6Address;
-use crate::ip::{InvalidCIDRError, InvalidPrefixError, IPCIDR};
+use crate::{
+    ip::{
+        parse::{parse_ipv6, parse_prefix},
+        InvalidCIDRError, InvalidPrefixError, IPCIDR,
+    },
+    padding::pad,
+};
 use std::{cmp::Ordering, str::FromStr};
 
 /// An IPv6 Classless Inter-Domain Routing (CIDR) address
 #[derive(Clone, Copy, PartialEq, Eq, Ord, Hash)]
 pub struct IPv6CIDR {
-    address: IPv6Address,
+    /// The address as an integer, so masking and ordering need no conversion
+    address: u128,
     prefix: u8,
 }
 
@@ -49,7 +56,33 @@ impl IPv6CIDR {
     /// ## Return Value
     /// Returns the newly created [`IPv6CIDR`]
     pub const unsafe fn new_unchecked(address: IPv6Address, prefix: u8) -> Self {
-        IPv6CIDR { address, prefix }
+        IPv6CIDR {
+            address: address.to_bits(),
+            prefix,
+        }
+    }
+
+    /// Parses a CIDR in "address/prefix" form in a const context
+    ///
+    /// [`FromStr`] can not be used to initialize a const item, so this parses the same form
+    /// without reporting why the text is invalid.
+    ///
+    /// ## Parameters
+    ///  * `s` - The text to parse
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`IPv6CIDR`], or [`None`] if `s` is not a valid IPv6 CIDR
+    pub const fn parse_const(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        let (slash, prefix) = match parse_prefix(bytes, 128) {
+            Some(prefix) => prefix,
+            None => return None,
+        };
+
+        match parse_ipv6(bytes, 0, slash) {
+            Some(address) => Some(unsafe { IPv6CIDR::new_unchecked(address, prefix) }),
+            None => None,
+        }
     }
 
     /// Gets the address of this CIDR
@@ -57,7 +90,7 @@ impl IPv6CIDR {
     /// ## Return Value
     /// Returns the address of this CIDR
     pub const fn address(&self) -> IPv6Address {
-        self.address
+        IPv6Address::from_bits(self.address)
     }
 
     /// Gets the subnet prefix length of this CIDR
@@ -68,12 +101,55 @@ impl IPv6CIDR {
         self.prefix
     }
 
+    /// Checks if `address` is in the network of this CIDR
+    ///
+    /// ## Parameters
+    ///  * `address` - The address to check
+    ///
+    /// ## Return Value
+    /// Returns true if the first `prefix` bits of `address` match this CIDR
+    pub const fn contains(&self, address: IPv6Address) -> bool {
+        (self.address ^ address.to_bits()) & mask(self.prefix) == 0
+    }
+
+    /// Gets the first address in the network of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this CIDR with every host bit cleared
+    pub const fn first_address(&self) -> IPv6Address {
+        IPv6Address::from_bits(self.address & mask(self.prefix))
+    }
+
+    /// Gets the last address in the network of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this CIDR with every host bit set
+    pub const fn last_address(&self) -> IPv6Address {
+        IPv6Address::from_bits(self.address | !mask(self.prefix))
+    }
+
+    /// Gets the netmask of this CIDR
+    ///
+    /// ## Return Value
+    /// Returns the mask with the first `prefix` bits set
+    pub const fn netmask(&self) -> IPv6Address {
+        IPv6Address::from_bits(mask(self.prefix))
+    }
+
+    /// Gets the wildcard mask of this CIDR, as used by access lists
+    ///
+    /// ## Return Value
+    /// Returns the mask with every bit after the first `prefix` bits set
+    pub const fn wildcard_mask(&self) -> IPv6Address {
+        IPv6Address::from_bits(!mask(self.prefix))
+    }
+
     /// Sets the address for this CIDR
     ///
     /// ## Parameters
     ///  * `address` - The new address
     pub fn set_address(&mut self, address: IPv6Address) {
-        self.address = address;
+        self.address = address.to_bits();
     }
 
     /// Sets the prefix for this CIDR
@@ -88,13 +164,13 @@ impl IPv6CIDR {
 
 impl Into<IPv6Address> for IPv6CIDR {
     fn into(self) -> IPv6Address {
-        self.address
+        self.address()
     }
 }
 
 impl Into<(IPv6Address, u8)> for IPv6CIDR {
     fn into(self) -> (IPv6Address, u8) {
-        (self.address, self.prefix)
+        (self.address(), self.prefix)
     }
 }
 
@@ -140,7 +216,7 @@ impl FromStr for IPv6CIDR {
 
 impl std::fmt::Display for IPv6CIDR {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}/{}", self.address, self.prefix)
+        pad(f, format_args!("{}/{}", self.address(), self.prefix))
     }
 }
 
@@ -173,3 +249,11 @@ impl PartialOrd<IPCIDR> for IPv6CIDR {
         }
     }
 }
+
+/// Gets the mask with the first `prefix` bits set
+const fn mask(prefix: u8) -> u128 {
+    match u128::MAX.checked_shl(128 - prefix as u32) {
+        Some(mask) => mask,
+        None => 0,
+    }
+}
@@ -0,0 +1,202 @@
+use crate::ip::InvalidIPv6HeaderError;
+
+/// The kind of an IPv6 extension header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExtensionHeaderKind {
+    /// Options examined by every node along the path
+    HopByHop,
+
+    /// A list of nodes to visit on the way to the destination
+    Routing,
+
+    /// The position of a fragment in the original packet
+    Fragment,
+
+    /// The authentication header from RFC 4302
+    Authentication,
+
+    /// Options examined only by the destination
+    DestinationOptions,
+}
+
+/// An IPv6 extension header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtensionHeader<'a> {
+    kind: ExtensionHeaderKind,
+    next_header: u8,
+    data: &'a [u8],
+}
+
+/// An iterator over the extension headers of an IPv6 packet
+///
+/// Iteration stops at the first header which is not an extension header, or after the first
+/// error.
+#[derive(Debug, Clone)]
+pub struct ExtensionHeaders<'a> {
+    next_header: u8,
+    remaining: &'a [u8],
+    failed: bool,
+}
+
+impl ExtensionHeaderKind {
+    /// Gets the kind of extension header with the protocol number `protocol`
+    ///
+    /// ## Parameters
+    ///  * `protocol` - The protocol number from a next header field
+    ///
+    /// ## Return Value
+    /// Returns the kind of extension header, or [`None`] if `protocol` is not an extension header
+    /// which can be walked
+    pub const fn from_protocol(protocol: u8) -> Option<Self> {
+        Some(match protocol {
+            0 => ExtensionHeaderKind::HopByHop,
+            43 => ExtensionHeaderKind::Routing,
+            44 => ExtensionHeaderKind::Fragment,
+            51 => ExtensionHeaderKind::Authentication,
+            60 => ExtensionHeaderKind::DestinationOptions,
+            _ => return None,
+        })
+    }
+
+    /// Gets the protocol number of this kind of extension header
+    ///
+    /// ## Return Value
+    /// Returns the value of the next header field which precedes this kind of header
+    pub const fn protocol(&self) -> u8 {
+        match self {
+            ExtensionHeaderKind::HopByHop => 0,
+            ExtensionHeaderKind::Routing => 43,
+            ExtensionHeaderKind::Fragment => 44,
+            ExtensionHeaderKind::Authentication => 51,
+            ExtensionHeaderKind::DestinationOptions => 60,
+        }
+    }
+}
+
+impl<'a> ExtensionHeader<'a> {
+    /// Gets the kind of this extension header
+    ///
+    /// ## Return Value
+    /// Returns the kind of this extension header
+    pub const fn kind(&self) -> ExtensionHeaderKind {
+        self.kind
+    }
+
+    /// Gets the protocol of the header following this one
+    ///
+    /// ## Return Value
+    /// Returns the protocol number of the next extension header or the upper-layer header
+    pub const fn next_header(&self) -> u8 {
+        self.next_header
+    }
+
+    /// Gets the bytes of this extension header
+    ///
+    /// ## Return Value
+    /// Returns the entire header, starting with the next header field
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Gets the offset of the fragment carried by a fragment header
+    ///
+    /// ## Return Value
+    /// Returns the offset in units of 8 bytes, or [`None`] if this is not a fragment header
+    pub const fn fragment_offset(&self) -> Option<u16> {
+        match self.kind {
+            ExtensionHeaderKind::Fragment => {
+                Some(u16::from_be_bytes([self.data[2], self.data[3]]) >> 3)
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets whether more fragments follow the one carried by a fragment header
+    ///
+    /// ## Return Value
+    /// Returns the more fragments flag, or [`None`] if this is not a fragment header
+    pub const fn more_fragments(&self) -> Option<bool> {
+        match self.kind {
+            ExtensionHeaderKind::Fragment => Some(self.data[3] & 1 != 0),
+            _ => None,
+        }
+    }
+
+    /// Gets the identification of the original packet from a fragment header
+    ///
+    /// ## Return Value
+    /// Returns the identification, or [`None`] if this is not a fragment header
+    pub const fn identification(&self) -> Option<u32> {
+        match self.kind {
+            ExtensionHeaderKind::Fragment => Some(u32::from_be_bytes([
+                self.data[4],
+                self.data[5],
+                self.data[6],
+                self.data[7],
+            ])),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> ExtensionHeaders<'a> {
+    /// Creates an iterator over the extension headers at the start of `payload`
+    ///
+    /// ## Parameters
+    ///  * `next_header` - The next header field of the fixed header
+    ///  * `payload` - The payload following the fixed header
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ExtensionHeaders`]
+    pub const fn new(next_header: u8, payload: &'a [u8]) -> Self {
+        ExtensionHeaders {
+            next_header,
+            remaining: payload,
+            failed: false,
+        }
+    }
+
+    /// Gets the header following the extension headers walked so far
+    ///
+    /// ## Return Value
+    /// Returns the protocol number of the next header and the data starting with it
+    pub const fn remainder(&self) -> (u8, &'a [u8]) {
+        (self.next_header, self.remaining)
+    }
+}
+
+impl<'a> Iterator for ExtensionHeaders<'a> {
+    type Item = Result<ExtensionHeader<'a>, InvalidIPv6HeaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let kind = ExtensionHeaderKind::from_protocol(self.next_header)?;
+
+        let length = match (kind, self.remaining.get(1)) {
+            (_, None) => None,
+            (ExtensionHeaderKind::Fragment, Some(_)) => Some(8),
+            (ExtensionHeaderKind::Authentication, Some(length)) => Some((*length as usize + 2) * 4),
+            (_, Some(length)) => Some((*length as usize + 1) * 8),
+        };
+
+        let data = match length.and_then(|length| self.remaining.get(..length)) {
+            Some(data) => data,
+            None => {
+                self.failed = true;
+                return Some(Err(InvalidIPv6HeaderError::Truncated));
+            }
+        };
+
+        self.next_header = data[0];
+        self.remaining = &self.remaining[data.len()..];
+
+        Some(Ok(ExtensionHeader {
+            kind,
+            next_header: data[0],
+            data,
+        }))
+    }
+}
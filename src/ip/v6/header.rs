@@ -0,0 +1,231 @@
+use super::{ExtensionHeaders, IPv6Address};
+use crate::ip::InvalidIPv6HeaderError;
+
+/// The fixed header of an IPv6 packet from RFC 8200
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IPv6Header {
+    traffic_class: u8,
+    flow_label: u32,
+    payload_length: u16,
+    next_header: u8,
+    hop_limit: u8,
+    source: IPv6Address,
+    destination: IPv6Address,
+}
+
+impl IPv6Header {
+    /// The length of the fixed header
+    pub const LENGTH: usize = 40;
+
+    /// The hop limit of a newly created header
+    pub const DEFAULT_HOP_LIMIT: u8 = 64;
+
+    /// Creates a new [`IPv6Header`]
+    ///
+    /// ## Parameters
+    ///  * `source` - The address the packet is sent from
+    ///  * `destination` - The address the packet is sent to
+    ///  * `next_header` - The protocol number of the header following this one
+    ///  * `payload_length` - The length of everything following this header
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`IPv6Header`]
+    pub const fn new(
+        source: IPv6Address,
+        destination: IPv6Address,
+        next_header: u8,
+        payload_length: u16,
+    ) -> Self {
+        IPv6Header {
+            traffic_class: 0,
+            flow_label: 0,
+            payload_length,
+            next_header,
+            hop_limit: IPv6Header::DEFAULT_HOP_LIMIT,
+            source,
+            destination,
+        }
+    }
+
+    /// Sets the traffic class
+    ///
+    /// ## Parameters
+    ///  * `traffic_class` - The traffic class, holding the DSCP and ECN bits
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv6Header`]
+    pub const fn with_traffic_class(mut self, traffic_class: u8) -> Self {
+        self.traffic_class = traffic_class;
+        self
+    }
+
+    /// Sets the flow label
+    ///
+    /// ## Parameters
+    ///  * `flow_label` - The flow label, of which only the low 20 bits are kept
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv6Header`]
+    pub const fn with_flow_label(mut self, flow_label: u32) -> Self {
+        self.flow_label = flow_label & 0xFFFFF;
+        self
+    }
+
+    /// Sets the hop limit
+    ///
+    /// ## Parameters
+    ///  * `hop_limit` - The number of hops the packet may take
+    ///
+    /// ## Return Value
+    /// Returns the modified [`IPv6Header`]
+    pub const fn with_hop_limit(mut self, hop_limit: u8) -> Self {
+        self.hop_limit = hop_limit;
+        self
+    }
+
+    /// Parses the header at the start of `packet`
+    ///
+    /// The payload is borrowed from `packet` and ends where the payload length says the packet
+    /// ends, so any link-layer padding is dropped.
+    ///
+    /// ## Parameters
+    ///  * `packet` - The packet, starting at the version
+    ///
+    /// ## Return Value
+    /// Returns the parsed header and the payload following it, starting with any extension
+    /// headers
+    pub fn parse(packet: &[u8]) -> Result<(Self, &[u8]), InvalidIPv6HeaderError> {
+        if packet.len() < IPv6Header::LENGTH {
+            return Err(InvalidIPv6HeaderError::Truncated);
+        }
+
+        let version = packet[0] >> 4;
+        if version != 6 {
+            return Err(InvalidIPv6HeaderError::InvalidVersion(version));
+        }
+
+        let payload_length = u16::from_be_bytes([packet[4], packet[5]]);
+        let payload = packet[IPv6Header::LENGTH..]
+            .get(..payload_length as usize)
+            .ok_or(InvalidIPv6HeaderError::Truncated)?;
+
+        let address = |start: usize| {
+            IPv6Address::from(<[u8; 16]>::try_from(&packet[start..start + 16]).unwrap())
+        };
+
+        let header = IPv6Header {
+            traffic_class: (packet[0] << 4) | (packet[1] >> 4),
+            flow_label: u32::from_be_bytes([0, packet[1] & 0x0F, packet[2], packet[3]]),
+            payload_length,
+            next_header: packet[6],
+            hop_limit: packet[7],
+            source: address(8),
+            destination: address(24),
+        };
+
+        Ok((header, payload))
+    }
+
+    /// Encodes this header
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded header to
+    pub fn write(&self, output: &mut Vec<u8>) {
+        output.reserve(IPv6Header::LENGTH);
+        output.extend_from_slice(
+            &((6 << 28) | ((self.traffic_class as u32) << 20) | self.flow_label).to_be_bytes(),
+        );
+        output.extend_from_slice(&self.payload_length.to_be_bytes());
+        output.push(self.next_header);
+        output.push(self.hop_limit);
+        output.extend_from_slice(&self.source.octets());
+        output.extend_from_slice(&self.destination.octets());
+    }
+
+    /// Walks the extension headers at the start of `payload`
+    ///
+    /// ## Parameters
+    ///  * `payload` - The payload following this header
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the extension headers
+    pub fn extension_headers<'a>(&self, payload: &'a [u8]) -> ExtensionHeaders<'a> {
+        ExtensionHeaders::new(self.next_header, payload)
+    }
+
+    /// Finds the upper-layer protocol by skipping the extension headers at the start of
+    /// `payload`
+    ///
+    /// ## Parameters
+    ///  * `payload` - The payload following this header
+    ///
+    /// ## Return Value
+    /// Returns the upper-layer protocol number and the data following the extension headers
+    pub fn upper_layer<'a>(
+        &self,
+        payload: &'a [u8],
+    ) -> Result<(u8, &'a [u8]), InvalidIPv6HeaderError> {
+        let mut headers = self.extension_headers(payload);
+        for header in &mut headers {
+            header?;
+        }
+
+        Ok(headers.remainder())
+    }
+
+    /// Gets the traffic class
+    ///
+    /// ## Return Value
+    /// Returns the traffic class, holding the DSCP and ECN bits
+    pub const fn traffic_class(&self) -> u8 {
+        self.traffic_class
+    }
+
+    /// Gets the flow label
+    ///
+    /// ## Return Value
+    /// Returns the flow label, from 0 to 0xFFFFF
+    pub const fn flow_label(&self) -> u32 {
+        self.flow_label
+    }
+
+    /// Gets the length of the payload
+    ///
+    /// ## Return Value
+    /// Returns the length of everything following this header, including extension headers
+    pub const fn payload_length(&self) -> u16 {
+        self.payload_length
+    }
+
+    /// Gets the protocol of the header following this one
+    ///
+    /// ## Return Value
+    /// Returns the protocol number of the first extension header or the upper-layer header
+    pub const fn next_header(&self) -> u8 {
+        self.next_header
+    }
+
+    /// Gets the hop limit
+    ///
+    /// ## Return Value
+    /// Returns the number of hops the packet may still take
+    pub const fn hop_limit(&self) -> u8 {
+        self.hop_limit
+    }
+
+    /// Gets the address the packet is sent from
+    ///
+    /// ## Return Value
+    /// Returns the source address
+    pub const fn source(&self) -> IPv6Address {
+        self.source
+    }
+
+    /// Gets the address the packet is sent to
+    ///
+    /// ## Return Value
+    /// Returns the destination address
+    pub const fn destination(&self) -> IPv6Address {
+        self.destination
+    }
+}
@@ -1,8 +1,33 @@
 //! IPv6 address utilities
 
+use crate::mac::{MACAddress, EUI64};
+
 mod cidr;
+mod extension;
+mod header;
+mod packed;
+mod zoned;
 
 pub use cidr::IPv6CIDR;
+pub use extension::{ExtensionHeader, ExtensionHeaderKind, ExtensionHeaders};
+pub use header::IPv6Header;
+pub use packed::{PackedIPv6CIDR, PackedIPv6Header};
+pub use zoned::{Zone, ZonedSocketAddress};
 
 pub use std::net::Ipv6Addr as IPv6Address;
 pub use std::net::SocketAddrV6 as IPv6SocketAddress;
+
+/// Gets the MAC address embedded in the interface identifier of `address`
+///
+/// Addresses from stateless autoconfiguration (SLAAC) without privacy extensions carry the MAC
+/// address of the interface as a modified EUI-64.
+///
+/// ## Parameters
+///  * `address` - The address to check
+///
+/// ## Return Value
+/// Returns the embedded MAC address, or [`None`] if the interface identifier is not derived from
+/// one
+pub const fn embedded_mac(address: IPv6Address) -> Option<MACAddress> {
+    EUI64::from_interface_id(address).to_mac()
+}
@@ -0,0 +1,267 @@
+use super::{IPv6Address, IPv6CIDR};
+use crate::ip::InvalidPrefixError;
+
+/// An [`IPv6CIDR`] with a fixed layout of the address octets followed by the prefix
+///
+/// Any 17 bytes form a packed CIDR, so the prefix is only checked when converting back into an
+/// [`IPv6CIDR`].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PackedIPv6CIDR {
+    address: [u8; 16],
+    prefix: u8,
+}
+
+/// The fixed header of an IPv6 packet with the layout it has on the wire
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PackedIPv6Header {
+    version_class_flow: [u8; 4],
+    payload_length: [u8; 2],
+    next_header: u8,
+    hop_limit: u8,
+    source: [u8; 16],
+    destination: [u8; 16],
+}
+
+impl PackedIPv6CIDR {
+    /// The length of a packed CIDR
+    pub const LENGTH: usize = 17;
+
+    /// Creates a [`PackedIPv6CIDR`] from its bytes
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The address octets followed by the prefix
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedIPv6CIDR`]
+    pub const fn from_bytes(bytes: [u8; 17]) -> Self {
+        let mut address = [0; 16];
+        let mut i = 0;
+        while i < 16 {
+            address[i] = bytes[i];
+            i += 1;
+        }
+
+        PackedIPv6CIDR {
+            address,
+            prefix: bytes[16],
+        }
+    }
+
+    /// Gets the bytes of this packed CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address octets followed by the prefix
+    pub const fn to_bytes(self) -> [u8; 17] {
+        let mut bytes = [0; 17];
+        let mut i = 0;
+        while i < 16 {
+            bytes[i] = self.address[i];
+            i += 1;
+        }
+        bytes[16] = self.prefix;
+        bytes
+    }
+
+    /// Gets the address of this packed CIDR
+    ///
+    /// ## Return Value
+    /// Returns the address of this packed CIDR
+    pub const fn address(&self) -> IPv6Address {
+        IPv6Address::from_bits(u128::from_be_bytes(self.address))
+    }
+
+    /// Gets the prefix of this packed CIDR
+    ///
+    /// ## Return Value
+    /// Returns the prefix, which may be greater than 128
+    pub const fn prefix(&self) -> u8 {
+        self.prefix
+    }
+}
+
+impl From<IPv6CIDR> for PackedIPv6CIDR {
+    fn from(cidr: IPv6CIDR) -> Self {
+        PackedIPv6CIDR {
+            address: cidr.address().octets(),
+            prefix: cidr.prefix(),
+        }
+    }
+}
+
+impl TryFrom<PackedIPv6CIDR> for IPv6CIDR {
+    type Error = InvalidPrefixError;
+
+    fn try_from(packed: PackedIPv6CIDR) -> Result<Self, Self::Error> {
+        IPv6CIDR::try_new(packed.address(), packed.prefix)
+    }
+}
+
+impl std::fmt::Debug for PackedIPv6CIDR {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address(), self.prefix)
+    }
+}
+
+impl PackedIPv6Header {
+    /// The length of a packed header
+    pub const LENGTH: usize = 40;
+
+    /// Creates a [`PackedIPv6Header`] from its bytes
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The first 40 bytes of the packet
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedIPv6Header`]
+    pub const fn from_bytes(bytes: [u8; 40]) -> Self {
+        let mut source = [0; 16];
+        let mut destination = [0; 16];
+        let mut i = 0;
+        while i < 16 {
+            source[i] = bytes[8 + i];
+            destination[i] = bytes[24 + i];
+            i += 1;
+        }
+
+        PackedIPv6Header {
+            version_class_flow: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            payload_length: [bytes[4], bytes[5]],
+            next_header: bytes[6],
+            hop_limit: bytes[7],
+            source,
+            destination,
+        }
+    }
+
+    /// Gets the bytes of this packed header
+    ///
+    /// ## Return Value
+    /// Returns the 40 bytes of the header as they appear on the wire
+    pub const fn to_bytes(self) -> [u8; 40] {
+        let mut bytes = [0; 40];
+        bytes[0] = self.version_class_flow[0];
+        bytes[1] = self.version_class_flow[1];
+        bytes[2] = self.version_class_flow[2];
+        bytes[3] = self.version_class_flow[3];
+        bytes[4] = self.payload_length[0];
+        bytes[5] = self.payload_length[1];
+        bytes[6] = self.next_header;
+        bytes[7] = self.hop_limit;
+        let mut i = 0;
+        while i < 16 {
+            bytes[8 + i] = self.source[i];
+            bytes[24 + i] = self.destination[i];
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Gets the IP version
+    ///
+    /// ## Return Value
+    /// Returns the version, which is 6 for a valid header
+    pub const fn version(&self) -> u8 {
+        self.version_class_flow[0] >> 4
+    }
+
+    /// Gets the traffic class
+    ///
+    /// ## Return Value
+    /// Returns the traffic class
+    pub const fn traffic_class(&self) -> u8 {
+        (u32::from_be_bytes(self.version_class_flow) >> 20) as u8
+    }
+
+    /// Gets the flow label
+    ///
+    /// ## Return Value
+    /// Returns the 20-bit flow label
+    pub const fn flow_label(&self) -> u32 {
+        u32::from_be_bytes(self.version_class_flow) & 0xFFFFF
+    }
+
+    /// Gets the payload length
+    ///
+    /// ## Return Value
+    /// Returns the length of the extension headers and payload in bytes
+    pub const fn payload_length(&self) -> u16 {
+        u16::from_be_bytes(self.payload_length)
+    }
+
+    /// Gets the protocol number of the header following this one
+    ///
+    /// ## Return Value
+    /// Returns the protocol number of the next header
+    pub const fn next_header(&self) -> u8 {
+        self.next_header
+    }
+
+    /// Gets the hop limit
+    ///
+    /// ## Return Value
+    /// Returns the hop limit
+    pub const fn hop_limit(&self) -> u8 {
+        self.hop_limit
+    }
+
+    /// Gets the source address
+    ///
+    /// ## Return Value
+    /// Returns the address the packet is sent from
+    pub const fn source(&self) -> IPv6Address {
+        IPv6Address::from_bits(u128::from_be_bytes(self.source))
+    }
+
+    /// Gets the destination address
+    ///
+    /// ## Return Value
+    /// Returns the address the packet is sent to
+    pub const fn destination(&self) -> IPv6Address {
+        IPv6Address::from_bits(u128::from_be_bytes(self.destination))
+    }
+}
+
+impl std::fmt::Debug for PackedIPv6Header {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackedIPv6Header")
+            .field("version", &self.version())
+            .field("traffic_class", &self.traffic_class())
+            .field("flow_label", &self.flow_label())
+            .field("payload_length", &self.payload_length())
+            .field("next_header", &self.next_header)
+            .field("hop_limit", &self.hop_limit)
+            .field("source", &self.source())
+            .field("destination", &self.destination())
+            .finish()
+    }
+}
@@ -0,0 +1,191 @@
+use super::{IPv6Address, IPv6SocketAddress};
+use crate::ip::InvalidZonedAddressError;
+use std::str::FromStr;
+
+/// The zone of a link-local IPv6 address, identifying the interface it belongs to
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Zone {
+    /// A numeric interface index, used directly as the scope id
+    Index(u32),
+
+    /// An interface name, such as "eth0"
+    Name(String),
+}
+
+/// An IPv6 socket address which may carry a zone, such as "[fe80::1%eth0]:8080"
+///
+/// The zone may be written as an interface index or an interface name. Before an interface name,
+/// the '%' may be percent-encoded as "%25" as it is in URIs. Interface names are kept as written
+/// and are only turned into scope ids when converting to an [`IPv6SocketAddress`].
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZonedSocketAddress {
+    address: IPv6Address,
+    zone: Option<Zone>,
+    port: u16,
+}
+
+impl Zone {
+    /// Gets the scope id of this zone
+    ///
+    /// Interface names are only resolved when the `os` feature is enabled on a supported platform.
+    ///
+    /// ## Return Value
+    /// Returns the interface index, or [`None`] if the interface name could not be resolved
+    pub fn scope_id(&self) -> Option<u32> {
+        match self {
+            Zone::Index(index) => Some(*index),
+            #[cfg(feature = "os")]
+            Zone::Name(name) => crate::os::interface_index(name),
+            #[cfg(not(feature = "os"))]
+            Zone::Name(_) => None,
+        }
+    }
+}
+
+impl From<u32> for Zone {
+    fn from(index: u32) -> Self {
+        Zone::Index(index)
+    }
+}
+
+impl FromStr for Zone {
+    type Err = InvalidZonedAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(InvalidZonedAddressError::EmptyZone);
+        }
+
+        Ok(match s.parse() {
+            Ok(index) => Zone::Index(index),
+            Err(_) => Zone::Name(s.to_owned()),
+        })
+    }
+}
+
+impl std::fmt::Display for Zone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Zone::Index(index) => index.fmt(f),
+            Zone::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+impl ZonedSocketAddress {
+    /// Creates a new [`ZonedSocketAddress`]
+    ///
+    /// ## Parameters
+    ///  * `address` - The IPv6 address
+    ///  * `zone` - The zone of the address, if it has one
+    ///  * `port` - The port
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`ZonedSocketAddress`]
+    pub const fn new(address: IPv6Address, zone: Option<Zone>, port: u16) -> Self {
+        ZonedSocketAddress {
+            address,
+            zone,
+            port,
+        }
+    }
+
+    /// Gets the address of this socket address
+    ///
+    /// ## Return Value
+    /// Returns the IPv6 address without its zone
+    pub const fn address(&self) -> IPv6Address {
+        self.address
+    }
+
+    /// Gets the zone of this socket address
+    ///
+    /// ## Return Value
+    /// Returns the zone, if one was given
+    pub const fn zone(&self) -> Option<&Zone> {
+        self.zone.as_ref()
+    }
+
+    /// Gets the port of this socket address
+    ///
+    /// ## Return Value
+    /// Returns the port of this socket address
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Converts this address into an [`IPv6SocketAddress`], resolving the zone to a scope id
+    ///
+    /// ## Return Value
+    /// Returns the socket address, with a scope id of 0 if there is no zone, or [`None`] if the
+    /// zone is an interface name which could not be resolved
+    pub fn to_socket_addr(&self) -> Option<IPv6SocketAddress> {
+        let scope_id = match &self.zone {
+            Some(zone) => zone.scope_id()?,
+            None => 0,
+        };
+
+        Some(IPv6SocketAddress::new(self.address, self.port, 0, scope_id))
+    }
+}
+
+impl From<IPv6SocketAddress> for ZonedSocketAddress {
+    fn from(address: IPv6SocketAddress) -> Self {
+        let zone = match address.scope_id() {
+            0 => None,
+            index => Some(Zone::Index(index)),
+        };
+
+        ZonedSocketAddress::new(*address.ip(), zone, address.port())
+    }
+}
+
+impl FromStr for ZonedSocketAddress {
+    type Err = InvalidZonedAddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (host, port) = s
+            .strip_prefix('[')
+            .and_then(|s| s.split_once(']'))
+            .ok_or(InvalidZonedAddressError::MissingBrackets)?;
+
+        let port = port
+            .strip_prefix(':')
+            .ok_or(InvalidZonedAddressError::MissingPort)?
+            .parse()
+            .map_err(InvalidZonedAddressError::InvalidPort)?;
+
+        let (address, zone) = match host.split_once('%') {
+            Some((address, zone)) => {
+                let zone = zone
+                    .strip_prefix("25")
+                    .filter(|name| name.starts_with(|c: char| !c.is_ascii_digit()))
+                    .unwrap_or(zone);
+                (address, Some(zone.parse()?))
+            }
+            None => (host, None),
+        };
+
+        let address = address
+            .parse()
+            .map_err(InvalidZonedAddressError::InvalidAddress)?;
+
+        Ok(ZonedSocketAddress::new(address, zone, port))
+    }
+}
+
+impl std::fmt::Display for ZonedSocketAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}", self.address)?;
+        if let Some(zone) = &self.zone {
+            write!(f, "%{}", zone)?;
+        }
+        write!(f, "]:{}", self.port)
+    }
+}
+
+impl std::fmt::Debug for ZonedSocketAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,53 @@
+use super::IPAddress;
+
+/// A version of the Internet Protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IPVersion {
+    /// Internet Protocol version 4
+    IPv4,
+
+    /// Internet Protocol version 6
+    IPv6,
+}
+
+impl IPVersion {
+    /// Gets the length of a header of this version without options or extension headers
+    ///
+    /// ## Return Value
+    /// Returns 20 for IPv4 or 40 for IPv6
+    pub const fn min_header_len(&self) -> u16 {
+        match self {
+            IPVersion::IPv4 => 20,
+            IPVersion::IPv6 => 40,
+        }
+    }
+
+    /// Gets the smallest MTU every link carrying this version must support
+    ///
+    /// ## Return Value
+    /// Returns 68 for IPv4 from RFC 791 or 1280 for IPv6 from RFC 8200
+    pub const fn min_mtu(&self) -> u16 {
+        match self {
+            IPVersion::IPv4 => 68,
+            IPVersion::IPv6 => 1280,
+        }
+    }
+}
+
+impl From<IPAddress> for IPVersion {
+    fn from(address: IPAddress) -> Self {
+        match address {
+            IPAddress::V4(_) => IPVersion::IPv4,
+            IPAddress::V6(_) => IPVersion::IPv6,
+        }
+    }
+}
+
+impl std::fmt::Display for IPVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            IPVersion::IPv4 => "IPv4",
+            IPVersion::IPv6 => "IPv6",
+        })
+    }
+}
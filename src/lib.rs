@@ -3,8 +3,37 @@
 //! A library with common utilities for networking programs
 
 #![deny(missing_docs)]
-#![feature(const_trait_impl)]
 
+pub mod acl;
+pub mod arp;
+pub mod bgp;
+pub mod checksum;
+pub mod dns;
+pub mod endpoint;
+pub mod ethernet;
+pub mod hexdump;
+pub mod host;
+pub mod icmp;
+#[cfg(feature = "os")]
+pub mod interface;
 pub mod ip;
 pub mod mac;
+pub mod mtu;
+pub mod port;
+pub mod punycode;
+pub mod rate;
+pub mod transport;
+pub mod tunnel;
 pub mod uri;
+
+mod error;
+mod padding;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "os")]
+mod os;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use error::{Error, ErrorKind};
@@ -1,15 +1,50 @@
 //! MAC address utilites
 
+use crate::{padding::pad, ErrorKind};
 use std::str::FromStr;
 
+mod eui64;
+mod oui;
+
+#[cfg(feature = "defmt")]
+mod format;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(any(feature = "postgres", feature = "sqlx"))]
+mod sql;
+#[cfg(feature = "proptest")]
+mod strategy;
+
+pub use eui64::EUI64;
+pub use oui::{InvalidOUIRegistryError, OUIAssignment, OUIRegistry};
+
 /// A media access control (MAC) address
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct MACAddress {
     octets: [u8; 6],
 }
 
 /// An error occured while parsing a MAC address
+#[derive(Clone, PartialEq, Eq)]
 pub struct InvalidMACAddress;
 
 impl MACAddress {
@@ -45,6 +80,46 @@ impl MACAddress {
     pub const fn as_slice(&self) -> &[u8] {
         &self.octets
     }
+
+    /// Parses a MAC address in "XX:XX:XX:XX:XX:XX" form in a const context
+    ///
+    /// [`FromStr`] can not be used to initialize a const item, so this parses the same form
+    /// without reporting why the text is invalid.
+    ///
+    /// ## Parameters
+    ///  * `s` - The text to parse
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`MACAddress`], or [`None`] if `s` is not a valid MAC address
+    pub const fn parse_const(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 17 {
+            return None;
+        }
+
+        let mut octets = [0; 6];
+        let mut i = 0;
+        while i < 6 {
+            let high = match hex_digit(bytes[i * 3]) {
+                Some(digit) => digit,
+                None => return None,
+            };
+
+            let low = match hex_digit(bytes[i * 3 + 1]) {
+                Some(digit) => digit,
+                None => return None,
+            };
+
+            if i < 5 && bytes[i * 3 + 2] != b':' {
+                return None;
+            }
+
+            octets[i] = (high << 4) | low;
+            i += 1;
+        }
+
+        Some(MACAddress::new(octets))
+    }
 }
 
 impl From<[u8; 6]> for MACAddress {
@@ -57,49 +132,23 @@ impl FromStr for MACAddress {
     type Err = InvalidMACAddress;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.chars();
-
-        let mut octets = [0; 6];
-        for i in 0..6 {
-            // Parse MAC segment
-            let c = chars.next().ok_or(InvalidMACAddress)?;
-            let mut octet = c.to_digit(16).ok_or(InvalidMACAddress)? as u8;
-
-            octet <<= 4;
-
-            let c = chars.next().ok_or(InvalidMACAddress)?;
-            octet |= c.to_digit(16).ok_or(InvalidMACAddress)? as u8;
-
-            octets[i] = octet;
-
-            if i < 5 {
-                // Parse colon
-                let c = chars.next().ok_or(InvalidMACAddress)?;
-                if c != ':' {
-                    return Err(InvalidMACAddress);
-                }
-            }
-        }
-
-        if chars.next().is_some() {
-            Err(InvalidMACAddress)
-        } else {
-            Ok(MACAddress::new(octets))
-        }
+        MACAddress::parse_const(s).ok_or(InvalidMACAddress)
     }
 }
 
 impl std::fmt::Display for MACAddress {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
+        pad(
             f,
-            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-            self.octets[0],
-            self.octets[1],
-            self.octets[2],
-            self.octets[3],
-            self.octets[4],
-            self.octets[5],
+            format_args!(
+                "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+                self.octets[0],
+                self.octets[1],
+                self.octets[2],
+                self.octets[3],
+                self.octets[4],
+                self.octets[5],
+            ),
         )
     }
 }
@@ -110,6 +159,16 @@ impl std::fmt::Debug for MACAddress {
     }
 }
 
+impl InvalidMACAddress {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        ErrorKind::InvalidFormat
+    }
+}
+
 impl std::error::Error for InvalidMACAddress {}
 
 impl std::fmt::Display for InvalidMACAddress {
@@ -123,3 +182,13 @@ impl std::fmt::Debug for InvalidMACAddress {
         std::fmt::Display::fmt(self, f)
     }
 }
+
+/// Gets the value of an ASCII hexadecimal digit
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{ip::v6::IPv6Address, mac::MACAddress, padding::pad};
+
+/// The universal/local bit, which the modified EUI-64 of an IPv6 interface identifier inverts
+const UNIVERSAL_LOCAL: u8 = 0x02;
+
+/// A 64-bit extended unique identifier (EUI-64)
+///
+/// A MAC address becomes an EUI-64 by inserting FF-FE between its third and fourth octets. IPv6
+/// interface identifiers from RFC 4291 carry the modified form, with the universal/local bit
+/// inverted, which [`EUI64::from_interface_id`] and [`EUI64::to_ipv6_address`] convert.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EUI64 {
+    octets: [u8; 8],
+}
+
+impl EUI64 {
+    /// Creates a new [`EUI64`]
+    ///
+    /// ## Parameters
+    ///  * `octets` - The octets which make up the identifier
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`EUI64`]
+    pub const fn new(octets: [u8; 8]) -> Self {
+        EUI64 { octets }
+    }
+
+    /// Creates the [`EUI64`] of a MAC address
+    ///
+    /// ## Parameters
+    ///  * `mac` - The address to extend
+    ///
+    /// ## Return Value
+    /// Returns `mac` with FF-FE inserted in the middle
+    pub const fn from_mac(mac: MACAddress) -> Self {
+        let mac = mac.octets();
+        EUI64::new([mac[0], mac[1], mac[2], 0xFF, 0xFE, mac[3], mac[4], mac[5]])
+    }
+
+    /// Gets the [`EUI64`] from the interface identifier of an IPv6 address
+    ///
+    /// ## Parameters
+    ///  * `address` - The address whose last 64 bits are a modified EUI-64
+    ///
+    /// ## Return Value
+    /// Returns the last 64 bits of `address` with the universal/local bit restored
+    pub const fn from_interface_id(address: IPv6Address) -> Self {
+        let address = address.octets();
+        EUI64::new([
+            address[8] ^ UNIVERSAL_LOCAL,
+            address[9],
+            address[10],
+            address[11],
+            address[12],
+            address[13],
+            address[14],
+            address[15],
+        ])
+    }
+
+    /// Gets the octets of this identifier
+    ///
+    /// ## Return Value
+    /// Returns the 8 octets that make up this identifier
+    pub const fn octets(&self) -> [u8; 8] {
+        self.octets
+    }
+
+    /// Gets the MAC address this identifier was created from
+    ///
+    /// ## Return Value
+    /// Returns the MAC address, or [`None`] if the middle octets are not FF-FE
+    pub const fn to_mac(&self) -> Option<MACAddress> {
+        let octets = self.octets;
+        if octets[3] != 0xFF || octets[4] != 0xFE {
+            return None;
+        }
+
+        Some(MACAddress::new([
+            octets[0], octets[1], octets[2], octets[5], octets[6], octets[7],
+        ]))
+    }
+
+    /// Creates an IPv6 address with this identifier as its interface identifier
+    ///
+    /// ## Parameters
+    ///  * `network` - The address whose first 64 bits are kept
+    ///
+    /// ## Return Value
+    /// Returns the first 64 bits of `network` followed by the modified form of this identifier
+    pub const fn to_ipv6_address(&self, network: IPv6Address) -> IPv6Address {
+        let mut address = network.octets();
+        let mut i = 0;
+        while i < 8 {
+            address[8 + i] = self.octets[i];
+            i += 1;
+        }
+        address[8] ^= UNIVERSAL_LOCAL;
+
+        IPv6Address::from_bits(u128::from_be_bytes(address))
+    }
+}
+
+impl From<MACAddress> for EUI64 {
+    fn from(mac: MACAddress) -> Self {
+        EUI64::from_mac(mac)
+    }
+}
+
+impl std::fmt::Display for EUI64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let o = self.octets;
+        pad(
+            f,
+            format_args!(
+                "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+                o[0], o[1], o[2], o[3], o[4], o[5], o[6], o[7]
+            ),
+        )
+    }
+}
+
+impl std::fmt::Debug for EUI64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
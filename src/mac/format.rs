@@ -0,0 +1,18 @@
+use super::MACAddress;
+use defmt::{Format, Formatter};
+
+impl Format for MACAddress {
+    fn format(&self, fmt: Formatter) {
+        let [a, b, c, d, e, f] = self.octets;
+        defmt::write!(
+            fmt,
+            "{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}",
+            a,
+            b,
+            c,
+            d,
+            e,
+            f
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::mac::MACAddress;
+
+/// A block of MAC addresses assigned to an organization by the IEEE
+///
+/// Blocks come from the MA-L (24-bit), MA-M (28-bit), and MA-S (36-bit) registries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OUIAssignment {
+    value: u64,
+    length: u8,
+    organization: String,
+    address: String,
+}
+
+impl OUIAssignment {
+    /// Creates a new [`OUIAssignment`]
+    ///
+    /// ## Parameters
+    ///  * `prefix` - The first address of the block, bits after `length` are ignored
+    ///  * `length` - The number of bits assigned, must be 24, 28, or 36
+    ///  * `organization` - The name of the organization holding the block
+    ///  * `address` - The postal address of the organization
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`OUIAssignment`]
+    pub fn new(
+        prefix: MACAddress,
+        length: u8,
+        organization: impl Into<String>,
+        address: impl Into<String>,
+    ) -> Self {
+        assert!(matches!(length, 24 | 28 | 36));
+
+        OUIAssignment {
+            value: to_bits(prefix) & mask(length),
+            length,
+            organization: organization.into(),
+            address: address.into(),
+        }
+    }
+
+    /// Gets the first address of this block
+    ///
+    /// ## Return Value
+    /// Returns the assigned prefix with the remaining bits cleared
+    pub fn prefix(&self) -> MACAddress {
+        from_bits(self.value)
+    }
+
+    /// Gets the number of bits assigned
+    ///
+    /// ## Return Value
+    /// Returns 24 for MA-L, 28 for MA-M, or 36 for MA-S
+    pub const fn length(&self) -> u8 {
+        self.length
+    }
+
+    /// Gets the name of the organization holding this block
+    ///
+    /// ## Return Value
+    /// Returns the organization name as written in the registry
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+
+    /// Gets the postal address of the organization holding this block
+    ///
+    /// ## Return Value
+    /// Returns the address on one line, which is empty for private assignments
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Checks if `mac` is in this block
+    ///
+    /// ## Parameters
+    ///  * `mac` - The address to check
+    ///
+    /// ## Return Value
+    /// Returns true if the first [`OUIAssignment::length`] bits of `mac` match this block
+    pub fn contains(&self, mac: MACAddress) -> bool {
+        to_bits(mac) & mask(self.length) == self.value
+    }
+
+    /// Gets the key of this block in a registry
+    pub(super) const fn key(&self) -> (u64, u8) {
+        (self.value, self.length)
+    }
+}
+
+/// Gets the 48 bits of `mac` as an integer
+pub(super) fn to_bits(mac: MACAddress) -> u64 {
+    let octets = mac.octets();
+    u64::from_be_bytes([
+        0, 0, octets[0], octets[1], octets[2], octets[3], octets[4], octets[5],
+    ])
+}
+
+/// Gets the MAC address made of the low 48 bits of `bits`
+pub(super) fn from_bits(bits: u64) -> MACAddress {
+    let bytes = bits.to_be_bytes();
+    MACAddress::new([bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+}
+
+/// Gets the mask selecting the first `length` bits of a 48-bit address
+pub(super) const fn mask(length: u8) -> u64 {
+    (u64::MAX << (48 - length as u32)) & 0xFFFF_FFFF_FFFF
+}
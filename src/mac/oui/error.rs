@@ -0,0 +1,65 @@
+use crate::ErrorKind;
+
+/// An error while parsing an IEEE registry file
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidOUIRegistryError {
+    /// A record on the line, starting from 1, does not have an assignment and organization
+    MissingField(usize),
+
+    /// The assignment on the line, starting from 1, is not a 24, 28, or 36-bit block
+    InvalidAssignment(usize),
+
+    /// A quoted field on the line, starting from 1, is not closed
+    UnclosedQuote(usize),
+}
+
+impl InvalidOUIRegistryError {
+    /// Gets the line the error was found on
+    ///
+    /// ## Return Value
+    /// Returns the line number, starting from 1
+    pub const fn line(&self) -> usize {
+        match self {
+            InvalidOUIRegistryError::MissingField(line)
+            | InvalidOUIRegistryError::InvalidAssignment(line)
+            | InvalidOUIRegistryError::UnclosedQuote(line) => *line,
+        }
+    }
+
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidOUIRegistryError::MissingField(_) => ErrorKind::Missing,
+            InvalidOUIRegistryError::InvalidAssignment(_)
+            | InvalidOUIRegistryError::UnclosedQuote(_) => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidOUIRegistryError {}
+
+impl std::fmt::Display for InvalidOUIRegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidOUIRegistryError::MissingField(line) => {
+                write!(f, "missing field on line {}", line)
+            }
+            InvalidOUIRegistryError::InvalidAssignment(line) => {
+                write!(f, "invalid assignment on line {}", line)
+            }
+            InvalidOUIRegistryError::UnclosedQuote(line) => {
+                write!(f, "unclosed quote on line {}", line)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidOUIRegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
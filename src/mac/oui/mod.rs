@@ -0,0 +1,137 @@
+use crate::mac::MACAddress;
+use assignment::{mask, to_bits};
+use std::collections::BTreeMap;
+
+mod assignment;
+mod error;
+mod parse;
+
+pub use assignment::OUIAssignment;
+pub use error::InvalidOUIRegistryError;
+
+/// The IEEE MAC address block registries, loaded at runtime
+///
+/// Registries are loaded from the files the IEEE publishes, so fresh data can be used without
+/// rebuilding. The MA-L ("oui"), MA-M ("mam"), and MA-S ("oui36") files can all be added to one
+/// registry, with lookups finding the most specific block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OUIRegistry {
+    assignments: BTreeMap<(u64, u8), OUIAssignment>,
+}
+
+impl OUIRegistry {
+    /// Creates a new, empty [`OUIRegistry`]
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`OUIRegistry`]
+    pub const fn new() -> Self {
+        OUIRegistry {
+            assignments: BTreeMap::new(),
+        }
+    }
+
+    /// Creates an [`OUIRegistry`] from a registry in CSV form, such as "oui.csv"
+    ///
+    /// ## Parameters
+    ///  * `csv` - The contents of the file
+    ///
+    /// ## Return Value
+    /// Returns the loaded [`OUIRegistry`], or the first line which could not be parsed
+    pub fn from_csv(csv: &str) -> Result<Self, InvalidOUIRegistryError> {
+        let mut registry = OUIRegistry::new();
+        registry.add_csv(csv)?;
+        Ok(registry)
+    }
+
+    /// Creates an [`OUIRegistry`] from a registry in text form, such as "oui.txt"
+    ///
+    /// ## Parameters
+    ///  * `text` - The contents of the file
+    ///
+    /// ## Return Value
+    /// Returns the loaded [`OUIRegistry`], or the first line which could not be parsed
+    pub fn from_txt(text: &str) -> Result<Self, InvalidOUIRegistryError> {
+        let mut registry = OUIRegistry::new();
+        registry.add_txt(text)?;
+        Ok(registry)
+    }
+
+    /// Adds the assignments from a registry in CSV form
+    ///
+    /// Assignments read before an error are kept.
+    ///
+    /// ## Parameters
+    ///  * `csv` - The contents of the file
+    ///
+    /// ## Return Value
+    /// Returns the first line which could not be parsed, if any
+    pub fn add_csv(&mut self, csv: &str) -> Result<(), InvalidOUIRegistryError> {
+        parse::parse_csv(csv, |assignment| {
+            self.insert(assignment);
+        })
+    }
+
+    /// Adds the assignments from a registry in text form
+    ///
+    /// Assignments read before an error are kept.
+    ///
+    /// ## Parameters
+    ///  * `text` - The contents of the file
+    ///
+    /// ## Return Value
+    /// Returns the first line which could not be parsed, if any
+    pub fn add_txt(&mut self, text: &str) -> Result<(), InvalidOUIRegistryError> {
+        parse::parse_txt(text, |assignment| {
+            self.insert(assignment);
+        })
+    }
+
+    /// Adds an assignment to this registry
+    ///
+    /// ## Parameters
+    ///  * `assignment` - The assignment to add
+    ///
+    /// ## Return Value
+    /// Returns the assignment of the same block which was replaced, if any
+    pub fn insert(&mut self, assignment: OUIAssignment) -> Option<OUIAssignment> {
+        self.assignments.insert(assignment.key(), assignment)
+    }
+
+    /// Finds the assignment holding `mac`
+    ///
+    /// ## Parameters
+    ///  * `mac` - The address to look up
+    ///
+    /// ## Return Value
+    /// Returns the most specific assignment holding `mac`, if any
+    pub fn lookup(&self, mac: MACAddress) -> Option<&OUIAssignment> {
+        let bits = to_bits(mac);
+        [36, 28, 24]
+            .into_iter()
+            .find_map(|length| self.assignments.get(&(bits & mask(length), length)))
+    }
+
+    /// Gets the number of assignments in this registry
+    ///
+    /// ## Return Value
+    /// Returns the number of assignments
+    pub fn len(&self) -> usize {
+        self.assignments.len()
+    }
+
+    /// Checks if this registry has no assignments
+    ///
+    /// ## Return Value
+    /// Returns true if this registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.assignments.is_empty()
+    }
+
+    /// Gets the assignments in this registry
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the assignments, ordered by their first address
+    pub fn iter(&self) -> impl Iterator<Item = &OUIAssignment> + '_ {
+        self.assignments.values()
+    }
+}
@@ -0,0 +1,193 @@
+//! Parsers for the CSV and text forms of the IEEE registries
+
+use super::{assignment::from_bits, InvalidOUIRegistryError, OUIAssignment};
+use crate::mac::MACAddress;
+
+/// Parses the records of an IEEE registry in CSV form, such as "oui.csv"
+///
+/// Each record is "Registry,Assignment,Organization Name,Organization Address", with fields
+/// containing commas wrapped in quotes. A header line is skipped.
+///
+/// ## Parameters
+///  * `csv` - The contents of the file
+///  * `insert` - Called with each assignment in the file
+pub(super) fn parse_csv(
+    csv: &str,
+    mut insert: impl FnMut(OUIAssignment),
+) -> Result<(), InvalidOUIRegistryError> {
+    for (index, line) in csv.lines().enumerate() {
+        let number = index + 1;
+        if line.trim().is_empty() || line.starts_with("Registry,") {
+            continue;
+        }
+
+        let fields = split_csv(line).ok_or(InvalidOUIRegistryError::UnclosedQuote(number))?;
+        if fields.len() < 3 {
+            return Err(InvalidOUIRegistryError::MissingField(number));
+        }
+
+        let (prefix, length) = parse_assignment(fields[1].trim())
+            .ok_or(InvalidOUIRegistryError::InvalidAssignment(number))?;
+        let address = fields.get(3).map(|address| address.trim()).unwrap_or("");
+
+        insert(OUIAssignment::new(
+            prefix,
+            length,
+            fields[2].trim(),
+            address,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parses the records of an IEEE registry in text form, such as "oui.txt"
+///
+/// Each record has a "(hex)" line, a "(base 16)" line with the organization, and indented lines
+/// with its address. MA-M and MA-S records give the block as a range on the "(base 16)" line.
+///
+/// ## Parameters
+///  * `text` - The contents of the file
+///  * `insert` - Called with each assignment in the file
+pub(super) fn parse_txt(
+    text: &str,
+    mut insert: impl FnMut(OUIAssignment),
+) -> Result<(), InvalidOUIRegistryError> {
+    // The 24 bits from the last "(hex)" line, which MA-M and MA-S ranges extend
+    let mut hex = None;
+
+    let mut lines = text.lines().enumerate().peekable();
+    while let Some((index, line)) = lines.next() {
+        let number = index + 1;
+
+        if let Some((assignment, _)) = line.split_once("(hex)") {
+            let (prefix, _) = parse_assignment(&assignment.trim().replace('-', ""))
+                .filter(|(_, length)| *length == 24)
+                .ok_or(InvalidOUIRegistryError::InvalidAssignment(number))?;
+            hex = Some(prefix);
+            continue;
+        }
+
+        let Some((assignment, organization)) = line.split_once("(base 16)") else {
+            continue;
+        };
+
+        let assignment = assignment.trim();
+        let (prefix, length) = match assignment.split_once('-') {
+            Some((start, end)) => hex.and_then(|hex| parse_range(hex, start, end)),
+            None => parse_assignment(assignment).filter(|(_, length)| *length == 24),
+        }
+        .ok_or(InvalidOUIRegistryError::InvalidAssignment(number))?;
+
+        // The address is on the indented lines which follow
+        let mut address = Vec::new();
+        while let Some((_, line)) = lines
+            .next_if(|(_, line)| line.starts_with(char::is_whitespace) && !line.trim().is_empty())
+        {
+            address.push(line.trim());
+        }
+
+        insert(OUIAssignment::new(
+            prefix,
+            length,
+            organization.trim(),
+            address.join(" "),
+        ));
+        hex = None;
+    }
+
+    Ok(())
+}
+
+/// Splits a CSV line into its fields, removing quotes
+///
+/// ## Return Value
+/// Returns the fields, or [`None`] if a quoted field is not closed
+fn split_csv(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+
+    if quoted {
+        return None;
+    }
+
+    fields.push(field);
+    Some(fields)
+}
+
+/// Parses an assignment of 6, 7, or 9 hexadecimal digits
+///
+/// ## Return Value
+/// Returns the first address of the block and the number of bits assigned
+fn parse_assignment(assignment: &str) -> Option<(MACAddress, u8)> {
+    let length = match assignment.len() {
+        6 => 24,
+        7 => 28,
+        9 => 36,
+        _ => return None,
+    };
+
+    if !assignment.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let value = u64::from_str_radix(assignment, 16).ok()?;
+    Some((from_bits(value << (48 - length as u32)), length))
+}
+
+/// Parses a range such as "C00000-CFFFFF" which extends the 24 bits from a "(hex)" line
+///
+/// ## Return Value
+/// Returns the first address of the block and the number of bits assigned
+fn parse_range(hex: MACAddress, start: &str, end: &str) -> Option<(MACAddress, u8)> {
+    let (start, end) = (start.trim(), end.trim());
+    if start.len() != 6
+        || end.len() != 6
+        || !start
+            .bytes()
+            .chain(end.bytes())
+            .all(|c| c.is_ascii_hexdigit())
+    {
+        return None;
+    }
+
+    // The fixed digits are shared, then the start runs through zeros and the end through Fs
+    let fixed = start
+        .bytes()
+        .zip(end.bytes())
+        .take_while(|(start, end)| start.eq_ignore_ascii_case(end))
+        .count();
+    if !start[fixed..].bytes().all(|c| c == b'0')
+        || !end[fixed..].bytes().all(|c| c.eq_ignore_ascii_case(&b'F'))
+    {
+        return None;
+    }
+
+    let length = 24 + fixed as u8 * 4;
+    if length != 28 && length != 36 {
+        return None;
+    }
+
+    let start = u32::from_str_radix(start, 16).ok()?.to_be_bytes();
+    let octets = hex.octets();
+    Some((
+        MACAddress::new([
+            octets[0], octets[1], octets[2], start[1], start[2], start[3],
+        ]),
+        length,
+    ))
+}
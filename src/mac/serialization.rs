@@ -0,0 +1,17 @@
+use super::MACAddress;
+use crate::serialization::{deserialize_compact, serialize_compact};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for MACAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_compact(self, self.as_slice(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MACAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_compact(deserializer, "a MAC address", |bytes| {
+            bytes.try_into().ok().map(MACAddress::new)
+        })
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(feature = "postgres")]
+mod postgres {
+    use crate::mac::MACAddress;
+    use bytes::BytesMut;
+    use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+    use std::error::Error;
+
+    impl<'a> FromSql<'a> for MACAddress {
+        fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            raw.try_into()
+                .map(MACAddress::new)
+                .map_err(|_| "invalid MACADDR value".into())
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            *ty == Type::MACADDR
+        }
+    }
+
+    impl ToSql for MACAddress {
+        fn to_sql(
+            &self,
+            _: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            out.extend_from_slice(self.as_slice());
+            Ok(IsNull::No)
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            *ty == Type::MACADDR
+        }
+
+        to_sql_checked!();
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+    use crate::mac::MACAddress;
+    use sqlx::{
+        encode::IsNull,
+        error::BoxDynError,
+        postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres},
+        Decode, Encode, Type,
+    };
+
+    impl Type<Postgres> for MACAddress {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("macaddr")
+        }
+    }
+
+    impl Encode<'_, Postgres> for MACAddress {
+        fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+            buf.extend_from_slice(self.as_slice());
+            Ok(IsNull::No)
+        }
+    }
+
+    impl<'r> Decode<'r, Postgres> for MACAddress {
+        fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+            match value.format() {
+                PgValueFormat::Binary => value
+                    .as_bytes()?
+                    .try_into()
+                    .map(MACAddress::new)
+                    .map_err(|_| "invalid MACADDR value".into()),
+                PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+            }
+        }
+    }
+}
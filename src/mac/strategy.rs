@@ -0,0 +1,29 @@
+use super::MACAddress;
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+/// Edge case MAC addresses: broadcast, unspecified, and multicast addresses
+const EDGE_CASES: &[MACAddress] = &[
+    MACAddress::BROADCAST,
+    MACAddress::UNSPECIFIED,
+    MACAddress::new([0x01, 0x00, 0x5E, 0x00, 0x00, 0x01]),
+    MACAddress::new([0x33, 0x33, 0x00, 0x00, 0x00, 0x01]),
+    MACAddress::new([0x01, 0x80, 0xC2, 0x00, 0x00, 0x00]),
+];
+
+impl Arbitrary for MACAddress {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            3 => any::<[u8; 6]>().prop_map(MACAddress::new),
+            1 => select(EDGE_CASES),
+        ]
+        .boxed()
+    }
+}
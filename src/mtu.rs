@@ -0,0 +1,196 @@
+//! Maximum transmission unit (MTU) utilities
+
+use crate::{ip::IPVersion, ErrorKind};
+use std::{num::ParseIntError, str::FromStr};
+
+/// A maximum transmission unit, the largest IP packet a link carries without fragmentation
+///
+/// The MTU counts the IP header but not the link-layer header, so a standard Ethernet link has an
+/// MTU of 1500 even though its frames are longer.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MTU(u16);
+
+/// An error while creating or parsing an MTU
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidMTUError {
+    /// The MTU is not a number
+    InvalidNumber(ParseIntError),
+
+    /// The MTU is smaller than the 68 bytes every IPv4 link must carry
+    TooSmall(u16),
+}
+
+/// The length of a TCP header without options
+const TCP_HEADER_LENGTH: u16 = 20;
+
+/// The length of a UDP header
+const UDP_HEADER_LENGTH: u16 = 8;
+
+impl MTU {
+    /// The smallest MTU allowed for IPv4
+    pub const IPV4_MIN: MTU = MTU(68);
+
+    /// The smallest MTU allowed for IPv6
+    pub const IPV6_MIN: MTU = MTU(1280);
+
+    /// The MTU of a PPPoE link over standard Ethernet
+    pub const PPPOE: MTU = MTU(1492);
+
+    /// The MTU of standard Ethernet
+    pub const ETHERNET: MTU = MTU(1500);
+
+    /// The MTU commonly used for jumbo Ethernet frames
+    pub const JUMBO: MTU = MTU(9000);
+
+    /// Creates a new [`MTU`]
+    ///
+    /// ## Parameters
+    ///  * `mtu` - The MTU in bytes
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`MTU`], or an error if `mtu` is smaller than 68
+    pub const fn new(mtu: u16) -> Result<Self, InvalidMTUError> {
+        if mtu < MTU::IPV4_MIN.0 {
+            Err(InvalidMTUError::TooSmall(mtu))
+        } else {
+            Ok(MTU(mtu))
+        }
+    }
+
+    /// Gets the MTU in bytes
+    ///
+    /// ## Return Value
+    /// Returns the MTU in bytes
+    pub const fn get(&self) -> u16 {
+        self.0
+    }
+
+    /// Checks if a link with this MTU may carry `version`
+    ///
+    /// ## Parameters
+    ///  * `version` - The IP version to check
+    ///
+    /// ## Return Value
+    /// Returns true if this MTU is at least the minimum MTU of `version`
+    pub const fn supports(&self, version: IPVersion) -> bool {
+        self.0 >= version.min_mtu()
+    }
+
+    /// Gets the TCP maximum segment size to advertise on a link with this MTU
+    ///
+    /// ## Parameters
+    ///  * `version` - The IP version carrying the connection
+    ///
+    /// ## Return Value
+    /// Returns this MTU less the IP and TCP headers without options
+    pub const fn tcp_mss(&self, version: IPVersion) -> u16 {
+        self.0
+            .saturating_sub(version.min_header_len())
+            .saturating_sub(TCP_HEADER_LENGTH)
+    }
+
+    /// Gets the largest UDP payload which fits in a single packet on a link with this MTU
+    ///
+    /// ## Parameters
+    ///  * `version` - The IP version carrying the datagrams
+    ///
+    /// ## Return Value
+    /// Returns this MTU less the IP and UDP headers
+    pub const fn udp_payload(&self, version: IPVersion) -> u16 {
+        self.0
+            .saturating_sub(version.min_header_len())
+            .saturating_sub(UDP_HEADER_LENGTH)
+    }
+
+    /// Gets the MTU inside a tunnel carried over a link with this MTU
+    ///
+    /// ## Parameters
+    ///  * `overhead` - The bytes each packet grows by when encapsulated, including the outer IP
+    ///    header
+    ///
+    /// ## Return Value
+    /// Returns the MTU of the tunnel, or an error if it would be smaller than 68
+    pub const fn tunnel(&self, overhead: u16) -> Result<Self, InvalidMTUError> {
+        MTU::new(self.0.saturating_sub(overhead))
+    }
+}
+
+impl TryFrom<u16> for MTU {
+    type Error = InvalidMTUError;
+
+    fn try_from(mtu: u16) -> Result<Self, Self::Error> {
+        MTU::new(mtu)
+    }
+}
+
+impl From<MTU> for u16 {
+    fn from(mtu: MTU) -> Self {
+        mtu.0
+    }
+}
+
+impl Default for MTU {
+    fn default() -> Self {
+        MTU::ETHERNET
+    }
+}
+
+impl FromStr for MTU {
+    type Err = InvalidMTUError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MTU::new(s.parse().map_err(InvalidMTUError::InvalidNumber)?)
+    }
+}
+
+impl std::fmt::Display for MTU {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for MTU {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl InvalidMTUError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidMTUError::InvalidNumber(_) => ErrorKind::InvalidNumber,
+            InvalidMTUError::TooSmall(_) => ErrorKind::OutOfRange,
+        }
+    }
+}
+
+impl std::error::Error for InvalidMTUError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidMTUError::InvalidNumber(error) => Some(error),
+            InvalidMTUError::TooSmall(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidMTUError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidMTUError::InvalidNumber(error) => write!(f, "invalid MTU - {}", error),
+            InvalidMTUError::TooSmall(mtu) => {
+                write!(f, "MTU {} is less than the minimum of 68", mtu)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidMTUError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
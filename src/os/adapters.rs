@@ -0,0 +1,240 @@
+use crate::{
+    ip::{v4::IPv4Address, v6::IPv6Address, IPAddress},
+    mac::MACAddress,
+};
+use std::ffi::{c_char, c_int, c_void};
+
+/// The start of an `IP_ADAPTER_ADDRESSES_LH` entry of the list returned by
+/// `GetAdaptersAddresses`
+///
+/// Only the fields up to the IPv6 interface index are declared, as none after it are read.
+#[repr(C)]
+struct AdapterAddresses {
+    length: u32,
+    if_index: u32,
+    next: *mut AdapterAddresses,
+    adapter_name: *mut c_char,
+    first_unicast_address: *mut UnicastAddress,
+    first_anycast_address: *mut c_void,
+    first_multicast_address: *mut c_void,
+    first_dns_server_address: *mut c_void,
+    dns_suffix: *mut u16,
+    description: *mut u16,
+    friendly_name: *mut u16,
+    physical_address: [u8; 8],
+    physical_address_length: u32,
+    flags: u32,
+    mtu: u32,
+    if_type: u32,
+    oper_status: c_int,
+    ipv6_if_index: u32,
+}
+
+/// The start of an `IP_ADAPTER_UNICAST_ADDRESS_LH` entry of an adapter's address list
+///
+/// Only the fields up to the prefix length are declared, as none after it are read.
+#[repr(C)]
+struct UnicastAddress {
+    length: u32,
+    flags: u32,
+    next: *mut UnicastAddress,
+    address: *mut c_void,
+    address_length: c_int,
+    prefix_origin: c_int,
+    suffix_origin: c_int,
+    dad_state: c_int,
+    valid_lifetime: u32,
+    preferred_lifetime: u32,
+    lease_lifetime: u32,
+    on_link_prefix_length: u8,
+}
+
+#[link(name = "iphlpapi")]
+extern "system" {
+    fn GetAdaptersAddresses(
+        family: u32,
+        flags: u32,
+        reserved: *mut c_void,
+        addresses: *mut AdapterAddresses,
+        size: *mut u32,
+    ) -> u32;
+}
+
+/// Requests both IPv4 and IPv6 addresses
+const AF_UNSPEC: u32 = 0;
+
+/// The address family of IPv4 socket addresses
+const AF_INET: u16 = 2;
+
+/// The address family of IPv6 socket addresses
+const AF_INET6: u16 = 23;
+
+/// Leaves the anycast, multicast, and DNS server addresses out of the list
+const GAA_FLAGS: u32 = 0x2 | 0x4 | 0x8;
+
+/// Returned when the buffer is too small to hold the list
+const ERROR_BUFFER_OVERFLOW: u32 = 111;
+
+/// Returned when there are no adapters
+const ERROR_NO_DATA: u32 = 232;
+
+/// The size of the buffer first offered to `GetAdaptersAddresses`, as Microsoft recommends
+const INITIAL_BUFFER_SIZE: u32 = 15 * 1024;
+
+/// An address assigned to a local interface
+pub(crate) struct InterfaceAddress {
+    /// The name of the interface
+    pub(crate) name: String,
+
+    /// The assigned address
+    pub(crate) address: IPAddress,
+
+    /// The prefix length of the network the address is on
+    pub(crate) prefix: u8,
+}
+
+/// The hardware address of a local interface
+pub(crate) struct HardwareAddress {
+    /// The name of the interface
+    pub(crate) name: String,
+
+    /// The hardware address
+    pub(crate) address: MACAddress,
+}
+
+/// Gets every IP address assigned to a local interface
+///
+/// ## Return Value
+/// Returns the addresses in the order the system lists them
+pub(crate) fn interface_addresses() -> std::io::Result<Vec<InterfaceAddress>> {
+    let mut addresses = Vec::new();
+    for_each_adapter(|adapter| {
+        let name = unsafe { read_name(adapter) };
+
+        let mut entry = adapter.first_unicast_address;
+        while let Some(current) = unsafe { entry.as_ref() } {
+            if let Some(address) = unsafe { read_address(current.address) } {
+                addresses.push(InterfaceAddress {
+                    name: name.clone(),
+                    address,
+                    prefix: current.on_link_prefix_length,
+                });
+            }
+            entry = current.next;
+        }
+    })?;
+    Ok(addresses)
+}
+
+/// Gets the Ethernet hardware address of every local interface which has one
+///
+/// ## Return Value
+/// Returns the hardware addresses in the order the system lists them
+pub(crate) fn hardware_addresses() -> std::io::Result<Vec<HardwareAddress>> {
+    let mut addresses = Vec::new();
+    for_each_adapter(|adapter| {
+        if adapter.physical_address_length != 6 {
+            return;
+        }
+
+        let mut octets = [0; 6];
+        octets.copy_from_slice(&adapter.physical_address[..6]);
+        addresses.push(HardwareAddress {
+            name: unsafe { read_name(adapter) },
+            address: MACAddress::new(octets),
+        });
+    })?;
+    Ok(addresses)
+}
+
+/// Gets the index of the interface `name`
+///
+/// ## Parameters
+///  * `name` - The friendly name of the interface, such as "Ethernet"
+///
+/// ## Return Value
+/// Returns the index of the interface, or [`None`] if there is no interface with that name
+pub(crate) fn interface_index(name: &str) -> Option<u32> {
+    let mut index = None;
+    for_each_adapter(|adapter| {
+        if index.is_none() && unsafe { read_name(adapter) } == name {
+            // The IPv4 index is zero when IPv4 is disabled on the interface
+            index = match adapter.if_index {
+                0 => Some(adapter.ipv6_if_index),
+                index => Some(index),
+            };
+        }
+    })
+    .ok()?;
+    index.filter(|index| *index != 0)
+}
+
+/// Calls `f` with each entry of the list returned by `GetAdaptersAddresses`
+fn for_each_adapter<F: FnMut(&AdapterAddresses)>(mut f: F) -> std::io::Result<()> {
+    // The list holds pointers, so the buffer is made of `u64`s to keep it aligned
+    let mut size = INITIAL_BUFFER_SIZE;
+    let buffer = loop {
+        let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+        match unsafe {
+            GetAdaptersAddresses(
+                AF_UNSPEC,
+                GAA_FLAGS,
+                std::ptr::null_mut(),
+                buffer.as_mut_ptr() as *mut AdapterAddresses,
+                &mut size,
+            )
+        } {
+            0 => break buffer,
+            ERROR_NO_DATA => return Ok(()),
+            ERROR_BUFFER_OVERFLOW => continue,
+            error => return Err(std::io::Error::from_raw_os_error(error as i32)),
+        }
+    };
+
+    let mut entry = buffer.as_ptr() as *const AdapterAddresses;
+    while let Some(current) = unsafe { entry.as_ref() } {
+        f(current);
+        entry = current.next;
+    }
+
+    Ok(())
+}
+
+/// Reads the friendly name of an adapter, such as "Ethernet" or "Wi-Fi"
+unsafe fn read_name(adapter: &AdapterAddresses) -> String {
+    if adapter.friendly_name.is_null() {
+        return String::new();
+    }
+
+    let mut length = 0;
+    while *adapter.friendly_name.add(length) != 0 {
+        length += 1;
+    }
+
+    String::from_utf16_lossy(std::slice::from_raw_parts(adapter.friendly_name, length))
+}
+
+/// Reads the IP address out of a `SOCKADDR`
+///
+/// ## Return Value
+/// Returns the address, or [`None`] if `address` is null or not an IPv4 or IPv6 address
+unsafe fn read_address(address: *const c_void) -> Option<IPAddress> {
+    if address.is_null() {
+        return None;
+    }
+
+    let bytes = address as *const u8;
+    match (bytes as *const u16).read_unaligned() {
+        AF_INET => {
+            let mut octets = [0; 4];
+            std::ptr::copy_nonoverlapping(bytes.add(4), octets.as_mut_ptr(), 4);
+            Some(IPAddress::V4(IPv4Address::from(octets)))
+        }
+        AF_INET6 => {
+            let mut octets = [0; 16];
+            std::ptr::copy_nonoverlapping(bytes.add(8), octets.as_mut_ptr(), 16);
+            Some(IPAddress::V6(IPv6Address::from(octets)))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    ip::{v4::IPv4Address, v6::IPv6Address, IPAddress},
+    mac::MACAddress,
+};
+use std::ffi::{c_char, c_int, c_uint, c_void, CStr};
+
+/// An entry of the list returned by `getifaddrs`
+#[repr(C)]
+struct IfAddrs {
+    next: *mut IfAddrs,
+    name: *mut c_char,
+    flags: c_uint,
+    address: *mut c_void,
+    netmask: *mut c_void,
+    destination: *mut c_void,
+    data: *mut c_void,
+}
+
+extern "C" {
+    fn getifaddrs(list: *mut *mut IfAddrs) -> c_int;
+    fn freeifaddrs(list: *mut IfAddrs);
+}
+
+/// The address family of IPv4 socket addresses
+const AF_INET: u16 = 2;
+
+/// The address family of IPv6 socket addresses
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const AF_INET6: u16 = 10;
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+const AF_INET6: u16 = 30;
+#[cfg(target_os = "freebsd")]
+const AF_INET6: u16 = 28;
+#[cfg(any(target_os = "openbsd", target_os = "netbsd"))]
+const AF_INET6: u16 = 24;
+
+/// The address family of link-layer socket addresses
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const AF_LINK: u16 = 17;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+const AF_LINK: u16 = 18;
+
+/// An address assigned to a local interface
+pub(crate) struct InterfaceAddress {
+    /// The name of the interface
+    pub(crate) name: String,
+
+    /// The assigned address
+    pub(crate) address: IPAddress,
+
+    /// The prefix length of the network the address is on
+    pub(crate) prefix: u8,
+}
+
+/// The hardware address of a local interface
+pub(crate) struct HardwareAddress {
+    /// The name of the interface
+    pub(crate) name: String,
+
+    /// The hardware address
+    pub(crate) address: MACAddress,
+}
+
+/// Gets every IP address assigned to a local interface
+///
+/// ## Return Value
+/// Returns the addresses in the order the system lists them
+pub(crate) fn interface_addresses() -> std::io::Result<Vec<InterfaceAddress>> {
+    let mut addresses = Vec::new();
+    for_each_entry(|entry| {
+        if let Some(address) = unsafe { read_address(entry.address) } {
+            addresses.push(InterfaceAddress {
+                name: unsafe { read_name(entry) },
+                prefix: unsafe { read_prefix(entry.netmask, &address) },
+                address,
+            });
+        }
+    })?;
+    Ok(addresses)
+}
+
+/// Gets the Ethernet hardware address of every local interface which has one
+///
+/// ## Return Value
+/// Returns the hardware addresses in the order the system lists them
+pub(crate) fn hardware_addresses() -> std::io::Result<Vec<HardwareAddress>> {
+    let mut addresses = Vec::new();
+    for_each_entry(|entry| {
+        if let Some(address) = unsafe { read_hardware_address(entry.address) } {
+            addresses.push(HardwareAddress {
+                name: unsafe { read_name(entry) },
+                address,
+            });
+        }
+    })?;
+    Ok(addresses)
+}
+
+/// Calls `f` with each entry of the list returned by `getifaddrs`
+fn for_each_entry<F: FnMut(&IfAddrs)>(mut f: F) -> std::io::Result<()> {
+    let mut list = std::ptr::null_mut();
+    if unsafe { getifaddrs(&mut list) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    let mut entry = list;
+    while let Some(current) = unsafe { entry.as_ref() } {
+        f(current);
+        entry = current.next;
+    }
+
+    unsafe { freeifaddrs(list) };
+    Ok(())
+}
+
+/// Reads the interface name of an entry
+unsafe fn read_name(entry: &IfAddrs) -> String {
+    CStr::from_ptr(entry.name).to_string_lossy().into_owned()
+}
+
+/// Reads the IP address out of a `sockaddr`
+///
+/// ## Return Value
+/// Returns the address, or [`None`] if `address` is null or not an IPv4 or IPv6 address
+unsafe fn read_address(address: *const c_void) -> Option<IPAddress> {
+    if address.is_null() {
+        return None;
+    }
+
+    let bytes = address as *const u8;
+    match family(bytes) {
+        AF_INET => {
+            let mut octets = [0; 4];
+            std::ptr::copy_nonoverlapping(bytes.add(4), octets.as_mut_ptr(), 4);
+            Some(IPAddress::V4(IPv4Address::from(octets)))
+        }
+        AF_INET6 => {
+            let mut octets = [0; 16];
+            std::ptr::copy_nonoverlapping(bytes.add(8), octets.as_mut_ptr(), 16);
+            Some(IPAddress::V6(IPv6Address::from(octets)))
+        }
+        _ => None,
+    }
+}
+
+/// Reads the prefix length out of the netmask `sockaddr` of `address`
+///
+/// The family of the netmask is not checked, as some systems leave it unset.
+///
+/// ## Return Value
+/// Returns the number of leading set bits of the netmask, or the full length of `address` if
+/// there is no netmask
+unsafe fn read_prefix(netmask: *const c_void, address: &IPAddress) -> u8 {
+    let (offset, length) = match address {
+        IPAddress::V4(_) => (4, 4),
+        IPAddress::V6(_) => (8, 16),
+    };
+
+    if netmask.is_null() {
+        return length as u8 * 8;
+    }
+
+    let bytes = netmask as *const u8;
+    let available = sockaddr_length(bytes)
+        .map(|available| available.saturating_sub(offset).min(length))
+        .unwrap_or(length);
+
+    let mut prefix = 0;
+    for i in 0..available {
+        let byte = *bytes.add(offset + i);
+        prefix += byte.leading_ones() as u8;
+        if byte != 0xFF {
+            break;
+        }
+    }
+    prefix
+}
+
+/// Reads the Ethernet address out of a link-layer `sockaddr_ll`
+///
+/// ## Return Value
+/// Returns the address, or [`None`] if `address` is null or not a 6 byte link-layer address
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn read_hardware_address(address: *const c_void) -> Option<MACAddress> {
+    if address.is_null() {
+        return None;
+    }
+
+    let bytes = address as *const u8;
+    if family(bytes) != AF_LINK || *bytes.add(11) != 6 {
+        return None;
+    }
+
+    let mut octets = [0; 6];
+    std::ptr::copy_nonoverlapping(bytes.add(12), octets.as_mut_ptr(), 6);
+    Some(MACAddress::new(octets))
+}
+
+/// Reads the Ethernet address out of a link-layer `sockaddr_dl`, which follows the interface
+/// name
+///
+/// ## Return Value
+/// Returns the address, or [`None`] if `address` is null or not a 6 byte link-layer address
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn read_hardware_address(address: *const c_void) -> Option<MACAddress> {
+    if address.is_null() {
+        return None;
+    }
+
+    let bytes = address as *const u8;
+    if family(bytes) != AF_LINK || *bytes.add(6) != 6 {
+        return None;
+    }
+
+    let mut octets = [0; 6];
+    let name_length = *bytes.add(5) as usize;
+    std::ptr::copy_nonoverlapping(bytes.add(8 + name_length), octets.as_mut_ptr(), 6);
+    Some(MACAddress::new(octets))
+}
+
+/// Reads the address family of a `sockaddr`
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn family(address: *const u8) -> u16 {
+    (address as *const u16).read_unaligned()
+}
+
+/// Reads the address family of a `sockaddr`, which follows a length byte on BSD systems
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn family(address: *const u8) -> u16 {
+    *address.add(1) as u16
+}
+
+/// Reads the length of a `sockaddr`, which is not recorded on Linux
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn sockaddr_length(_: *const u8) -> Option<usize> {
+    None
+}
+
+/// Reads the length of a `sockaddr` from its first byte
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn sockaddr_length(address: *const u8) -> Option<usize> {
+    Some(*address as usize)
+}
@@ -0,0 +1,68 @@
+//! Access to the network configuration of the running system
+//!
+//! Interface addresses are read with `getifaddrs` on the Unix systems which provide it and with
+//! `GetAdaptersAddresses` on Windows. Every other platform falls back to reporting them as
+//! unsupported.
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd"
+))]
+#[path = "ifaddrs.rs"]
+mod addresses;
+
+#[cfg(windows)]
+#[path = "adapters.rs"]
+mod addresses;
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    windows
+)))]
+#[path = "unsupported.rs"]
+mod addresses;
+
+pub(crate) use addresses::{hardware_addresses, interface_addresses};
+
+#[cfg(windows)]
+pub(crate) use addresses::interface_index;
+
+/// Gets the index of the interface `name`
+///
+/// ## Parameters
+///  * `name` - The name of the interface, such as "eth0"
+///
+/// ## Return Value
+/// Returns the index of the interface, or [`None`] if there is no interface with that name
+#[cfg(unix)]
+pub(crate) fn interface_index(name: &str) -> Option<u32> {
+    extern "C" {
+        fn if_nametoindex(name: *const std::ffi::c_char) -> std::ffi::c_uint;
+    }
+
+    let name = std::ffi::CString::new(name).ok()?;
+    match unsafe { if_nametoindex(name.as_ptr()) } {
+        0 => None,
+        index => Some(index),
+    }
+}
+
+/// Gets the index of the interface `name`
+///
+/// ## Return Value
+/// Returns [`None`], as interface indices are not available on this platform
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn interface_index(_: &str) -> Option<u32> {
+    None
+}
@@ -0,0 +1,46 @@
+use crate::{ip::IPAddress, mac::MACAddress};
+
+/// An address assigned to a local interface
+pub(crate) struct InterfaceAddress {
+    /// The name of the interface
+    pub(crate) name: String,
+
+    /// The assigned address
+    pub(crate) address: IPAddress,
+
+    /// The prefix length of the network the address is on
+    pub(crate) prefix: u8,
+}
+
+/// The hardware address of a local interface
+pub(crate) struct HardwareAddress {
+    /// The name of the interface
+    pub(crate) name: String,
+
+    /// The hardware address
+    pub(crate) address: MACAddress,
+}
+
+/// Gets every IP address assigned to a local interface
+///
+/// ## Return Value
+/// Returns an error, as listing interface addresses is not supported on this platform
+pub(crate) fn interface_addresses() -> std::io::Result<Vec<InterfaceAddress>> {
+    Err(unsupported())
+}
+
+/// Gets the Ethernet hardware address of every local interface which has one
+///
+/// ## Return Value
+/// Returns an error, as listing interface addresses is not supported on this platform
+pub(crate) fn hardware_addresses() -> std::io::Result<Vec<HardwareAddress>> {
+    Err(unsupported())
+}
+
+/// Creates the error returned when interfaces cannot be listed
+fn unsupported() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "listing interface addresses is not supported on this platform",
+    )
+}
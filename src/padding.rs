@@ -0,0 +1,57 @@
+use std::fmt::{Arguments, Formatter, Write};
+
+/// The longest text which can be padded, enough for an IPv6 CIDR
+const MAX_LENGTH: usize = 64;
+
+/// Text formatted on the stack before it is padded
+struct PaddingBuffer {
+    bytes: [u8; MAX_LENGTH],
+    length: usize,
+}
+
+impl PaddingBuffer {
+    /// Creates a new, empty [`PaddingBuffer`]
+    const fn new() -> Self {
+        PaddingBuffer {
+            bytes: [0; MAX_LENGTH],
+            length: 0,
+        }
+    }
+
+    /// Gets the text written into this buffer
+    fn as_str(&self) -> &str {
+        // Only complete strings are ever copied in, so the bytes are always valid UTF-8
+        std::str::from_utf8(&self.bytes[..self.length]).unwrap()
+    }
+}
+
+impl Write for PaddingBuffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.length + s.len();
+        if end > MAX_LENGTH {
+            return Err(std::fmt::Error);
+        }
+
+        self.bytes[self.length..end].copy_from_slice(s.as_bytes());
+        self.length = end;
+        Ok(())
+    }
+}
+
+/// Writes `args` into `f`, honoring the width, fill, alignment, and precision flags of `f`
+///
+/// ## Parameters
+///  * `f` - The formatter to write into
+///  * `args` - The text to write, which must be at most 64 bytes when any flags are given
+///
+/// ## Return Value
+/// Returns an error if writing into `f` fails
+pub(crate) fn pad(f: &mut Formatter, args: Arguments) -> std::fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return f.write_fmt(args);
+    }
+
+    let mut buffer = PaddingBuffer::new();
+    buffer.write_fmt(args)?;
+    f.pad(buffer.as_str())
+}
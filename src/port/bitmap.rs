@@ -0,0 +1,233 @@
+use super::{Port, PortRange, PortSet};
+
+/// The number of 64-bit words needed for one bit per port
+const WORDS: usize = (Port::MAX as usize + 1) / 64;
+
+/// A set of ports held as a fixed bitmap with one bit for each of the 65536 ports
+///
+/// The bitmap always takes 8 KiB, but inserting and checking a port take constant time and
+/// combining two bitmaps works a word at a time. This suits sets with many scattered ports, such
+/// as the results of a scan, while a [`PortSet`] is smaller for a few ranges.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PortBitmap {
+    words: Box<[u64; WORDS]>,
+}
+
+impl PortBitmap {
+    /// Creates a new, empty [`PortBitmap`]
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PortBitmap`]
+    pub fn new() -> Self {
+        PortBitmap {
+            words: Box::new([0; WORDS]),
+        }
+    }
+
+    /// Adds a port to this bitmap
+    ///
+    /// ## Parameters
+    ///  * `port` - The port to add
+    ///
+    /// ## Return Value
+    /// Returns true if `port` was not already in this bitmap
+    pub fn insert(&mut self, port: Port) -> bool {
+        let (word, bit) = position(port);
+        let inserted = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        inserted
+    }
+
+    /// Adds a range of ports to this bitmap
+    ///
+    /// ## Parameters
+    ///  * `range` - The ports to add
+    pub fn insert_range(&mut self, range: PortRange) {
+        let (start, end) = (range.start() as usize, range.end() as usize);
+
+        for word in start / 64..=end / 64 {
+            let low = if word == start / 64 { start % 64 } else { 0 };
+            let high = if word == end / 64 { end % 64 } else { 63 };
+            self.words[word] |= (u64::MAX >> (63 - high)) & (u64::MAX << low);
+        }
+    }
+
+    /// Removes a port from this bitmap
+    ///
+    /// ## Parameters
+    ///  * `port` - The port to remove
+    ///
+    /// ## Return Value
+    /// Returns true if `port` was in this bitmap
+    pub fn remove(&mut self, port: Port) -> bool {
+        let (word, bit) = position(port);
+        let removed = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        removed
+    }
+
+    /// Checks if this bitmap holds `port`
+    ///
+    /// ## Parameters
+    ///  * `port` - The port to check
+    ///
+    /// ## Return Value
+    /// Returns true if `port` is in this bitmap
+    pub fn contains(&self, port: Port) -> bool {
+        let (word, bit) = position(port);
+        self.words[word] & bit != 0
+    }
+
+    /// Gets the number of ports in this bitmap
+    ///
+    /// ## Return Value
+    /// Returns the number of ports in this bitmap
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Checks if this bitmap holds no ports
+    ///
+    /// ## Return Value
+    /// Returns true if this bitmap is empty
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    /// Gets an iterator over the ports of this bitmap
+    ///
+    /// ## Return Value
+    /// Returns an iterator over every port in this bitmap, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = Port> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some((index * 64 + bit) as Port)
+            })
+        })
+    }
+
+    /// Gets the ranges of consecutive ports in this bitmap
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the longest ranges of consecutive ports, in ascending order
+    pub fn ranges(&self) -> impl Iterator<Item = PortRange> + '_ {
+        let mut ports = self.iter().peekable();
+        std::iter::from_fn(move || {
+            let start = ports.next()?;
+            let mut end = start;
+            while ports
+                .next_if(|port| end < Port::MAX && *port == end + 1)
+                .is_some()
+            {
+                end += 1;
+            }
+
+            Some(PortRange::new(start, end))
+        })
+    }
+
+    /// Gets the ports in either this bitmap or `other`
+    ///
+    /// ## Parameters
+    ///  * `other` - The bitmap to combine with
+    ///
+    /// ## Return Value
+    /// Returns the union of the two bitmaps
+    pub fn union(&self, other: &PortBitmap) -> PortBitmap {
+        let mut union = self.clone();
+        for (word, other) in union.words.iter_mut().zip(other.words.iter()) {
+            *word |= other;
+        }
+        union
+    }
+
+    /// Gets the ports in both this bitmap and `other`
+    ///
+    /// ## Parameters
+    ///  * `other` - The bitmap to intersect with
+    ///
+    /// ## Return Value
+    /// Returns the intersection of the two bitmaps
+    pub fn intersection(&self, other: &PortBitmap) -> PortBitmap {
+        let mut intersection = self.clone();
+        for (word, other) in intersection.words.iter_mut().zip(other.words.iter()) {
+            *word &= other;
+        }
+        intersection
+    }
+}
+
+impl Default for PortBitmap {
+    fn default() -> Self {
+        PortBitmap::new()
+    }
+}
+
+impl From<&PortSet> for PortBitmap {
+    fn from(set: &PortSet) -> Self {
+        let mut bitmap = PortBitmap::new();
+        for range in set.ranges() {
+            bitmap.insert_range(*range);
+        }
+        bitmap
+    }
+}
+
+impl From<&PortBitmap> for PortSet {
+    fn from(bitmap: &PortBitmap) -> Self {
+        // The ranges are already sorted and separated, so nothing needs to be merged
+        PortSet {
+            ranges: bitmap.ranges().collect(),
+        }
+    }
+}
+
+impl FromIterator<Port> for PortBitmap {
+    fn from_iter<I: IntoIterator<Item = Port>>(iter: I) -> Self {
+        let mut bitmap = PortBitmap::new();
+        bitmap.extend(iter);
+        bitmap
+    }
+}
+
+impl Extend<Port> for PortBitmap {
+    fn extend<I: IntoIterator<Item = Port>>(&mut self, iter: I) {
+        for port in iter {
+            self.insert(port);
+        }
+    }
+}
+
+impl std::fmt::Display for PortBitmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.ranges().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            range.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for PortBitmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Gets the word holding `port` and the bit for it within that word
+fn position(port: Port) -> (usize, u64) {
+    (port as usize / 64, 1 << (port % 64))
+}
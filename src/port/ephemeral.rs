@@ -0,0 +1,87 @@
+use super::{Port, PortRange};
+
+/// The range of ports an operating system picks source ports from for outgoing connections
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EphemeralRange {
+    range: PortRange,
+}
+
+impl EphemeralRange {
+    /// The dynamic port range assigned by IANA in RFC 6335, 49152-65535
+    pub const IANA: EphemeralRange = EphemeralRange::new(PortRange::new(49152, 65535));
+
+    /// The default range on Linux, 32768-60999
+    pub const LINUX: EphemeralRange = EphemeralRange::new(PortRange::new(32768, 60999));
+
+    /// The default range on Windows Vista and later, 49152-65535
+    pub const WINDOWS: EphemeralRange = EphemeralRange::IANA;
+
+    /// The default range on Windows XP and earlier, 1025-5000
+    pub const WINDOWS_LEGACY: EphemeralRange = EphemeralRange::new(PortRange::new(1025, 5000));
+
+    /// The default range on macOS, 49152-65535
+    pub const MACOS: EphemeralRange = EphemeralRange::IANA;
+
+    /// The default range on FreeBSD, 10000-65535
+    pub const FREEBSD: EphemeralRange = EphemeralRange::new(PortRange::new(10000, 65535));
+
+    /// Creates a new [`EphemeralRange`]
+    ///
+    /// ## Parameters
+    ///  * `range` - The ports used as source ports
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`EphemeralRange`]
+    pub const fn new(range: PortRange) -> Self {
+        EphemeralRange { range }
+    }
+
+    /// Gets the ports of this range
+    ///
+    /// ## Return Value
+    /// Returns the ports used as source ports
+    pub const fn range(&self) -> PortRange {
+        self.range
+    }
+
+    /// Checks if `port` is an ephemeral port in this range
+    ///
+    /// ## Parameters
+    ///  * `port` - The port to check
+    ///
+    /// ## Return Value
+    /// Returns true if `port` may have been picked as a source port
+    pub const fn is_ephemeral(&self, port: Port) -> bool {
+        self.range.contains(port)
+    }
+}
+
+impl Default for EphemeralRange {
+    fn default() -> Self {
+        EphemeralRange::IANA
+    }
+}
+
+impl From<PortRange> for EphemeralRange {
+    fn from(range: PortRange) -> Self {
+        EphemeralRange::new(range)
+    }
+}
+
+impl From<EphemeralRange> for PortRange {
+    fn from(range: EphemeralRange) -> Self {
+        range.range
+    }
+}
+
+impl std::fmt::Display for EphemeralRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.range.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for EphemeralRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,87 @@
+use super::Port;
+use crate::ErrorKind;
+use std::num::ParseIntError;
+
+/// An error while parsing a port range
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidPortRangeError {
+    /// A port could not be parsed
+    InvalidPort(ParseIntError),
+
+    /// The start of the range is after its end
+    Reversed {
+        /// The start of the range
+        start: Port,
+
+        /// The end of the range
+        end: Port,
+    },
+}
+
+impl InvalidPortRangeError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidPortRangeError::InvalidPort(_) => ErrorKind::InvalidNumber,
+            InvalidPortRangeError::Reversed { .. } => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidPortRangeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidPortRangeError::InvalidPort(error) => Some(error),
+            InvalidPortRangeError::Reversed { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidPortRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidPortRangeError::InvalidPort(error) => write!(f, "invalid port - {}", error),
+            InvalidPortRangeError::Reversed { start, end } => {
+                write!(f, "range start {} is after its end {}", start, end)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidPortRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// A protocol is not "tcp" or "udp"
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidProtocolError;
+
+impl InvalidProtocolError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        ErrorKind::Unsupported
+    }
+}
+
+impl std::error::Error for InvalidProtocolError {}
+
+impl std::fmt::Display for InvalidProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid protocol")
+    }
+}
+
+impl std::fmt::Debug for InvalidProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,14 @@
+use super::{Port, PortRange};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for PortRange {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let a: Port = u.arbitrary()?;
+        let b: Port = u.arbitrary()?;
+        Ok(PortRange::new(a.min(b), a.max(b)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 4]>::size_hint(depth)
+    }
+}
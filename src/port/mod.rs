@@ -0,0 +1,60 @@
+//! Port utilities
+
+pub mod services;
+
+mod bitmap;
+mod ephemeral;
+mod error;
+mod protocol;
+mod range;
+mod set;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use bitmap::PortBitmap;
+pub use ephemeral::EphemeralRange;
+pub use error::{InvalidPortRangeError, InvalidProtocolError};
+pub use protocol::Protocol;
+pub use range::PortRange;
+pub use set::PortSet;
+
+/// A TCP or UDP port number
+pub type Port = u16;
+
+/// Checks if `port` is a well-known (system) port, 0-1023
+///
+/// ## Parameters
+///  * `port` - The port to check
+///
+/// ## Return Value
+/// Returns true if `port` is below 1024
+pub const fn is_well_known(port: Port) -> bool {
+    port < 1024
+}
+
+/// Checks if `port` is a registered (user) port, 1024-49151
+///
+/// ## Parameters
+///  * `port` - The port to check
+///
+/// ## Return Value
+/// Returns true if `port` is in the registered range
+pub const fn is_registered(port: Port) -> bool {
+    port >= 1024 && port < 49152
+}
+
+/// Checks if `port` is in the IANA dynamic range, 49152-65535
+///
+/// Operating systems often use a different range for source ports, see [`EphemeralRange`].
+///
+/// ## Parameters
+///  * `port` - The port to check
+///
+/// ## Return Value
+/// Returns true if `port` is in the dynamic range
+pub const fn is_dynamic(port: Port) -> bool {
+    EphemeralRange::IANA.is_ephemeral(port)
+}
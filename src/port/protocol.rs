@@ -0,0 +1,35 @@
+use super::InvalidProtocolError;
+use std::str::FromStr;
+
+/// A transport protocol which uses ports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Protocol {
+    /// The Transmission Control Protocol
+    TCP,
+
+    /// The User Datagram Protocol
+    UDP,
+}
+
+impl FromStr for Protocol {
+    type Err = InvalidProtocolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("tcp") {
+            Ok(Protocol::TCP)
+        } else if s.eq_ignore_ascii_case("udp") {
+            Ok(Protocol::UDP)
+        } else {
+            Err(InvalidProtocolError)
+        }
+    }
+}
+
+impl std::fmt::Display for Protocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Protocol::TCP => "tcp",
+            Protocol::UDP => "udp",
+        })
+    }
+}
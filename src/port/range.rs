@@ -0,0 +1,217 @@
+use super::{InvalidPortRangeError, Port};
+use std::{ops::RangeInclusive, str::FromStr};
+
+/// An inclusive range of ports, such as "8000-9000"
+///
+/// A range always holds at least one port. A single port is written without the '-'.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PortRange {
+    start: Port,
+    end: Port,
+}
+
+impl PortRange {
+    /// The range holding every port
+    pub const ALL: PortRange = PortRange {
+        start: 0,
+        end: Port::MAX,
+    };
+
+    /// Creates a new [`PortRange`]
+    ///
+    /// ## Parameters
+    ///  * `start` - The first port in the range
+    ///  * `end` - The last port in the range, must not be less than `start`
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PortRange`]
+    pub const fn new(start: Port, end: Port) -> Self {
+        assert!(start <= end);
+        PortRange { start, end }
+    }
+
+    /// Attempts to create a new [`PortRange`]
+    ///
+    /// ## Parameters
+    ///  * `start` - The first port in the range
+    ///  * `end` - The last port in the range
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PortRange`] if `start` is not after `end`
+    pub const fn try_new(start: Port, end: Port) -> Result<Self, InvalidPortRangeError> {
+        if start > end {
+            Err(InvalidPortRangeError::Reversed { start, end })
+        } else {
+            Ok(PortRange { start, end })
+        }
+    }
+
+    /// Creates a [`PortRange`] holding a single port
+    ///
+    /// ## Parameters
+    ///  * `port` - The only port in the range
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PortRange`]
+    pub const fn single(port: Port) -> Self {
+        PortRange {
+            start: port,
+            end: port,
+        }
+    }
+
+    /// Gets the first port of this range
+    ///
+    /// ## Return Value
+    /// Returns the first port in this range
+    pub const fn start(&self) -> Port {
+        self.start
+    }
+
+    /// Gets the last port of this range
+    ///
+    /// ## Return Value
+    /// Returns the last port in this range
+    pub const fn end(&self) -> Port {
+        self.end
+    }
+
+    /// Gets the number of ports in this range
+    ///
+    /// ## Return Value
+    /// Returns the number of ports, which is always at least one
+    pub const fn len(&self) -> usize {
+        (self.end - self.start) as usize + 1
+    }
+
+    /// Checks if this range holds no ports
+    ///
+    /// ## Return Value
+    /// Returns false, as a range always holds at least one port
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Checks if this range holds `port`
+    ///
+    /// ## Parameters
+    ///  * `port` - The port to check
+    ///
+    /// ## Return Value
+    /// Returns true if `port` is between the start and end of this range
+    pub const fn contains(&self, port: Port) -> bool {
+        self.start <= port && port <= self.end
+    }
+
+    /// Checks if this range shares any ports with `other`
+    ///
+    /// ## Parameters
+    ///  * `other` - The range to check against
+    ///
+    /// ## Return Value
+    /// Returns true if at least one port is in both ranges
+    pub const fn overlaps(&self, other: &PortRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Gets the ports in both this range and `other`
+    ///
+    /// ## Parameters
+    ///  * `other` - The range to intersect with
+    ///
+    /// ## Return Value
+    /// Returns the shared ports, or [`None`] if the ranges do not overlap
+    pub const fn intersection(&self, other: &PortRange) -> Option<PortRange> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        Some(PortRange {
+            start: if self.start > other.start {
+                self.start
+            } else {
+                other.start
+            },
+            end: if self.end < other.end {
+                self.end
+            } else {
+                other.end
+            },
+        })
+    }
+
+    /// Gets an iterator over the ports of this range
+    ///
+    /// ## Return Value
+    /// Returns an iterator over every port in this range, in ascending order
+    pub fn iter(&self) -> RangeInclusive<Port> {
+        self.start..=self.end
+    }
+}
+
+impl IntoIterator for PortRange {
+    type Item = Port;
+    type IntoIter = RangeInclusive<Port>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &PortRange {
+    type Item = Port;
+    type IntoIter = RangeInclusive<Port>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<Port> for PortRange {
+    fn from(port: Port) -> Self {
+        PortRange::single(port)
+    }
+}
+
+impl From<PortRange> for RangeInclusive<Port> {
+    fn from(range: PortRange) -> Self {
+        range.iter()
+    }
+}
+
+impl TryFrom<RangeInclusive<Port>> for PortRange {
+    type Error = InvalidPortRangeError;
+
+    fn try_from(range: RangeInclusive<Port>) -> Result<Self, Self::Error> {
+        PortRange::try_new(*range.start(), *range.end())
+    }
+}
+
+impl FromStr for PortRange {
+    type Err = InvalidPortRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s.split_once('-').unwrap_or((s, s));
+
+        let start = start.parse().map_err(InvalidPortRangeError::InvalidPort)?;
+        let end = end.parse().map_err(InvalidPortRangeError::InvalidPort)?;
+
+        PortRange::try_new(start, end)
+    }
+}
+
+impl std::fmt::Display for PortRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+impl std::fmt::Debug for PortRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
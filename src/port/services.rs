@@ -0,0 +1,212 @@
+//! An offline table of well-known service names and their ports
+//!
+//! The table holds the common entries of the IANA service name registry and never reads
+//! "/etc/services", so lookups behave the same on every system. Applications can add their own
+//! services with [`register`], which take precedence over the built-in entries.
+
+use super::{Port, Protocol};
+use std::sync::RwLock;
+
+/// A service known by name
+type Service = (String, Port, Protocol);
+
+/// The services registered by the application, searched before the well-known services
+static REGISTRY: RwLock<Vec<Service>> = RwLock::new(Vec::new());
+
+/// The well-known services, with the primary name of each port listed first
+const WELL_KNOWN: &[(&str, Port, Protocol)] = &[
+    ("echo", 7, Protocol::TCP),
+    ("echo", 7, Protocol::UDP),
+    ("discard", 9, Protocol::TCP),
+    ("discard", 9, Protocol::UDP),
+    ("daytime", 13, Protocol::TCP),
+    ("daytime", 13, Protocol::UDP),
+    ("ftp-data", 20, Protocol::TCP),
+    ("ftp", 21, Protocol::TCP),
+    ("ssh", 22, Protocol::TCP),
+    ("telnet", 23, Protocol::TCP),
+    ("smtp", 25, Protocol::TCP),
+    ("time", 37, Protocol::TCP),
+    ("time", 37, Protocol::UDP),
+    ("whois", 43, Protocol::TCP),
+    ("tacacs", 49, Protocol::TCP),
+    ("tacacs", 49, Protocol::UDP),
+    ("domain", 53, Protocol::TCP),
+    ("domain", 53, Protocol::UDP),
+    ("bootps", 67, Protocol::UDP),
+    ("bootpc", 68, Protocol::UDP),
+    ("tftp", 69, Protocol::UDP),
+    ("gopher", 70, Protocol::TCP),
+    ("finger", 79, Protocol::TCP),
+    ("http", 80, Protocol::TCP),
+    ("www", 80, Protocol::TCP),
+    ("kerberos", 88, Protocol::TCP),
+    ("kerberos", 88, Protocol::UDP),
+    ("pop3", 110, Protocol::TCP),
+    ("sunrpc", 111, Protocol::TCP),
+    ("sunrpc", 111, Protocol::UDP),
+    ("auth", 113, Protocol::TCP),
+    ("nntp", 119, Protocol::TCP),
+    ("ntp", 123, Protocol::UDP),
+    ("netbios-ns", 137, Protocol::UDP),
+    ("netbios-dgm", 138, Protocol::UDP),
+    ("netbios-ssn", 139, Protocol::TCP),
+    ("imap", 143, Protocol::TCP),
+    ("imap2", 143, Protocol::TCP),
+    ("snmp", 161, Protocol::TCP),
+    ("snmp", 161, Protocol::UDP),
+    ("snmp-trap", 162, Protocol::TCP),
+    ("snmp-trap", 162, Protocol::UDP),
+    ("bgp", 179, Protocol::TCP),
+    ("ldap", 389, Protocol::TCP),
+    ("ldap", 389, Protocol::UDP),
+    ("https", 443, Protocol::TCP),
+    ("https", 443, Protocol::UDP),
+    ("microsoft-ds", 445, Protocol::TCP),
+    ("kpasswd", 464, Protocol::TCP),
+    ("kpasswd", 464, Protocol::UDP),
+    ("isakmp", 500, Protocol::UDP),
+    ("syslog", 514, Protocol::UDP),
+    ("printer", 515, Protocol::TCP),
+    ("dhcpv6-client", 546, Protocol::UDP),
+    ("dhcpv6-server", 547, Protocol::UDP),
+    ("rtsp", 554, Protocol::TCP),
+    ("rtsp", 554, Protocol::UDP),
+    ("submission", 587, Protocol::TCP),
+    ("ipp", 631, Protocol::TCP),
+    ("ldaps", 636, Protocol::TCP),
+    ("ldaps", 636, Protocol::UDP),
+    ("kerberos-adm", 749, Protocol::TCP),
+    ("domain-s", 853, Protocol::TCP),
+    ("domain-s", 853, Protocol::UDP),
+    ("rsync", 873, Protocol::TCP),
+    ("ftps-data", 989, Protocol::TCP),
+    ("ftps", 990, Protocol::TCP),
+    ("imaps", 993, Protocol::TCP),
+    ("pop3s", 995, Protocol::TCP),
+    ("socks", 1080, Protocol::TCP),
+    ("openvpn", 1194, Protocol::TCP),
+    ("openvpn", 1194, Protocol::UDP),
+    ("ms-sql-s", 1433, Protocol::TCP),
+    ("mqtt", 1883, Protocol::TCP),
+    ("radius", 1812, Protocol::TCP),
+    ("radius", 1812, Protocol::UDP),
+    ("radius-acct", 1813, Protocol::TCP),
+    ("radius-acct", 1813, Protocol::UDP),
+    ("nfs", 2049, Protocol::TCP),
+    ("nfs", 2049, Protocol::UDP),
+    ("mysql", 3306, Protocol::TCP),
+    ("ms-wbt-server", 3389, Protocol::TCP),
+    ("svn", 3690, Protocol::TCP),
+    ("sip", 5060, Protocol::TCP),
+    ("sip", 5060, Protocol::UDP),
+    ("sip-tls", 5061, Protocol::TCP),
+    ("sip-tls", 5061, Protocol::UDP),
+    ("xmpp-client", 5222, Protocol::TCP),
+    ("xmpp-server", 5269, Protocol::TCP),
+    ("mdns", 5353, Protocol::UDP),
+    ("postgresql", 5432, Protocol::TCP),
+    ("amqp", 5672, Protocol::TCP),
+    ("x11", 6000, Protocol::TCP),
+    ("redis", 6379, Protocol::TCP),
+    ("ircs-u", 6697, Protocol::TCP),
+    ("http-alt", 8080, Protocol::TCP),
+    ("git", 9418, Protocol::TCP),
+    ("zabbix-agent", 10050, Protocol::TCP),
+    ("memcache", 11211, Protocol::TCP),
+    ("memcache", 11211, Protocol::UDP),
+    ("mongodb", 27017, Protocol::TCP),
+];
+
+/// Gets the port of the service `name`
+///
+/// ## Parameters
+///  * `name` - The name of the service, compared case-insensitively
+///  * `protocol` - The protocol the service runs over
+///
+/// ## Return Value
+/// Returns the port of the service, if it is known
+pub fn port(name: &str, protocol: Protocol) -> Option<Port> {
+    let registry = REGISTRY.read().unwrap_or_else(|error| error.into_inner());
+    registry
+        .iter()
+        .map(|(name, port, protocol)| (name.as_str(), *port, *protocol))
+        .chain(WELL_KNOWN.iter().copied())
+        .find(|(known, _, known_protocol)| {
+            *known_protocol == protocol && known.eq_ignore_ascii_case(name)
+        })
+        .map(|(_, port, _)| port)
+}
+
+/// Gets the name of the service running on `port`
+///
+/// ## Parameters
+///  * `port` - The port of the service
+///  * `protocol` - The protocol the service runs over
+///
+/// ## Return Value
+/// Returns the primary name of the service, if it is known
+pub fn name(port: Port, protocol: Protocol) -> Option<String> {
+    let registry = REGISTRY.read().unwrap_or_else(|error| error.into_inner());
+    registry
+        .iter()
+        .map(|(name, port, protocol)| (name.as_str(), *port, *protocol))
+        .chain(WELL_KNOWN.iter().copied())
+        .find(|(_, known, known_protocol)| *known == port && *known_protocol == protocol)
+        .map(|(name, _, _)| name.to_owned())
+}
+
+/// Parses a port written either as a number or as a service name
+///
+/// ## Parameters
+///  * `port` - The port number or service name
+///  * `protocol` - The protocol the service runs over
+///
+/// ## Return Value
+/// Returns the port, if `port` is a number or a known service
+pub fn parse(port: &str, protocol: Protocol) -> Option<Port> {
+    match port.parse() {
+        Ok(port) => Some(port),
+        Err(_) => self::port(port, protocol),
+    }
+}
+
+/// Registers a service, making it known to every lookup
+///
+/// ## Parameters
+///  * `name` - The name of the service
+///  * `port` - The port the service runs on
+///  * `protocol` - The protocol the service runs over
+///
+/// ## Return Value
+/// Returns the port previously registered for the service by the application, if any
+pub fn register(name: &str, port: Port, protocol: Protocol) -> Option<Port> {
+    let mut registry = REGISTRY.write().unwrap_or_else(|error| error.into_inner());
+    match registry.iter_mut().find(|(known, _, known_protocol)| {
+        *known_protocol == protocol && known.eq_ignore_ascii_case(name)
+    }) {
+        Some(service) => Some(std::mem::replace(&mut service.1, port)),
+        None => {
+            registry.push((name.to_owned(), port, protocol));
+            None
+        }
+    }
+}
+
+/// Removes a service registered by the application
+///
+/// The well-known services can not be removed.
+///
+/// ## Parameters
+///  * `name` - The name of the service
+///  * `protocol` - The protocol the service runs over
+///
+/// ## Return Value
+/// Returns the port which was registered for the service, if any
+pub fn unregister(name: &str, protocol: Protocol) -> Option<Port> {
+    let mut registry = REGISTRY.write().unwrap_or_else(|error| error.into_inner());
+    let index = registry.iter().position(|(known, _, known_protocol)| {
+        *known_protocol == protocol && known.eq_ignore_ascii_case(name)
+    })?;
+    Some(registry.remove(index).1)
+}
@@ -0,0 +1,195 @@
+use super::{InvalidPortRangeError, Port, PortRange};
+use std::str::FromStr;
+
+/// A set of ports written as a comma-separated list of ports and ranges, such as
+/// "22,80,443,8000-8100"
+///
+/// The ports are kept as sorted ranges, with overlapping and adjacent ranges merged, so a set is
+/// always displayed in its most compact form.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct PortSet {
+    /// The sorted ranges, none of which overlap or touch
+    pub(super) ranges: Vec<PortRange>,
+}
+
+impl PortSet {
+    /// Creates a new, empty [`PortSet`]
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PortSet`]
+    pub const fn new() -> Self {
+        PortSet { ranges: Vec::new() }
+    }
+
+    /// Adds ports to this set
+    ///
+    /// ## Parameters
+    ///  * `ports` - The port or range of ports to add
+    pub fn insert<R: Into<PortRange>>(&mut self, ports: R) {
+        let ports = ports.into();
+        let (mut start, mut end) = (ports.start(), ports.end());
+
+        // Ranges touching the new one are merged into it
+        self.ranges.retain(|range| {
+            let touching =
+                range.start() as u32 <= end as u32 + 1 && start as u32 <= range.end() as u32 + 1;
+            if touching {
+                start = start.min(range.start());
+                end = end.max(range.end());
+            }
+            !touching
+        });
+
+        let index = self.ranges.partition_point(|range| range.start() < start);
+        self.ranges.insert(index, PortRange::new(start, end));
+    }
+
+    /// Checks if this set holds `port`
+    ///
+    /// ## Parameters
+    ///  * `port` - The port to check
+    ///
+    /// ## Return Value
+    /// Returns true if `port` is in this set
+    pub fn contains(&self, port: Port) -> bool {
+        let index = self.ranges.partition_point(|range| range.end() < port);
+        self.ranges
+            .get(index)
+            .map(|range| range.contains(port))
+            .unwrap_or(false)
+    }
+
+    /// Gets the number of ports in this set
+    ///
+    /// ## Return Value
+    /// Returns the number of ports in this set
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(PortRange::len).sum()
+    }
+
+    /// Checks if this set holds no ports
+    ///
+    /// ## Return Value
+    /// Returns true if this set is empty
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Gets the ranges making up this set
+    ///
+    /// ## Return Value
+    /// Returns the sorted, non-overlapping ranges of this set
+    pub fn ranges(&self) -> &[PortRange] {
+        &self.ranges
+    }
+
+    /// Gets an iterator over the ports of this set
+    ///
+    /// ## Return Value
+    /// Returns an iterator over every port in this set, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = Port> + '_ {
+        self.ranges.iter().flat_map(PortRange::iter)
+    }
+
+    /// Gets the ports in either this set or `other`
+    ///
+    /// ## Parameters
+    ///  * `other` - The set to combine with
+    ///
+    /// ## Return Value
+    /// Returns the union of the two sets
+    pub fn union(&self, other: &PortSet) -> PortSet {
+        let mut union = self.clone();
+        union.extend(other.ranges.iter().copied());
+        union
+    }
+
+    /// Gets the ports in both this set and `other`
+    ///
+    /// ## Parameters
+    ///  * `other` - The set to intersect with
+    ///
+    /// ## Return Value
+    /// Returns the intersection of the two sets
+    pub fn intersection(&self, other: &PortSet) -> PortSet {
+        let mut ranges = Vec::new();
+
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if let Some(range) = x.intersection(y) {
+                ranges.push(range);
+            }
+
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        PortSet { ranges }
+    }
+}
+
+impl From<PortRange> for PortSet {
+    fn from(range: PortRange) -> Self {
+        PortSet {
+            ranges: vec![range],
+        }
+    }
+}
+
+impl<R: Into<PortRange>> FromIterator<R> for PortSet {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = PortSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<R: Into<PortRange>> Extend<R> for PortSet {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for ports in iter {
+            self.insert(ports);
+        }
+    }
+}
+
+impl FromStr for PortSet {
+    type Err = InvalidPortRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = PortSet::new();
+        if s.trim().is_empty() {
+            return Ok(set);
+        }
+
+        for item in s.split(',') {
+            set.insert(item.trim().parse::<PortRange>()?);
+        }
+
+        Ok(set)
+    }
+}
+
+impl std::fmt::Display for PortSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            range.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for PortSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::ErrorKind;
+
+/// An error while encoding or decoding Punycode
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidPunycodeError {
+    /// A character which cannot appear in the encoded label
+    InvalidCharacter(char),
+
+    /// The label ends in the middle of an encoded integer
+    Truncated,
+
+    /// The decoded value is not a valid non-ASCII code point
+    InvalidCodePoint(u32),
+
+    /// An intermediate value does not fit in 32 bits
+    Overflow,
+}
+
+impl InvalidPunycodeError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidPunycodeError::InvalidCharacter(_) => ErrorKind::InvalidCharacter,
+            InvalidPunycodeError::Truncated => ErrorKind::Truncated,
+            InvalidPunycodeError::InvalidCodePoint(_) | InvalidPunycodeError::Overflow => {
+                ErrorKind::OutOfRange
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidPunycodeError {}
+
+impl std::fmt::Display for InvalidPunycodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidPunycodeError::InvalidCharacter(c) => write!(f, "invalid character {:?}", c),
+            InvalidPunycodeError::Truncated => write!(f, "label ends unexpectedly"),
+            InvalidPunycodeError::InvalidCodePoint(code_point) => {
+                write!(f, "invalid code point U+{:04X}", code_point)
+            }
+            InvalidPunycodeError::Overflow => write!(f, "encoded value overflows"),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidPunycodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,201 @@
+//! Punycode encoding from RFC 3492
+//!
+//! These functions apply the raw algorithm to a single label. No IDNA mapping or validation is
+//! performed and the "xn--" prefix is neither added nor expected.
+
+mod error;
+
+pub use error::InvalidPunycodeError;
+
+/// The number of digit values in the encoding
+const BASE: u32 = 36;
+
+/// The lowest threshold used when writing a variable-length integer
+const T_MIN: u32 = 1;
+
+/// The highest threshold used when writing a variable-length integer
+const T_MAX: u32 = 26;
+
+/// The damping factor used for the first bias adaptation
+const SKEW: u32 = 38;
+
+/// The damping factor used for every other bias adaptation
+const DAMP: u32 = 700;
+
+/// The bias before any code points are encoded
+const INITIAL_BIAS: u32 = 72;
+
+/// The first code point which is not a basic code point
+const INITIAL_N: u32 = 0x80;
+
+/// The separator between the basic code points and the encoded deltas
+const DELIMITER: char = '-';
+
+/// Encodes a label into Punycode
+///
+/// ## Parameters
+///  * `label` - The Unicode label to encode
+///
+/// ## Return Value
+/// Returns the encoded label, without the "xn--" prefix
+pub fn encode_label(label: &str) -> Result<String, InvalidPunycodeError> {
+    let input: Vec<u32> = label.chars().map(|c| c as u32).collect();
+
+    let mut output: String = label.chars().filter(char::is_ascii).collect();
+    let basic = output.len() as u32;
+    if basic > 0 {
+        output.push(DELIMITER);
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic;
+    while (handled as usize) < input.len() {
+        let next = input.iter().copied().filter(|&c| c >= n).min().unwrap();
+
+        delta = (next - n)
+            .checked_mul(handled + 1)
+            .and_then(|increase| delta.checked_add(increase))
+            .ok_or(InvalidPunycodeError::Overflow)?;
+        n = next;
+
+        for &c in &input {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(InvalidPunycodeError::Overflow)?;
+            }
+
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+
+                output.push(digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta += 1;
+        n += 1;
+    }
+
+    Ok(output)
+}
+
+/// Decodes a Punycode label
+///
+/// ## Parameters
+///  * `label` - The encoded label, without the "xn--" prefix
+///
+/// ## Return Value
+/// Returns the decoded Unicode label
+pub fn decode_label(label: &str) -> Result<String, InvalidPunycodeError> {
+    let (basic, encoded) = match label.rfind(DELIMITER) {
+        Some(delimiter) => (&label[..delimiter], &label[delimiter + 1..]),
+        None => ("", label),
+    };
+
+    if let Some(c) = basic.chars().find(|c| !c.is_ascii()) {
+        return Err(InvalidPunycodeError::InvalidCharacter(c));
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut chars = encoded.chars().peekable();
+    while chars.peek().is_some() {
+        let old_i = i;
+        let mut weight: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let c = chars.next().ok_or(InvalidPunycodeError::Truncated)?;
+            let value = digit_value(c).ok_or(InvalidPunycodeError::InvalidCharacter(c))?;
+
+            i = value
+                .checked_mul(weight)
+                .and_then(|increase| i.checked_add(increase))
+                .ok_or(InvalidPunycodeError::Overflow)?;
+
+            let t = threshold(k, bias);
+            if value < t {
+                break;
+            }
+
+            weight = weight
+                .checked_mul(BASE - t)
+                .ok_or(InvalidPunycodeError::Overflow)?;
+            k += BASE;
+        }
+
+        let length = output.len() as u32 + 1;
+        bias = adapt(i - old_i, length, old_i == 0);
+        n = n
+            .checked_add(i / length)
+            .ok_or(InvalidPunycodeError::Overflow)?;
+        i %= length;
+
+        let c = char::from_u32(n)
+            .filter(|c| !c.is_ascii())
+            .ok_or(InvalidPunycodeError::InvalidCodePoint(n))?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+
+    Ok(output.into_iter().collect())
+}
+
+/// Gets the threshold for the digit at position `k` given the current `bias`
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+/// Calculates the bias after a code point is encoded or decoded
+fn adapt(delta: u32, length: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / DAMP } else { delta / 2 };
+    delta += delta / length;
+
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+
+    k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+}
+
+/// Gets the character representing the digit `value`
+fn digit(value: u32) -> char {
+    match value {
+        0..=25 => (b'a' + value as u8) as char,
+        _ => (b'0' + (value - 26) as u8) as char,
+    }
+}
+
+/// Gets the value of the digit `c`
+fn digit_value(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
@@ -0,0 +1,351 @@
+//! Data rate utilities
+
+use crate::ErrorKind;
+use std::{
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+    time::Duration,
+};
+
+/// A data rate, held as a whole number of bits per second
+///
+/// A lowercase 'b' in a unit means bits and an uppercase 'B' means bytes, so "1MBps" is eight
+/// times "1Mbps". Unit prefixes are decimal unless written with an 'i', so "1Kibps" is 1024 bits
+/// per second. A rate without a unit or with only a prefix, such as "10g", is taken as bits per
+/// second.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DataRate {
+    bits_per_second: u64,
+}
+
+/// An error while parsing a data rate
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidDataRateError {
+    /// The number is missing or is not a decimal number
+    InvalidNumber,
+
+    /// The unit is not recognized
+    InvalidUnit,
+
+    /// The rate is too large to hold
+    Overflow,
+}
+
+/// The decimal prefixes used when displaying a rate
+const DISPLAY_PREFIXES: [(u64, &str); 5] = [
+    (1_000_000_000_000_000, "P"),
+    (1_000_000_000_000, "T"),
+    (1_000_000_000, "G"),
+    (1_000_000, "M"),
+    (1_000, "k"),
+];
+
+impl DataRate {
+    /// A rate of zero
+    pub const ZERO: DataRate = DataRate::from_bps(0);
+
+    /// Creates a new [`DataRate`]
+    ///
+    /// ## Parameters
+    ///  * `bits_per_second` - The rate in bits per second
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`DataRate`]
+    pub const fn from_bps(bits_per_second: u64) -> Self {
+        DataRate { bits_per_second }
+    }
+
+    /// Creates a new [`DataRate`] from kilobits per second
+    ///
+    /// ## Parameters
+    ///  * `kbps` - The rate in thousands of bits per second
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`DataRate`]
+    pub const fn from_kbps(kbps: u64) -> Self {
+        DataRate::from_bps(kbps * 1_000)
+    }
+
+    /// Creates a new [`DataRate`] from megabits per second
+    ///
+    /// ## Parameters
+    ///  * `mbps` - The rate in millions of bits per second
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`DataRate`]
+    pub const fn from_mbps(mbps: u64) -> Self {
+        DataRate::from_bps(mbps * 1_000_000)
+    }
+
+    /// Creates a new [`DataRate`] from gigabits per second
+    ///
+    /// ## Parameters
+    ///  * `gbps` - The rate in billions of bits per second
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`DataRate`]
+    pub const fn from_gbps(gbps: u64) -> Self {
+        DataRate::from_bps(gbps * 1_000_000_000)
+    }
+
+    /// Creates a new [`DataRate`] from bytes per second
+    ///
+    /// ## Parameters
+    ///  * `bytes_per_second` - The rate in bytes per second
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`DataRate`]
+    pub const fn from_bytes_per_second(bytes_per_second: u64) -> Self {
+        DataRate::from_bps(bytes_per_second * 8)
+    }
+
+    /// Gets this rate in bits per second
+    ///
+    /// ## Return Value
+    /// Returns the number of bits per second
+    pub const fn bps(&self) -> u64 {
+        self.bits_per_second
+    }
+
+    /// Gets this rate in bytes per second
+    ///
+    /// ## Return Value
+    /// Returns the number of whole bytes per second, rounded down
+    pub const fn bytes_per_second(&self) -> u64 {
+        self.bits_per_second / 8
+    }
+
+    /// Gets the time it takes to send `bytes` at this rate
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The number of bytes to send
+    ///
+    /// ## Return Value
+    /// Returns the time to send `bytes`, or [`None`] if this rate is zero
+    pub fn transmit_time(&self, bytes: u64) -> Option<Duration> {
+        if self.bits_per_second == 0 {
+            return None;
+        }
+
+        let nanos = bytes as u128 * 8 * 1_000_000_000 / self.bits_per_second as u128;
+        Some(Duration::from_nanos(nanos.try_into().unwrap_or(u64::MAX)))
+    }
+
+    /// Adds `other` to this rate
+    ///
+    /// ## Parameters
+    ///  * `other` - The rate to add
+    ///
+    /// ## Return Value
+    /// Returns the sum, or [`None`] if it overflows
+    pub const fn checked_add(self, other: DataRate) -> Option<Self> {
+        match self.bits_per_second.checked_add(other.bits_per_second) {
+            Some(bits_per_second) => Some(DataRate::from_bps(bits_per_second)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `other` from this rate
+    ///
+    /// ## Parameters
+    ///  * `other` - The rate to subtract
+    ///
+    /// ## Return Value
+    /// Returns the difference, or [`None`] if `other` is larger than this rate
+    pub const fn checked_sub(self, other: DataRate) -> Option<Self> {
+        match self.bits_per_second.checked_sub(other.bits_per_second) {
+            Some(bits_per_second) => Some(DataRate::from_bps(bits_per_second)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `other` from this rate, stopping at zero
+    ///
+    /// ## Parameters
+    ///  * `other` - The rate to subtract
+    ///
+    /// ## Return Value
+    /// Returns the difference, or zero if `other` is larger than this rate
+    pub const fn saturating_sub(self, other: DataRate) -> Self {
+        DataRate::from_bps(self.bits_per_second.saturating_sub(other.bits_per_second))
+    }
+}
+
+impl Add for DataRate {
+    type Output = DataRate;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        DataRate::from_bps(self.bits_per_second + rhs.bits_per_second)
+    }
+}
+
+impl Sub for DataRate {
+    type Output = DataRate;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        DataRate::from_bps(self.bits_per_second - rhs.bits_per_second)
+    }
+}
+
+impl Mul<u64> for DataRate {
+    type Output = DataRate;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        DataRate::from_bps(self.bits_per_second * rhs)
+    }
+}
+
+impl Div<u64> for DataRate {
+    type Output = DataRate;
+
+    fn div(self, rhs: u64) -> Self::Output {
+        DataRate::from_bps(self.bits_per_second / rhs)
+    }
+}
+
+impl std::iter::Sum for DataRate {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(DataRate::ZERO, Add::add)
+    }
+}
+
+impl FromStr for DataRate {
+    type Err = InvalidDataRateError;
+
+    /// Parses a number and a unit, such as "100Mbps", "1.5 Gbit/s", or "10g"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if whole.is_empty() && fraction.is_empty() || fraction.contains('.') {
+            return Err(InvalidDataRateError::InvalidNumber);
+        }
+
+        let multiplier = parse_unit(unit.trim_start())? as u128;
+
+        let parse = |digits: &str| -> Result<u128, InvalidDataRateError> {
+            if digits.is_empty() {
+                return Ok(0);
+            }
+            digits.parse().map_err(|_| InvalidDataRateError::Overflow)
+        };
+
+        let scale = 10u128
+            .checked_pow(fraction.len() as u32)
+            .ok_or(InvalidDataRateError::Overflow)?;
+        let bits_per_second = parse(whole)?
+            .checked_mul(multiplier)
+            .and_then(|whole| {
+                parse(fraction)
+                    .ok()?
+                    .checked_mul(multiplier)
+                    .map(|fraction| whole + fraction / scale)
+            })
+            .ok_or(InvalidDataRateError::Overflow)?;
+
+        bits_per_second
+            .try_into()
+            .map(DataRate::from_bps)
+            .map_err(|_| InvalidDataRateError::Overflow)
+    }
+}
+
+impl std::fmt::Display for DataRate {
+    /// Writes the rate in bits per second with the largest decimal prefix which keeps a whole
+    /// part, such as "1.5Gbps"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (divisor, prefix) = DISPLAY_PREFIXES
+            .iter()
+            .copied()
+            .find(|(divisor, _)| self.bits_per_second >= *divisor)
+            .unwrap_or((1, ""));
+
+        write!(f, "{}", self.bits_per_second / divisor)?;
+
+        let remainder = self.bits_per_second % divisor;
+        if remainder != 0 {
+            let width = divisor.ilog10() as usize;
+            let fraction = format!("{:0width$}", remainder, width = width);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        write!(f, "{}bps", prefix)
+    }
+}
+
+impl std::fmt::Debug for DataRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl InvalidDataRateError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidDataRateError::InvalidNumber => ErrorKind::InvalidNumber,
+            InvalidDataRateError::InvalidUnit => ErrorKind::Unsupported,
+            InvalidDataRateError::Overflow => ErrorKind::OutOfRange,
+        }
+    }
+}
+
+impl std::error::Error for InvalidDataRateError {}
+
+impl std::fmt::Display for InvalidDataRateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidDataRateError::InvalidNumber => write!(f, "invalid data rate number"),
+            InvalidDataRateError::InvalidUnit => write!(f, "invalid data rate unit"),
+            InvalidDataRateError::Overflow => write!(f, "data rate is too large"),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidDataRateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Parses a unit into the number of bits per second it stands for
+fn parse_unit(unit: &str) -> Result<u64, InvalidDataRateError> {
+    let mut chars = unit.chars();
+    let (prefix, rest) = match chars.next().map(|c| c.to_ascii_lowercase()) {
+        Some(c @ ('k' | 'm' | 'g' | 't' | 'p')) => {
+            let exponent = "kmgtp".find(c).unwrap() as u32 + 1;
+            match chars.as_str().strip_prefix('i') {
+                Some(rest) => (1024u64.pow(exponent), rest),
+                None => (1000u64.pow(exponent), chars.as_str()),
+            }
+        }
+        _ => (1, unit),
+    };
+
+    let per_unit = match rest {
+        "" | "b" | "bps" | "b/s" => 1,
+        "B" | "Bps" | "B/s" => 8,
+        _ => {
+            let word = rest
+                .strip_suffix("/s")
+                .or_else(|| rest.strip_suffix("ps"))
+                .unwrap_or(rest)
+                .to_ascii_lowercase();
+            match word.as_str() {
+                "bit" | "bits" => 1,
+                "byte" | "bytes" => 8,
+                _ => return Err(InvalidDataRateError::InvalidUnit),
+            }
+        }
+    };
+
+    Ok(prefix * per_unit)
+}
@@ -0,0 +1,112 @@
+use serde::{
+    de::{Error, SeqAccess, Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+
+/// Deserializes a value by parsing it from a string
+pub(crate) struct ParseVisitor<T> {
+    expecting: &'static str,
+    _marker: PhantomData<T>,
+}
+
+impl<T> ParseVisitor<T> {
+    /// Creates a new [`ParseVisitor`]
+    pub(crate) fn new(expecting: &'static str) -> Self {
+        ParseVisitor {
+            expecting,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: FromStr> Visitor<'de> for ParseVisitor<T>
+where
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// The most bytes in the compact form of any value
+const MAX_COMPACT_LENGTH: usize = 17;
+
+/// Deserializes a value from its compact binary form
+pub(crate) struct BytesVisitor<T> {
+    expecting: &'static str,
+    from_bytes: fn(&[u8]) -> Option<T>,
+}
+
+impl<T> BytesVisitor<T> {
+    /// Creates a new [`BytesVisitor`]
+    pub(crate) fn new(expecting: &'static str, from_bytes: fn(&[u8]) -> Option<T>) -> Self {
+        BytesVisitor {
+            expecting,
+            from_bytes,
+        }
+    }
+}
+
+impl<'de, T> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        (self.from_bytes)(v).ok_or_else(|| E::invalid_value(Unexpected::Bytes(v), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0; MAX_COMPACT_LENGTH];
+        let mut length = 0;
+        while let Some(byte) = seq.next_element()? {
+            if length == MAX_COMPACT_LENGTH {
+                return Err(A::Error::invalid_length(length + 1, &self));
+            }
+
+            bytes[length] = byte;
+            length += 1;
+        }
+
+        self.visit_bytes(&bytes[..length])
+    }
+}
+
+/// Serializes `value` as a string for human-readable formats and as `bytes` otherwise
+pub(crate) fn serialize_compact<T: Display, S: Serializer>(
+    value: &T,
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.collect_str(value)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserializes a value from a string for human-readable formats and from the bytes accepted by
+/// `from_bytes` otherwise
+pub(crate) fn deserialize_compact<'de, T: FromStr, D: Deserializer<'de>>(
+    deserializer: D,
+    expecting: &'static str,
+    from_bytes: fn(&[u8]) -> Option<T>,
+) -> Result<T, D::Error>
+where
+    T::Err: Display,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(ParseVisitor::new(expecting))
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor::new(expecting, from_bytes))
+    }
+}
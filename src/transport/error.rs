@@ -0,0 +1,94 @@
+use crate::ErrorKind;
+
+/// An error while parsing a UDP header
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidUDPHeaderError {
+    /// The datagram ends before the header or the length does
+    Truncated,
+
+    /// The length is less than the length of the header
+    InvalidLength(u16),
+}
+
+/// An error while parsing or building a TCP header
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidTCPHeaderError {
+    /// The segment ends before the header does
+    Truncated,
+
+    /// The data offset is less than 5 words
+    InvalidDataOffset(u8),
+
+    /// The options are longer than 40 bytes
+    OptionsTooLong(usize),
+}
+
+impl InvalidUDPHeaderError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidUDPHeaderError::Truncated => ErrorKind::Truncated,
+            InvalidUDPHeaderError::InvalidLength(_) => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidUDPHeaderError {}
+
+impl std::fmt::Display for InvalidUDPHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidUDPHeaderError::Truncated => write!(f, "UDP datagram is truncated"),
+            InvalidUDPHeaderError::InvalidLength(length) => {
+                write!(f, "invalid UDP length {}", length)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidUDPHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl InvalidTCPHeaderError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidTCPHeaderError::Truncated => ErrorKind::Truncated,
+            InvalidTCPHeaderError::InvalidDataOffset(_) => ErrorKind::InvalidFormat,
+            InvalidTCPHeaderError::OptionsTooLong(_) => ErrorKind::TooLong,
+        }
+    }
+}
+
+impl std::error::Error for InvalidTCPHeaderError {}
+
+impl std::fmt::Display for InvalidTCPHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidTCPHeaderError::Truncated => write!(f, "TCP segment is truncated"),
+            InvalidTCPHeaderError::InvalidDataOffset(offset) => {
+                write!(f, "invalid TCP data offset {}", offset)
+            }
+            InvalidTCPHeaderError::OptionsTooLong(length) => {
+                write!(f, "TCP options length {} exceeds the maximum of 40", length)
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidTCPHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
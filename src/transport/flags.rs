@@ -0,0 +1,118 @@
+/// The control bits of a TCP header
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TCPFlags(u8);
+
+/// The names of each flag, starting with the least significant bit
+const NAMES: [&str; 8] = ["FIN", "SYN", "RST", "PSH", "ACK", "URG", "ECE", "CWR"];
+
+impl TCPFlags {
+    /// No more data from the sender
+    pub const FIN: TCPFlags = TCPFlags(0x01);
+
+    /// Synchronize sequence numbers
+    pub const SYN: TCPFlags = TCPFlags(0x02);
+
+    /// Reset the connection
+    pub const RST: TCPFlags = TCPFlags(0x04);
+
+    /// Push the data to the application
+    pub const PSH: TCPFlags = TCPFlags(0x08);
+
+    /// The acknowledgment number is significant
+    pub const ACK: TCPFlags = TCPFlags(0x10);
+
+    /// The urgent pointer is significant
+    pub const URG: TCPFlags = TCPFlags(0x20);
+
+    /// Explicit congestion notification echo
+    pub const ECE: TCPFlags = TCPFlags(0x40);
+
+    /// Congestion window reduced
+    pub const CWR: TCPFlags = TCPFlags(0x80);
+
+    /// Creates a new [`TCPFlags`] from the bits carried in a header
+    ///
+    /// ## Parameters
+    ///  * `bits` - The control bits
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`TCPFlags`]
+    pub const fn new(bits: u8) -> Self {
+        TCPFlags(bits)
+    }
+
+    /// Gets the bits carried in a header
+    ///
+    /// ## Return Value
+    /// Returns the control bits
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Checks if every flag of `other` is set
+    ///
+    /// ## Parameters
+    ///  * `other` - The flags to check for
+    ///
+    /// ## Return Value
+    /// Returns true if every flag set in `other` is also set in these flags
+    pub const fn contains(&self, other: TCPFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Checks if no flags are set
+    ///
+    /// ## Return Value
+    /// Returns true if no flags are set
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl std::ops::BitOr for TCPFlags {
+    type Output = TCPFlags;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        TCPFlags(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for TCPFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl std::ops::BitAnd for TCPFlags {
+    type Output = TCPFlags;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        TCPFlags(self.0 & rhs.0)
+    }
+}
+
+impl std::fmt::Display for TCPFlags {
+    /// Writes the names of the set flags separated by '|', such as "SYN|ACK"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for (bit, name) in NAMES.iter().enumerate() {
+            if self.0 & (1 << bit) == 0 {
+                continue;
+            }
+
+            if !first {
+                f.write_str("|")?;
+            }
+            f.write_str(name)?;
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for TCPFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,22 @@
+//! UDP and TCP header utilities
+//!
+//! The checksums of both protocols cover a pseudo-header built from the addresses of the IP
+//! packet, given as a [`PseudoHeader`](crate::checksum::PseudoHeader).
+
+mod error;
+mod flags;
+mod packed;
+mod tcp;
+mod udp;
+
+pub use error::{InvalidTCPHeaderError, InvalidUDPHeaderError};
+pub use flags::TCPFlags;
+pub use packed::{PackedTCPHeader, PackedUDPHeader};
+pub use tcp::TCPHeader;
+pub use udp::UDPHeader;
+
+/// The protocol number of TCP
+pub const TCP_PROTOCOL: u8 = 6;
+
+/// The protocol number of UDP
+pub const UDP_PROTOCOL: u8 = 17;
@@ -0,0 +1,281 @@
+use super::TCPFlags;
+use crate::port::Port;
+
+/// The header of a UDP datagram with the fixed layout it has on the wire
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PackedUDPHeader {
+    source_port: [u8; 2],
+    destination_port: [u8; 2],
+    length: [u8; 2],
+    checksum: [u8; 2],
+}
+
+/// The header of a TCP segment with the fixed layout it has on the wire
+///
+/// Only the first 20 bytes are covered, so any options follow the packed header.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable,
+        zerocopy::Unaligned
+    )
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PackedTCPHeader {
+    source_port: [u8; 2],
+    destination_port: [u8; 2],
+    sequence: [u8; 4],
+    acknowledgment: [u8; 4],
+    data_offset: u8,
+    flags: u8,
+    window: [u8; 2],
+    checksum: [u8; 2],
+    urgent_pointer: [u8; 2],
+}
+
+impl PackedUDPHeader {
+    /// The length of a packed header
+    pub const LENGTH: usize = 8;
+
+    /// Creates a [`PackedUDPHeader`] from its bytes
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The first 8 bytes of the datagram
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedUDPHeader`]
+    pub const fn from_bytes(bytes: [u8; 8]) -> Self {
+        PackedUDPHeader {
+            source_port: [bytes[0], bytes[1]],
+            destination_port: [bytes[2], bytes[3]],
+            length: [bytes[4], bytes[5]],
+            checksum: [bytes[6], bytes[7]],
+        }
+    }
+
+    /// Gets the bytes of this packed header
+    ///
+    /// ## Return Value
+    /// Returns the 8 bytes of the header as they appear on the wire
+    pub const fn to_bytes(self) -> [u8; 8] {
+        [
+            self.source_port[0],
+            self.source_port[1],
+            self.destination_port[0],
+            self.destination_port[1],
+            self.length[0],
+            self.length[1],
+            self.checksum[0],
+            self.checksum[1],
+        ]
+    }
+
+    /// Gets the source port
+    ///
+    /// ## Return Value
+    /// Returns the port the datagram is sent from
+    pub const fn source_port(&self) -> Port {
+        u16::from_be_bytes(self.source_port)
+    }
+
+    /// Gets the destination port
+    ///
+    /// ## Return Value
+    /// Returns the port the datagram is sent to
+    pub const fn destination_port(&self) -> Port {
+        u16::from_be_bytes(self.destination_port)
+    }
+
+    /// Gets the length
+    ///
+    /// ## Return Value
+    /// Returns the length of the header and payload in bytes
+    pub const fn length(&self) -> u16 {
+        u16::from_be_bytes(self.length)
+    }
+
+    /// Gets the checksum
+    ///
+    /// ## Return Value
+    /// Returns the checksum as written, where 0 means no checksum was computed
+    pub const fn checksum(&self) -> u16 {
+        u16::from_be_bytes(self.checksum)
+    }
+}
+
+impl std::fmt::Debug for PackedUDPHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackedUDPHeader")
+            .field("source_port", &self.source_port())
+            .field("destination_port", &self.destination_port())
+            .field("length", &self.length())
+            .field("checksum", &self.checksum())
+            .finish()
+    }
+}
+
+impl PackedTCPHeader {
+    /// The length of a packed header
+    pub const LENGTH: usize = 20;
+
+    /// Creates a [`PackedTCPHeader`] from its bytes
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The first 20 bytes of the segment
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`PackedTCPHeader`]
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        PackedTCPHeader {
+            source_port: [bytes[0], bytes[1]],
+            destination_port: [bytes[2], bytes[3]],
+            sequence: [bytes[4], bytes[5], bytes[6], bytes[7]],
+            acknowledgment: [bytes[8], bytes[9], bytes[10], bytes[11]],
+            data_offset: bytes[12],
+            flags: bytes[13],
+            window: [bytes[14], bytes[15]],
+            checksum: [bytes[16], bytes[17]],
+            urgent_pointer: [bytes[18], bytes[19]],
+        }
+    }
+
+    /// Gets the bytes of this packed header
+    ///
+    /// ## Return Value
+    /// Returns the 20 bytes of the header as they appear on the wire
+    pub const fn to_bytes(self) -> [u8; 20] {
+        [
+            self.source_port[0],
+            self.source_port[1],
+            self.destination_port[0],
+            self.destination_port[1],
+            self.sequence[0],
+            self.sequence[1],
+            self.sequence[2],
+            self.sequence[3],
+            self.acknowledgment[0],
+            self.acknowledgment[1],
+            self.acknowledgment[2],
+            self.acknowledgment[3],
+            self.data_offset,
+            self.flags,
+            self.window[0],
+            self.window[1],
+            self.checksum[0],
+            self.checksum[1],
+            self.urgent_pointer[0],
+            self.urgent_pointer[1],
+        ]
+    }
+
+    /// Gets the source port
+    ///
+    /// ## Return Value
+    /// Returns the port the segment is sent from
+    pub const fn source_port(&self) -> Port {
+        u16::from_be_bytes(self.source_port)
+    }
+
+    /// Gets the destination port
+    ///
+    /// ## Return Value
+    /// Returns the port the segment is sent to
+    pub const fn destination_port(&self) -> Port {
+        u16::from_be_bytes(self.destination_port)
+    }
+
+    /// Gets the sequence number
+    ///
+    /// ## Return Value
+    /// Returns the sequence number
+    pub const fn sequence(&self) -> u32 {
+        u32::from_be_bytes(self.sequence)
+    }
+
+    /// Gets the acknowledgment number
+    ///
+    /// ## Return Value
+    /// Returns the acknowledgment number
+    pub const fn acknowledgment(&self) -> u32 {
+        u32::from_be_bytes(self.acknowledgment)
+    }
+
+    /// Gets the data offset
+    ///
+    /// ## Return Value
+    /// Returns the length of the complete header in 32-bit words
+    pub const fn data_offset(&self) -> u8 {
+        self.data_offset >> 4
+    }
+
+    /// Gets the flags
+    ///
+    /// ## Return Value
+    /// Returns the flags of the segment
+    pub const fn flags(&self) -> TCPFlags {
+        TCPFlags::new(self.flags)
+    }
+
+    /// Gets the window size
+    ///
+    /// ## Return Value
+    /// Returns the window size
+    pub const fn window(&self) -> u16 {
+        u16::from_be_bytes(self.window)
+    }
+
+    /// Gets the checksum
+    ///
+    /// ## Return Value
+    /// Returns the checksum as written
+    pub const fn checksum(&self) -> u16 {
+        u16::from_be_bytes(self.checksum)
+    }
+
+    /// Gets the urgent pointer
+    ///
+    /// ## Return Value
+    /// Returns the urgent pointer
+    pub const fn urgent_pointer(&self) -> u16 {
+        u16::from_be_bytes(self.urgent_pointer)
+    }
+}
+
+impl std::fmt::Debug for PackedTCPHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackedTCPHeader")
+            .field("source_port", &self.source_port())
+            .field("destination_port", &self.destination_port())
+            .field("sequence", &self.sequence())
+            .field("acknowledgment", &self.acknowledgment())
+            .field("data_offset", &self.data_offset())
+            .field("flags", &self.flags())
+            .field("window", &self.window())
+            .field("checksum", &self.checksum())
+            .field("urgent_pointer", &self.urgent_pointer())
+            .finish()
+    }
+}
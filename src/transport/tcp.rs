@@ -0,0 +1,315 @@
+use super::{InvalidTCPHeaderError, TCPFlags, TCP_PROTOCOL};
+use crate::{
+    checksum::{Checksum, PseudoHeader},
+    port::Port,
+};
+
+/// The header of a TCP segment from RFC 9293
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TCPHeader {
+    source_port: Port,
+    destination_port: Port,
+    sequence: u32,
+    acknowledgment: u32,
+    flags: TCPFlags,
+    window: u16,
+    checksum: u16,
+    urgent_pointer: u16,
+    options: [u8; TCPHeader::MAX_OPTIONS_LENGTH],
+    options_length: usize,
+}
+
+impl TCPHeader {
+    /// The length of a header without options
+    pub const MIN_LENGTH: usize = 20;
+
+    /// The length of a header with the most options
+    pub const MAX_LENGTH: usize = 60;
+
+    /// The most bytes of options a header can hold
+    pub const MAX_OPTIONS_LENGTH: usize = TCPHeader::MAX_LENGTH - TCPHeader::MIN_LENGTH;
+
+    /// Creates a new [`TCPHeader`] without options or a checksum
+    ///
+    /// ## Parameters
+    ///  * `source_port` - The port the segment is sent from
+    ///  * `destination_port` - The port the segment is sent to
+    ///  * `sequence` - The sequence number of the first byte of data
+    ///  * `flags` - The control bits
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`TCPHeader`]
+    pub const fn new(
+        source_port: Port,
+        destination_port: Port,
+        sequence: u32,
+        flags: TCPFlags,
+    ) -> Self {
+        TCPHeader {
+            source_port,
+            destination_port,
+            sequence,
+            acknowledgment: 0,
+            flags,
+            window: 0,
+            checksum: 0,
+            urgent_pointer: 0,
+            options: [0; TCPHeader::MAX_OPTIONS_LENGTH],
+            options_length: 0,
+        }
+    }
+
+    /// Sets the acknowledgment number
+    ///
+    /// ## Parameters
+    ///  * `acknowledgment` - The next sequence number expected from the other side
+    ///
+    /// ## Return Value
+    /// Returns the modified [`TCPHeader`]
+    pub const fn with_acknowledgment(mut self, acknowledgment: u32) -> Self {
+        self.acknowledgment = acknowledgment;
+        self
+    }
+
+    /// Sets the receive window
+    ///
+    /// ## Parameters
+    ///  * `window` - The number of bytes the sender is willing to receive
+    ///
+    /// ## Return Value
+    /// Returns the modified [`TCPHeader`]
+    pub const fn with_window(mut self, window: u16) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Sets the urgent pointer
+    ///
+    /// ## Parameters
+    ///  * `urgent_pointer` - The offset from the sequence number to the end of the urgent data
+    ///
+    /// ## Return Value
+    /// Returns the modified [`TCPHeader`]
+    pub const fn with_urgent_pointer(mut self, urgent_pointer: u16) -> Self {
+        self.urgent_pointer = urgent_pointer;
+        self
+    }
+
+    /// Sets the checksum
+    ///
+    /// ## Parameters
+    ///  * `checksum` - The checksum, usually from [`TCPHeader::compute_checksum`]
+    ///
+    /// ## Return Value
+    /// Returns the modified [`TCPHeader`]
+    pub const fn with_checksum(mut self, checksum: u16) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Sets the options, replacing any existing options
+    ///
+    /// The options are padded with zeros, the end of option list, to a multiple of 4 bytes.
+    ///
+    /// ## Parameters
+    ///  * `options` - The encoded options
+    ///
+    /// ## Return Value
+    /// Returns the modified [`TCPHeader`], or an error if `options` is longer than 40 bytes
+    pub fn with_options(mut self, options: &[u8]) -> Result<Self, InvalidTCPHeaderError> {
+        if options.len() > TCPHeader::MAX_OPTIONS_LENGTH {
+            return Err(InvalidTCPHeaderError::OptionsTooLong(options.len()));
+        }
+
+        self.options = [0; TCPHeader::MAX_OPTIONS_LENGTH];
+        self.options[..options.len()].copy_from_slice(options);
+        self.options_length = options.len().div_ceil(4) * 4;
+
+        Ok(self)
+    }
+
+    /// Parses the header at the start of `segment`
+    ///
+    /// ## Parameters
+    ///  * `segment` - The segment, starting at the source port
+    ///
+    /// ## Return Value
+    /// Returns the parsed header and the data following it
+    pub fn parse(segment: &[u8]) -> Result<(Self, &[u8]), InvalidTCPHeaderError> {
+        if segment.len() < TCPHeader::MIN_LENGTH {
+            return Err(InvalidTCPHeaderError::Truncated);
+        }
+
+        let data_offset = segment[12] >> 4;
+        let header_length = data_offset as usize * 4;
+        if header_length < TCPHeader::MIN_LENGTH {
+            return Err(InvalidTCPHeaderError::InvalidDataOffset(data_offset));
+        }
+
+        let options = segment
+            .get(TCPHeader::MIN_LENGTH..header_length)
+            .ok_or(InvalidTCPHeaderError::Truncated)?;
+
+        let mut header = TCPHeader {
+            source_port: u16::from_be_bytes([segment[0], segment[1]]),
+            destination_port: u16::from_be_bytes([segment[2], segment[3]]),
+            sequence: u32::from_be_bytes([segment[4], segment[5], segment[6], segment[7]]),
+            acknowledgment: u32::from_be_bytes([segment[8], segment[9], segment[10], segment[11]]),
+            flags: TCPFlags::new(segment[13]),
+            window: u16::from_be_bytes([segment[14], segment[15]]),
+            checksum: u16::from_be_bytes([segment[16], segment[17]]),
+            urgent_pointer: u16::from_be_bytes([segment[18], segment[19]]),
+            options: [0; TCPHeader::MAX_OPTIONS_LENGTH],
+            options_length: options.len(),
+        };
+        header.options[..options.len()].copy_from_slice(options);
+
+        Ok((header, &segment[header_length..]))
+    }
+
+    /// Encodes this header with the checksum it holds
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded header to
+    pub fn write(&self, output: &mut Vec<u8>) {
+        self.write_with_checksum(output, self.checksum);
+    }
+
+    /// Computes the correct checksum of this header and `payload`
+    ///
+    /// ## Parameters
+    ///  * `pseudo_header` - The addresses of the IP packet carrying the segment
+    ///  * `payload` - The data following this header
+    ///
+    /// ## Return Value
+    /// Returns the checksum to carry in this header
+    pub fn compute_checksum(&self, pseudo_header: &PseudoHeader, payload: &[u8]) -> u16 {
+        let mut header = Vec::with_capacity(self.header_len());
+        self.write_with_checksum(&mut header, 0);
+
+        let mut checksum = Checksum::new();
+        checksum.add_pseudo_header(
+            pseudo_header,
+            TCP_PROTOCOL,
+            (header.len() + payload.len()) as u32,
+        );
+        checksum.add_bytes(&header);
+        checksum.add_bytes(payload);
+        checksum.finish()
+    }
+
+    /// Verifies the checksum carried in this header
+    ///
+    /// ## Parameters
+    ///  * `pseudo_header` - The addresses of the IP packet carrying the segment
+    ///  * `payload` - The data following this header
+    ///
+    /// ## Return Value
+    /// Returns true if the checksum carried in this header is correct
+    pub fn verify_checksum(&self, pseudo_header: &PseudoHeader, payload: &[u8]) -> bool {
+        self.checksum == self.compute_checksum(pseudo_header, payload)
+    }
+
+    /// Gets the data offset
+    ///
+    /// ## Return Value
+    /// Returns the length of this header in 32-bit words
+    pub const fn data_offset(&self) -> u8 {
+        (self.header_len() / 4) as u8
+    }
+
+    /// Gets the length of this header
+    ///
+    /// ## Return Value
+    /// Returns the length of this header in bytes, including options
+    pub const fn header_len(&self) -> usize {
+        TCPHeader::MIN_LENGTH + self.options_length
+    }
+
+    /// Gets the port the segment is sent from
+    ///
+    /// ## Return Value
+    /// Returns the source port
+    pub const fn source_port(&self) -> Port {
+        self.source_port
+    }
+
+    /// Gets the port the segment is sent to
+    ///
+    /// ## Return Value
+    /// Returns the destination port
+    pub const fn destination_port(&self) -> Port {
+        self.destination_port
+    }
+
+    /// Gets the sequence number
+    ///
+    /// ## Return Value
+    /// Returns the sequence number of the first byte of data
+    pub const fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    /// Gets the acknowledgment number
+    ///
+    /// ## Return Value
+    /// Returns the next sequence number expected from the other side
+    pub const fn acknowledgment(&self) -> u32 {
+        self.acknowledgment
+    }
+
+    /// Gets the control bits
+    ///
+    /// ## Return Value
+    /// Returns the control bits
+    pub const fn flags(&self) -> TCPFlags {
+        self.flags
+    }
+
+    /// Gets the receive window
+    ///
+    /// ## Return Value
+    /// Returns the number of bytes the sender is willing to receive
+    pub const fn window(&self) -> u16 {
+        self.window
+    }
+
+    /// Gets the checksum carried in this header
+    ///
+    /// ## Return Value
+    /// Returns the checksum
+    pub const fn checksum(&self) -> u16 {
+        self.checksum
+    }
+
+    /// Gets the urgent pointer
+    ///
+    /// ## Return Value
+    /// Returns the offset from the sequence number to the end of the urgent data
+    pub const fn urgent_pointer(&self) -> u16 {
+        self.urgent_pointer
+    }
+
+    /// Gets the encoded options
+    ///
+    /// ## Return Value
+    /// Returns the options, including any padding
+    pub fn options(&self) -> &[u8] {
+        &self.options[..self.options_length]
+    }
+
+    /// Encodes this header with `checksum` in the checksum field
+    fn write_with_checksum(&self, output: &mut Vec<u8>, checksum: u16) {
+        output.reserve(self.header_len());
+        output.extend_from_slice(&self.source_port.to_be_bytes());
+        output.extend_from_slice(&self.destination_port.to_be_bytes());
+        output.extend_from_slice(&self.sequence.to_be_bytes());
+        output.extend_from_slice(&self.acknowledgment.to_be_bytes());
+        output.push(self.data_offset() << 4);
+        output.push(self.flags.bits());
+        output.extend_from_slice(&self.window.to_be_bytes());
+        output.extend_from_slice(&checksum.to_be_bytes());
+        output.extend_from_slice(&self.urgent_pointer.to_be_bytes());
+        output.extend_from_slice(self.options());
+    }
+}
@@ -0,0 +1,178 @@
+use super::{InvalidUDPHeaderError, UDP_PROTOCOL};
+use crate::{
+    checksum::{Checksum, PseudoHeader},
+    port::Port,
+};
+
+/// The header of a UDP datagram from RFC 768
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UDPHeader {
+    source_port: Port,
+    destination_port: Port,
+    length: u16,
+    checksum: u16,
+}
+
+impl UDPHeader {
+    /// The length of a header
+    pub const LENGTH: usize = 8;
+
+    /// Creates a new [`UDPHeader`] without a checksum
+    ///
+    /// ## Parameters
+    ///  * `source_port` - The port the datagram is sent from
+    ///  * `destination_port` - The port the datagram is sent to
+    ///  * `payload_length` - The length of the data following the header
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`UDPHeader`]
+    pub const fn new(source_port: Port, destination_port: Port, payload_length: u16) -> Self {
+        UDPHeader {
+            source_port,
+            destination_port,
+            length: payload_length.saturating_add(UDPHeader::LENGTH as u16),
+            checksum: 0,
+        }
+    }
+
+    /// Sets the checksum
+    ///
+    /// ## Parameters
+    ///  * `checksum` - The checksum, usually from [`UDPHeader::compute_checksum`]
+    ///
+    /// ## Return Value
+    /// Returns the modified [`UDPHeader`]
+    pub const fn with_checksum(mut self, checksum: u16) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Parses the header at the start of `datagram`
+    ///
+    /// The payload is borrowed from `datagram` and ends where the length says the datagram ends.
+    ///
+    /// ## Parameters
+    ///  * `datagram` - The datagram, starting at the source port
+    ///
+    /// ## Return Value
+    /// Returns the parsed header and the payload following it
+    pub fn parse(datagram: &[u8]) -> Result<(Self, &[u8]), InvalidUDPHeaderError> {
+        if datagram.len() < UDPHeader::LENGTH {
+            return Err(InvalidUDPHeaderError::Truncated);
+        }
+
+        let length = u16::from_be_bytes([datagram[4], datagram[5]]);
+        if (length as usize) < UDPHeader::LENGTH {
+            return Err(InvalidUDPHeaderError::InvalidLength(length));
+        }
+
+        let datagram = datagram
+            .get(..length as usize)
+            .ok_or(InvalidUDPHeaderError::Truncated)?;
+
+        let header = UDPHeader {
+            source_port: u16::from_be_bytes([datagram[0], datagram[1]]),
+            destination_port: u16::from_be_bytes([datagram[2], datagram[3]]),
+            length,
+            checksum: u16::from_be_bytes([datagram[6], datagram[7]]),
+        };
+
+        Ok((header, &datagram[UDPHeader::LENGTH..]))
+    }
+
+    /// Encodes this header with the checksum it holds
+    ///
+    /// ## Parameters
+    ///  * `output` - The buffer to append the encoded header to
+    pub fn write(&self, output: &mut Vec<u8>) {
+        self.write_with_checksum(output, self.checksum);
+    }
+
+    /// Computes the correct checksum of this header and `payload`
+    ///
+    /// A computed checksum of zero is sent as 0xFFFF, since zero means no checksum.
+    ///
+    /// ## Parameters
+    ///  * `pseudo_header` - The addresses of the IP packet carrying the datagram
+    ///  * `payload` - The data following this header
+    ///
+    /// ## Return Value
+    /// Returns the checksum to carry in this header
+    pub fn compute_checksum(&self, pseudo_header: &PseudoHeader, payload: &[u8]) -> u16 {
+        let mut header = Vec::with_capacity(UDPHeader::LENGTH);
+        self.write_with_checksum(&mut header, 0);
+
+        let mut checksum = Checksum::new();
+        checksum.add_pseudo_header(pseudo_header, UDP_PROTOCOL, self.length as u32);
+        checksum.add_bytes(&header);
+        checksum.add_bytes(payload);
+
+        match checksum.finish() {
+            0 => 0xFFFF,
+            checksum => checksum,
+        }
+    }
+
+    /// Verifies the checksum carried in this header
+    ///
+    /// A checksum of zero in an IPv4 datagram means no checksum was computed and is accepted.
+    ///
+    /// ## Parameters
+    ///  * `pseudo_header` - The addresses of the IP packet carrying the datagram
+    ///  * `payload` - The data following this header
+    ///
+    /// ## Return Value
+    /// Returns true if the checksum carried in this header is correct
+    pub fn verify_checksum(&self, pseudo_header: &PseudoHeader, payload: &[u8]) -> bool {
+        (self.checksum == 0 && pseudo_header.is_ipv4())
+            || self.checksum == self.compute_checksum(pseudo_header, payload)
+    }
+
+    /// Gets the port the datagram is sent from
+    ///
+    /// ## Return Value
+    /// Returns the source port
+    pub const fn source_port(&self) -> Port {
+        self.source_port
+    }
+
+    /// Gets the port the datagram is sent to
+    ///
+    /// ## Return Value
+    /// Returns the destination port
+    pub const fn destination_port(&self) -> Port {
+        self.destination_port
+    }
+
+    /// Gets the length of the datagram
+    ///
+    /// ## Return Value
+    /// Returns the length of this header and the payload in bytes
+    pub const fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Gets the length of the payload
+    ///
+    /// ## Return Value
+    /// Returns the length less the length of this header
+    pub const fn payload_len(&self) -> usize {
+        self.length as usize - UDPHeader::LENGTH
+    }
+
+    /// Gets the checksum carried in this header
+    ///
+    /// ## Return Value
+    /// Returns the checksum, which is zero if none was computed
+    pub const fn checksum(&self) -> u16 {
+        self.checksum
+    }
+
+    /// Encodes this header with `checksum` in the checksum field
+    fn write_with_checksum(&self, output: &mut Vec<u8>, checksum: u16) {
+        output.extend_from_slice(&self.source_port.to_be_bytes());
+        output.extend_from_slice(&self.destination_port.to_be_bytes());
+        output.extend_from_slice(&self.length.to_be_bytes());
+        output.extend_from_slice(&checksum.to_be_bytes());
+    }
+}
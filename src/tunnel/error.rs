@@ -0,0 +1,58 @@
+use crate::ErrorKind;
+use std::num::ParseIntError;
+
+/// An error while creating or parsing a tunnel identifier
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidTunnelIDError {
+    /// The identifier is not a decimal or hexadecimal number
+    InvalidNumber(ParseIntError),
+
+    /// The identifier does not fit in 24 bits
+    OutOfRange(u32),
+}
+
+impl InvalidTunnelIDError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidTunnelIDError::InvalidNumber(_) => ErrorKind::InvalidNumber,
+            InvalidTunnelIDError::OutOfRange(_) => ErrorKind::OutOfRange,
+        }
+    }
+}
+
+impl std::error::Error for InvalidTunnelIDError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidTunnelIDError::InvalidNumber(error) => Some(error),
+            InvalidTunnelIDError::OutOfRange(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidTunnelIDError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidTunnelIDError::InvalidNumber(error) => {
+                write!(f, "invalid tunnel identifier - {}", error)
+            }
+            InvalidTunnelIDError::OutOfRange(id) => {
+                write!(
+                    f,
+                    "tunnel identifier {} exceeds the maximum of 16777215",
+                    id
+                )
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidTunnelIDError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
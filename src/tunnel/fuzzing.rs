@@ -0,0 +1,32 @@
+use super::{GREKey, GeneveVNI, VNI};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for VNI {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary().map(VNI::from_bytes)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 3]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for GeneveVNI {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary().map(GeneveVNI::from_bytes)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 3]>::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for GREKey {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary().map(GREKey::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
@@ -0,0 +1,243 @@
+use super::InvalidTunnelIDError;
+use std::str::FromStr;
+
+/// A VXLAN network identifier from RFC 7348, from 0 to 16777215
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VNI(u32);
+
+/// A Geneve virtual network identifier from RFC 8926, from 0 to 16777215
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GeneveVNI(u32);
+
+/// A GRE key from RFC 2890, which may be any 32-bit value
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GREKey(u32);
+
+/// The largest 24-bit identifier
+const MAX_24BIT: u32 = 0xFFFFFF;
+
+impl VNI {
+    /// The largest VXLAN network identifier
+    pub const MAX: u32 = MAX_24BIT;
+
+    /// Creates a new [`VNI`]
+    ///
+    /// ## Parameters
+    ///  * `id` - The numeric identifier
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`VNI`], or an error if `id` does not fit in 24 bits
+    pub const fn new(id: u32) -> Result<Self, InvalidTunnelIDError> {
+        if id > VNI::MAX {
+            Err(InvalidTunnelIDError::OutOfRange(id))
+        } else {
+            Ok(VNI(id))
+        }
+    }
+
+    /// Gets the numeric identifier
+    ///
+    /// ## Return Value
+    /// Returns the identifier, from 0 to 16777215
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the identifier as it is carried in a header
+    ///
+    /// ## Return Value
+    /// Returns the 3 bytes of the identifier in network order
+    pub const fn to_bytes(&self) -> [u8; 3] {
+        let bytes = self.0.to_be_bytes();
+        [bytes[1], bytes[2], bytes[3]]
+    }
+
+    /// Creates a [`VNI`] from the identifier carried in a header
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The 3 bytes of the identifier in network order
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`VNI`]
+    pub const fn from_bytes(bytes: [u8; 3]) -> Self {
+        VNI(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+}
+
+impl TryFrom<u32> for VNI {
+    type Error = InvalidTunnelIDError;
+
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        VNI::new(id)
+    }
+}
+
+impl From<VNI> for u32 {
+    fn from(id: VNI) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for VNI {
+    type Err = InvalidTunnelIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        VNI::new(parse_number(s)?)
+    }
+}
+
+impl GeneveVNI {
+    /// The largest Geneve virtual network identifier
+    pub const MAX: u32 = MAX_24BIT;
+
+    /// Creates a new [`GeneveVNI`]
+    ///
+    /// ## Parameters
+    ///  * `id` - The numeric identifier
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`GeneveVNI`], or an error if `id` does not fit in 24 bits
+    pub const fn new(id: u32) -> Result<Self, InvalidTunnelIDError> {
+        if id > GeneveVNI::MAX {
+            Err(InvalidTunnelIDError::OutOfRange(id))
+        } else {
+            Ok(GeneveVNI(id))
+        }
+    }
+
+    /// Gets the numeric identifier
+    ///
+    /// ## Return Value
+    /// Returns the identifier, from 0 to 16777215
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the identifier as it is carried in a header
+    ///
+    /// ## Return Value
+    /// Returns the 3 bytes of the identifier in network order
+    pub const fn to_bytes(&self) -> [u8; 3] {
+        let bytes = self.0.to_be_bytes();
+        [bytes[1], bytes[2], bytes[3]]
+    }
+
+    /// Creates a [`GeneveVNI`] from the identifier carried in a header
+    ///
+    /// ## Parameters
+    ///  * `bytes` - The 3 bytes of the identifier in network order
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`GeneveVNI`]
+    pub const fn from_bytes(bytes: [u8; 3]) -> Self {
+        GeneveVNI(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+}
+
+impl TryFrom<u32> for GeneveVNI {
+    type Error = InvalidTunnelIDError;
+
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        GeneveVNI::new(id)
+    }
+}
+
+impl From<GeneveVNI> for u32 {
+    fn from(id: GeneveVNI) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for GeneveVNI {
+    type Err = InvalidTunnelIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GeneveVNI::new(parse_number(s)?)
+    }
+}
+
+impl GREKey {
+    /// Creates a new [`GREKey`]
+    ///
+    /// ## Parameters
+    ///  * `key` - The numeric key
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`GREKey`]
+    pub const fn new(key: u32) -> Self {
+        GREKey(key)
+    }
+
+    /// Gets the numeric key
+    ///
+    /// ## Return Value
+    /// Returns the key
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for GREKey {
+    fn from(key: u32) -> Self {
+        GREKey::new(key)
+    }
+}
+
+impl From<GREKey> for u32 {
+    fn from(key: GREKey) -> Self {
+        key.0
+    }
+}
+
+impl FromStr for GREKey {
+    type Err = InvalidTunnelIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_number(s).map(GREKey)
+    }
+}
+
+impl std::fmt::Display for VNI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for VNI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for GeneveVNI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for GeneveVNI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for GREKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Debug for GREKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Parses a decimal number or a hexadecimal number with a "0x" prefix
+fn parse_number(s: &str) -> Result<u32, InvalidTunnelIDError> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => s.parse(),
+    }
+    .map_err(InvalidTunnelIDError::InvalidNumber)
+}
@@ -0,0 +1,13 @@
+//! Overlay tunnel identifier utilities
+//!
+//! Identifiers are parsed from decimal, such as "5000", or from hexadecimal with a "0x" prefix,
+//! such as "0x1388", and are displayed in decimal.
+
+mod error;
+mod id;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+
+pub use error::InvalidTunnelIDError;
+pub use id::{GREKey, GeneveVNI, VNI};
@@ -0,0 +1,140 @@
+use super::{host::parse_host, parse::parse_authority, InvalidURIError, URIComponent, URI};
+use crate::{endpoint::Endpoint, host::Host};
+use std::str::FromStr;
+
+/// The authority of a URI, "userinfo@host:port", on its own
+///
+/// This is the form of the HTTP/2 ":authority" pseudo-header and of the target of a proxy
+/// CONNECT request. The host is interpreted, so it is always a valid domain name or IP address.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Authority {
+    userinfo: Option<String>,
+    host: Host,
+    port: Option<u16>,
+}
+
+impl Authority {
+    /// Creates a new [`Authority`] without user information
+    ///
+    /// ## Parameters
+    ///  * `host` - The host
+    ///  * `port` - The port, if any
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Authority`]
+    pub const fn new(host: Host, port: Option<u16>) -> Self {
+        Authority {
+            userinfo: None,
+            host,
+            port,
+        }
+    }
+
+    /// Sets the user information
+    ///
+    /// ## Parameters
+    ///  * `userinfo` - The user information, which is written as given so must already be
+    ///    percent-encoded
+    ///
+    /// ## Return Value
+    /// Returns the modified [`Authority`]
+    pub fn with_userinfo(mut self, userinfo: impl Into<String>) -> Self {
+        self.userinfo = Some(userinfo.into());
+        self
+    }
+
+    /// Gets the authority of `uri`
+    ///
+    /// ## Parameters
+    ///  * `uri` - The URI to take the authority from
+    ///
+    /// ## Return Value
+    /// Returns the authority of `uri`, or [`None`] if it has no authority or its host can not be
+    /// interpreted
+    pub fn from_uri(uri: &URI) -> Option<Self> {
+        Some(Authority {
+            userinfo: uri.userinfo().map(str::to_owned),
+            host: uri.host().and_then(parse_host)?,
+            port: uri.port(),
+        })
+    }
+
+    /// Gets the user information
+    ///
+    /// ## Return Value
+    /// Returns the user information as written, if there is any
+    pub fn userinfo(&self) -> Option<&str> {
+        self.userinfo.as_deref()
+    }
+
+    /// Gets the host
+    ///
+    /// ## Return Value
+    /// Returns the interpreted host
+    pub const fn host(&self) -> &Host {
+        &self.host
+    }
+
+    /// Gets the port
+    ///
+    /// ## Return Value
+    /// Returns the port, if one is specified
+    pub const fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Gets the endpoint a connection for this authority should be made to
+    ///
+    /// ## Return Value
+    /// Returns the host and port without the user information
+    pub fn endpoint(&self) -> Endpoint {
+        Endpoint::new(self.host.clone(), self.port)
+    }
+}
+
+impl From<Endpoint> for Authority {
+    fn from(endpoint: Endpoint) -> Self {
+        Authority::new(endpoint.host().clone(), endpoint.port())
+    }
+}
+
+impl FromStr for Authority {
+    type Err = InvalidURIError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = parse_authority(s.as_bytes(), 0, s.len())?;
+
+        let host = &s[components.host.clone()];
+        let host = parse_host(host).ok_or(InvalidURIError::malformed(
+            URIComponent::Host,
+            components.host.start,
+        ))?;
+
+        Ok(Authority {
+            userinfo: components.userinfo.map(|range| s[range].to_owned()),
+            host,
+            port: components.port.and_then(|range| s[range].parse().ok()),
+        })
+    }
+}
+
+impl std::fmt::Display for Authority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(userinfo) = &self.userinfo {
+            write!(f, "{}@", userinfo)?;
+        }
+
+        self.host.fmt(f)?;
+
+        match self.port {
+            Some(port) => write!(f, ":{}", port),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Debug for Authority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,245 @@
+use super::{
+    chars::{find_invalid_scheme, CharClass},
+    form,
+    percent::encode_class_into,
+    serializer::Serializer,
+    InvalidURIError, URIComponent, URI,
+};
+use std::net::Ipv6Addr;
+
+/// Builds a [`URI`] from its components
+///
+/// Every component except the scheme and host may contain arbitrary Unicode, which is encoded as
+/// UTF-8 and percent-encoded where required. Existing percent-encoded triplets are kept as
+/// written.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct URIBuilder {
+    scheme: Option<String>,
+    userinfo: Option<String>,
+    host: Option<String>,
+    port: Option<u16>,
+    path: String,
+    query: Option<String>,
+    fragment: Option<String>,
+}
+
+impl URIBuilder {
+    /// Creates a new [`URIBuilder`] with no components
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`URIBuilder`]
+    pub const fn new() -> Self {
+        URIBuilder {
+            scheme: None,
+            userinfo: None,
+            host: None,
+            port: None,
+            path: String::new(),
+            query: None,
+            fragment: None,
+        }
+    }
+
+    /// Sets the scheme
+    ///
+    /// ## Parameters
+    ///  * `scheme` - The scheme, without the trailing ':'
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn scheme(mut self, scheme: &str) -> Self {
+        self.scheme = Some(scheme.to_owned());
+        self
+    }
+
+    /// Sets the user information, which requires a host
+    ///
+    /// ## Parameters
+    ///  * `userinfo` - The unencoded user information
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn userinfo(mut self, userinfo: &str) -> Self {
+        self.userinfo = Some(userinfo.to_owned());
+        self
+    }
+
+    /// Sets the host
+    ///
+    /// ## Parameters
+    ///  * `host` - The registered name or IP address, with or without the brackets around an IPv6
+    ///    address
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = Some(host.to_owned());
+        self
+    }
+
+    /// Sets the port, which requires a host
+    ///
+    /// ## Parameters
+    ///  * `port` - The port
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the path
+    ///
+    /// ## Parameters
+    ///  * `path` - The unencoded path, with '/' separating its segments
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = path.to_owned();
+        self
+    }
+
+    /// Sets the query, replacing any existing query pairs
+    ///
+    /// ## Parameters
+    ///  * `query` - The unencoded query, without the leading '?'
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn query(mut self, query: &str) -> Self {
+        let mut encoded = String::with_capacity(query.len());
+        encode_class_into(&mut encoded, query, CharClass::Query);
+        self.query = Some(encoded);
+        self
+    }
+
+    /// Appends a name and value pair to the query using the form encoding
+    ///
+    /// ## Parameters
+    ///  * `name` - The unencoded name
+    ///  * `value` - The unencoded value
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn query_pair(mut self, name: &str, value: &str) -> Self {
+        form::append_pair(self.query.get_or_insert_with(String::new), name, value);
+        self
+    }
+
+    /// Sets the fragment
+    ///
+    /// ## Parameters
+    ///  * `fragment` - The unencoded fragment, without the leading '#'
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIBuilder`]
+    pub fn fragment(mut self, fragment: &str) -> Self {
+        self.fragment = Some(fragment.to_owned());
+        self
+    }
+
+    /// Builds the [`URI`] from the components
+    ///
+    /// ## Return Value
+    /// Returns the built [`URI`], or an error if the scheme or host is invalid, or if user
+    /// information or a port is set without a host. The offset of an error is relative to the
+    /// value given for the component.
+    pub fn build(&self) -> Result<URI, InvalidURIError> {
+        let mut serializer = Serializer::new();
+
+        if let Some(scheme) = &self.scheme {
+            if let Some(invalid) = find_invalid_scheme(scheme.as_bytes(), 0, scheme.len()) {
+                return Err(InvalidURIError::at(
+                    scheme.as_bytes(),
+                    URIComponent::Scheme,
+                    invalid,
+                ));
+            }
+
+            serializer.scheme(scheme);
+        }
+
+        match &self.host {
+            Some(host) => {
+                let userinfo = self.userinfo.as_deref().map(|userinfo| {
+                    let mut encoded = String::with_capacity(userinfo.len());
+                    encode_class_into(&mut encoded, userinfo, CharClass::UserInfo);
+                    encoded
+                });
+
+                let port = self.port.map(|port| port.to_string());
+                serializer.authority(userinfo.as_deref(), &encode_host(host)?, port.as_deref());
+            }
+            None if self.userinfo.is_some() => {
+                return Err(InvalidURIError::malformed(URIComponent::UserInfo, 0))
+            }
+            None if self.port.is_some() => {
+                return Err(InvalidURIError::malformed(URIComponent::Port, 0))
+            }
+            None => {}
+        }
+
+        let mut path = String::with_capacity(self.path.len());
+        if self.host.is_some() {
+            // A path following an authority must be empty or absolute
+            if !self.path.is_empty() && !self.path.starts_with('/') {
+                path.push('/');
+            }
+        } else if self.path.starts_with("//") {
+            // Keep the path from being mistaken for an authority
+            path.push_str("/.");
+        } else if self.scheme.is_none()
+            && self
+                .path
+                .split('/')
+                .next()
+                .map(|segment| segment.contains(':'))
+                .unwrap_or(false)
+        {
+            // Keep the first segment from being mistaken for a scheme
+            path.push_str("./");
+        }
+        encode_class_into(&mut path, &self.path, CharClass::Path);
+        serializer.path(&path);
+
+        if let Some(query) = &self.query {
+            serializer.query(query);
+        }
+
+        if let Some(fragment) = &self.fragment {
+            let mut encoded = String::with_capacity(fragment.len());
+            encode_class_into(&mut encoded, fragment, CharClass::Query);
+            serializer.fragment(&encoded);
+        }
+
+        Ok(serializer.finish())
+    }
+}
+
+/// Encodes `host`, rejecting control characters, whitespace, and delimiters
+fn encode_host(host: &str) -> Result<String, InvalidURIError> {
+    let literal = host
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host);
+    if let Ok(address) = literal.parse::<Ipv6Addr>() {
+        return Ok(format!("[{}]", address));
+    }
+
+    if let Some(invalid) = host.bytes().position(|byte| {
+        byte.is_ascii_control()
+            || matches!(byte, b' ' | b'#' | b'/' | b':' | b'?' | b'@' | b'[' | b']')
+    }) {
+        return Err(InvalidURIError::at(
+            host.as_bytes(),
+            URIComponent::Host,
+            invalid,
+        ));
+    }
+
+    let mut encoded = String::with_capacity(host.len());
+    encode_class_into(&mut encoded, host, CharClass::RegName);
+    Ok(encoded)
+}
@@ -0,0 +1,136 @@
+use super::{
+    form, normalize::write_normalized_base, percent::normalize_into, SchemeInfo, SchemeRegistry,
+    URI,
+};
+use std::borrow::Cow;
+
+/// Settings controlling how the cache key of a [`URI`] is generated
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CacheKeyConfig {
+    ignored_parameters: Vec<Cow<'static, str>>,
+    schemes: SchemeRegistry,
+}
+
+/// Common query parameters which only track where a visitor came from
+const TRACKING_PARAMETERS: &[&str] = &[
+    "utm_*", "fbclid", "gclid", "dclid", "msclkid", "mc_cid", "mc_eid", "_ga", "_gl", "igshid",
+    "yclid",
+];
+
+impl CacheKeyConfig {
+    /// Creates a new [`CacheKeyConfig`] which keeps every query parameter and knows only the
+    /// well-known schemes
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`CacheKeyConfig`]
+    pub const fn new() -> Self {
+        CacheKeyConfig {
+            ignored_parameters: Vec::new(),
+            schemes: SchemeRegistry::new(),
+        }
+    }
+
+    /// Drops a query parameter from generated keys
+    ///
+    /// ## Parameters
+    ///  * `name` - The decoded name of the parameter, or a prefix followed by '*' to match every
+    ///    parameter starting with the prefix
+    ///
+    /// ## Return Value
+    /// Returns the updated [`CacheKeyConfig`]
+    pub fn with_ignored_parameter<S: Into<Cow<'static, str>>>(mut self, name: S) -> Self {
+        self.ignored_parameters.push(name.into());
+        self
+    }
+
+    /// Drops the common tracking parameters, such as "utm_*", "fbclid", and "gclid", from
+    /// generated keys
+    ///
+    /// ## Return Value
+    /// Returns the updated [`CacheKeyConfig`]
+    pub fn with_tracking_parameters(mut self) -> Self {
+        self.ignored_parameters
+            .extend(TRACKING_PARAMETERS.iter().map(|name| Cow::Borrowed(*name)));
+        self
+    }
+
+    /// Registers a scheme so its default port is dropped from generated keys
+    ///
+    /// ## Parameters
+    ///  * `name` - The name of the scheme, in any case
+    ///  * `info` - The information about the scheme
+    ///
+    /// ## Return Value
+    /// Returns the updated [`CacheKeyConfig`]
+    pub fn with_scheme(mut self, name: &str, info: SchemeInfo) -> Self {
+        self.schemes.register(name, info);
+        self
+    }
+
+    /// Gets the schemes known to this config
+    ///
+    /// ## Return Value
+    /// Returns the well-known schemes along with any registered ones
+    pub const fn schemes(&self) -> &SchemeRegistry {
+        &self.schemes
+    }
+
+    /// Gets the parameters which are dropped from generated keys
+    ///
+    /// ## Return Value
+    /// Returns the names and prefixes of the ignored parameters
+    pub fn ignored_parameters(&self) -> impl Iterator<Item = &str> {
+        self.ignored_parameters.iter().map(|name| name.as_ref())
+    }
+
+    /// Checks if the query parameter `name` is dropped from generated keys
+    ///
+    /// ## Parameters
+    ///  * `name` - The decoded name of the parameter
+    ///
+    /// ## Return Value
+    /// Returns true if `name` matches one of the ignored parameters
+    pub fn is_ignored(&self, name: &str) -> bool {
+        self.ignored_parameters
+            .iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => name == pattern,
+            })
+    }
+
+    /// Generates the cache key of `uri`
+    pub(crate) fn generate(&self, uri: &URI) -> String {
+        let mut key = String::with_capacity(uri.serialization.len());
+
+        write_normalized_base(&mut key, uri, &self.schemes);
+
+        let mut pairs: Vec<String> = uri
+            .query()
+            .unwrap_or("")
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .filter(|pair| {
+                let name = pair.split_once('=').map(|(name, _)| name).unwrap_or(pair);
+                !self.is_ignored(&form::decode(name))
+            })
+            .map(|pair| {
+                let mut normalized = String::with_capacity(pair.len());
+                normalize_into(&mut normalized, pair);
+                normalized
+            })
+            .collect();
+        pairs.sort_by(|a, b| {
+            let a = a.split_once('=').unwrap_or((a, ""));
+            let b = b.split_once('=').unwrap_or((b, ""));
+            a.cmp(&b)
+        });
+
+        for (i, pair) in pairs.iter().enumerate() {
+            key.push(if i == 0 { '?' } else { '&' });
+            key.push_str(pair);
+        }
+
+        key
+    }
+}
@@ -0,0 +1,214 @@
+//! Character classes from RFC 3986
+
+use super::percent::hex_value;
+
+/// A set of bytes which may appear unencoded in a component
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CharClass {
+    /// Bytes allowed in user information
+    UserInfo,
+
+    /// Bytes allowed in a registered name
+    RegName,
+
+    /// Bytes allowed in a path
+    Path,
+
+    /// Bytes allowed in a query or fragment
+    Query,
+}
+
+impl CharClass {
+    /// Checks if `byte` is a member of this class
+    pub(crate) const fn contains(self, byte: u8) -> bool {
+        match self {
+            CharClass::UserInfo => is_userinfo(byte),
+            CharClass::RegName => is_reg_name(byte),
+            CharClass::Path => is_path(byte),
+            CharClass::Query => is_query(byte),
+        }
+    }
+}
+
+/// Checks if `byte` is in the "unreserved" set
+pub(crate) const fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+/// Checks if `byte` is in the "sub-delims" set
+pub(crate) const fn is_sub_delim(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+    )
+}
+
+/// Checks if `byte` may appear unencoded in user information
+pub(crate) const fn is_userinfo(byte: u8) -> bool {
+    is_unreserved(byte) || is_sub_delim(byte) || byte == b':'
+}
+
+/// Checks if `byte` may appear unencoded in a registered name
+pub(crate) const fn is_reg_name(byte: u8) -> bool {
+    is_unreserved(byte) || is_sub_delim(byte)
+}
+
+/// Checks if `byte` is in the "pchar" set
+pub(crate) const fn is_pchar(byte: u8) -> bool {
+    is_unreserved(byte) || is_sub_delim(byte) || byte == b':' || byte == b'@'
+}
+
+/// Checks if `byte` may appear unencoded in a path
+pub(crate) const fn is_path(byte: u8) -> bool {
+    is_pchar(byte) || byte == b'/'
+}
+
+/// Checks if `byte` may appear unencoded in a query or fragment
+pub(crate) const fn is_query(byte: u8) -> bool {
+    is_pchar(byte) || byte == b'/' || byte == b'?'
+}
+
+/// Checks if the bytes from `start` to `end` form a valid scheme
+pub(crate) const fn is_scheme(bytes: &[u8], start: usize, end: usize) -> bool {
+    find_invalid_scheme(bytes, start, end).is_none()
+}
+
+/// Finds the first byte from `start` to `end` which prevents them from forming a valid scheme
+pub(crate) const fn find_invalid_scheme(bytes: &[u8], start: usize, end: usize) -> Option<usize> {
+    if start == end || !bytes[start].is_ascii_alphabetic() {
+        return Some(start);
+    }
+
+    let mut i = start + 1;
+    while i < end {
+        if !(bytes[i].is_ascii_alphanumeric() || matches!(bytes[i], b'+' | b'-' | b'.')) {
+            return Some(i);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Finds the first byte from `start` to `end` which is neither allowed by `class` nor part of a
+/// valid percent-encoded triplet
+pub(crate) const fn find_invalid(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    class: CharClass,
+) -> Option<usize> {
+    let mut i = start;
+    while i < end {
+        if bytes[i] == b'%' {
+            if i + 2 >= end
+                || hex_value(bytes[i + 1]).is_none()
+                || hex_value(bytes[i + 2]).is_none()
+            {
+                return Some(i);
+            }
+
+            i += 3;
+        } else if class.contains(bytes[i]) {
+            i += 1;
+        } else {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+/// Checks if the bytes from `start` to `end` form an IPv6 address as written in RFC 3986
+pub(crate) const fn is_ipv6(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut i = start;
+    let mut pieces = 0;
+    let mut compressed = false;
+
+    if end - start >= 2 && bytes[i] == b':' && bytes[i + 1] == b':' {
+        compressed = true;
+        i += 2;
+        if i == end {
+            return true;
+        }
+    }
+
+    loop {
+        let piece_start = i;
+        while i < end && bytes[i].is_ascii_hexdigit() {
+            i += 1;
+        }
+
+        // A trailing IPv4 address takes the place of the last two pieces
+        if i < end && bytes[i] == b'.' {
+            if !is_ipv4(bytes, piece_start, end) {
+                return false;
+            }
+
+            pieces += 2;
+            break;
+        }
+
+        if i == piece_start || i - piece_start > 4 {
+            return false;
+        }
+
+        pieces += 1;
+        if i == end {
+            break;
+        }
+
+        if bytes[i] != b':' || i + 1 == end {
+            return false;
+        }
+        i += 1;
+
+        if bytes[i] == b':' {
+            if compressed {
+                return false;
+            }
+
+            compressed = true;
+            i += 1;
+            if i == end {
+                break;
+            }
+        }
+    }
+
+    if compressed {
+        pieces < 8
+    } else {
+        pieces == 8
+    }
+}
+
+/// Checks if the bytes from `start` to `end` form a dotted-decimal IPv4 address
+pub(crate) const fn is_ipv4(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut i = start;
+    let mut octets = 0;
+
+    while octets < 4 {
+        let octet_start = i;
+        let mut value = 0u16;
+        while i < end && bytes[i].is_ascii_digit() && i - octet_start < 3 {
+            value = value * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        let length = i - octet_start;
+        if length == 0 || value > 255 || (length > 1 && bytes[octet_start] == b'0') {
+            return false;
+        }
+
+        octets += 1;
+        if octets < 4 {
+            if i == end || bytes[i] != b'.' {
+                return false;
+            }
+            i += 1;
+        }
+    }
+
+    i == end
+}
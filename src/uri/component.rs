@@ -0,0 +1,38 @@
+/// One of the components which make up a URI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum URIComponent {
+    /// The scheme, such as "https"
+    Scheme,
+
+    /// The user information before the host
+    UserInfo,
+
+    /// The host
+    Host,
+
+    /// The port after the host
+    Port,
+
+    /// The path
+    Path,
+
+    /// The query after the '?'
+    Query,
+
+    /// The fragment after the '#'
+    Fragment,
+}
+
+impl std::fmt::Display for URIComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            URIComponent::Scheme => "scheme",
+            URIComponent::UserInfo => "user information",
+            URIComponent::Host => "host",
+            URIComponent::Port => "port",
+            URIComponent::Path => "path",
+            URIComponent::Query => "query",
+            URIComponent::Fragment => "fragment",
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use std::ops::Range;
+
+/// The locations of each component of a URI within its serialization
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct Components {
+    /// The location of the scheme, not including the trailing ':'
+    pub(crate) scheme: Option<Range<usize>>,
+
+    /// The locations of the authority components, if an authority is present
+    pub(crate) authority: Option<AuthorityComponents>,
+
+    /// The location of the path
+    pub(crate) path: Range<usize>,
+
+    /// The location of the query, not including the leading '?'
+    pub(crate) query: Option<Range<usize>>,
+
+    /// The location of the fragment, not including the leading '#'
+    pub(crate) fragment: Option<Range<usize>>,
+}
+
+/// The locations of each component of an authority within a URI serialization
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct AuthorityComponents {
+    /// The location of the user information, not including the trailing '@'
+    pub(crate) userinfo: Option<Range<usize>>,
+
+    /// The location of the host, including any brackets
+    pub(crate) host: Range<usize>,
+
+    /// The location of the port, not including the leading ':'
+    pub(crate) port: Option<Range<usize>>,
+}
+
+impl Components {
+    /// Gets the scheme from `serialization`
+    pub(crate) fn scheme<'a>(&self, serialization: &'a str) -> Option<&'a str> {
+        self.scheme.clone().map(|range| &serialization[range])
+    }
+
+    /// Gets the complete authority from `serialization`
+    pub(crate) fn authority<'a>(&self, serialization: &'a str) -> Option<&'a str> {
+        self.authority.as_ref().map(|authority| {
+            let start = authority
+                .userinfo
+                .as_ref()
+                .map(|userinfo| userinfo.start)
+                .unwrap_or(authority.host.start);
+            let end = authority
+                .port
+                .as_ref()
+                .map(|port| port.end)
+                .unwrap_or(authority.host.end);
+            &serialization[start..end]
+        })
+    }
+
+    /// Gets the user information from `serialization`
+    pub(crate) fn userinfo<'a>(&self, serialization: &'a str) -> Option<&'a str> {
+        self.authority
+            .as_ref()
+            .and_then(|authority| authority.userinfo.clone())
+            .map(|range| &serialization[range])
+    }
+
+    /// Gets the host from `serialization`
+    pub(crate) fn host<'a>(&self, serialization: &'a str) -> Option<&'a str> {
+        self.authority
+            .as_ref()
+            .map(|authority| &serialization[authority.host.clone()])
+    }
+
+    /// Gets the port text from `serialization`
+    pub(crate) fn port_str<'a>(&self, serialization: &'a str) -> Option<&'a str> {
+        self.authority
+            .as_ref()
+            .and_then(|authority| authority.port.clone())
+            .map(|range| &serialization[range])
+    }
+
+    /// Gets the numeric port from `serialization`, ignoring an empty port
+    pub(crate) fn port(&self, serialization: &str) -> Option<u16> {
+        self.port_str(serialization)
+            .and_then(|port| port.parse().ok())
+    }
+
+    /// Gets the path from `serialization`
+    pub(crate) fn path<'a>(&self, serialization: &'a str) -> &'a str {
+        &serialization[self.path.clone()]
+    }
+
+    /// Gets the query from `serialization`
+    pub(crate) fn query<'a>(&self, serialization: &'a str) -> Option<&'a str> {
+        self.query.clone().map(|range| &serialization[range])
+    }
+
+    /// Gets the fragment from `serialization`
+    pub(crate) fn fragment<'a>(&self, serialization: &'a str) -> Option<&'a str> {
+        self.fragment.clone().map(|range| &serialization[range])
+    }
+}
@@ -0,0 +1,219 @@
+use super::{
+    InvalidURIError, LimitExceededError, ParseMode, SchemeInfo, SchemeRegistry, URIComponent,
+    URILimit, URI,
+};
+
+/// Settings controlling how a [`URI`] is parsed and how large it may be
+///
+/// Every limit is disabled by default, so inputs from untrusted sources should set the limits
+/// appropriate for the service. A URI whose scheme is known to require an authority is rejected
+/// without one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct URIParseConfig {
+    mode: ParseMode,
+    max_length: Option<usize>,
+    max_component_lengths: [Option<usize>; 7],
+    max_query_pairs: Option<usize>,
+    schemes: SchemeRegistry,
+}
+
+const COMPONENTS: [URIComponent; 7] = [
+    URIComponent::Scheme,
+    URIComponent::UserInfo,
+    URIComponent::Host,
+    URIComponent::Port,
+    URIComponent::Path,
+    URIComponent::Query,
+    URIComponent::Fragment,
+];
+
+impl URIParseConfig {
+    /// Creates a new [`URIParseConfig`] with no limits
+    ///
+    /// ## Parameters
+    ///  * `mode` - The set of parsing rules to follow
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`URIParseConfig`]
+    pub const fn new(mode: ParseMode) -> Self {
+        URIParseConfig {
+            mode,
+            max_length: None,
+            max_component_lengths: [None; 7],
+            max_query_pairs: None,
+            schemes: SchemeRegistry::new(),
+        }
+    }
+
+    /// Sets the maximum length of the entire input
+    ///
+    /// ## Parameters
+    ///  * `max_length` - The maximum number of bytes
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIParseConfig`]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the maximum length of a single component
+    ///
+    /// ## Parameters
+    ///  * `component` - The component to limit
+    ///  * `max_length` - The maximum number of bytes
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIParseConfig`]
+    pub const fn with_max_component_length(
+        mut self,
+        component: URIComponent,
+        max_length: usize,
+    ) -> Self {
+        self.max_component_lengths[component as usize] = Some(max_length);
+        self
+    }
+
+    /// Sets the maximum number of '&' separated pairs in the query
+    ///
+    /// ## Parameters
+    ///  * `max_query_pairs` - The maximum number of pairs
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIParseConfig`]
+    pub const fn with_max_query_pairs(mut self, max_query_pairs: usize) -> Self {
+        self.max_query_pairs = Some(max_query_pairs);
+        self
+    }
+
+    /// Registers information about a scheme for URIs parsed with this config
+    ///
+    /// ## Parameters
+    ///  * `name` - The name of the scheme, in any case
+    ///  * `info` - The information to register
+    ///
+    /// ## Return Value
+    /// Returns the updated [`URIParseConfig`]
+    pub fn with_scheme(mut self, name: &str, info: SchemeInfo) -> Self {
+        self.schemes.register(name, info);
+        self
+    }
+
+    /// Gets the set of parsing rules to follow
+    ///
+    /// ## Return Value
+    /// Returns the parsing mode
+    pub const fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Gets the maximum length of the entire input
+    ///
+    /// ## Return Value
+    /// Returns the maximum number of bytes, if limited
+    pub const fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Gets the maximum length of `component`
+    ///
+    /// ## Parameters
+    ///  * `component` - The component to get the limit of
+    ///
+    /// ## Return Value
+    /// Returns the maximum number of bytes, if limited
+    pub const fn max_component_length(&self, component: URIComponent) -> Option<usize> {
+        self.max_component_lengths[component as usize]
+    }
+
+    /// Gets the maximum number of pairs in the query
+    ///
+    /// ## Return Value
+    /// Returns the maximum number of pairs, if limited
+    pub const fn max_query_pairs(&self) -> Option<usize> {
+        self.max_query_pairs
+    }
+
+    /// Gets the schemes known to this config
+    ///
+    /// ## Return Value
+    /// Returns the well-known schemes along with any registered ones
+    pub const fn schemes(&self) -> &SchemeRegistry {
+        &self.schemes
+    }
+
+    /// Sets the set of parsing rules to follow
+    ///
+    /// ## Parameters
+    ///  * `mode` - The new parsing mode
+    pub fn set_mode(&mut self, mode: ParseMode) {
+        self.mode = mode;
+    }
+
+    /// Checks the length of the raw input before any parsing takes place
+    pub(crate) fn check_length(&self, input: &str) -> Result<(), LimitExceededError> {
+        check(URILimit::Length, self.max_length, input.len())
+    }
+
+    /// Checks that a parsed URI has an authority if its scheme requires one
+    pub(crate) fn check_authority(&self, uri: &URI) -> Result<(), InvalidURIError> {
+        let scheme = match uri.scheme() {
+            Some(scheme) => scheme,
+            None => return Ok(()),
+        };
+
+        let required = self
+            .schemes
+            .lookup(scheme)
+            .map(|info| info.is_authority_required())
+            .unwrap_or(false);
+        if required && uri.authority().is_none() {
+            return Err(InvalidURIError::malformed(
+                URIComponent::Host,
+                scheme.len() + 1,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the components of a parsed URI against the limits
+    pub(crate) fn check_components(&self, uri: &URI) -> Result<(), LimitExceededError> {
+        for component in COMPONENTS {
+            let length = match component {
+                URIComponent::Scheme => uri.scheme(),
+                URIComponent::UserInfo => uri.userinfo(),
+                URIComponent::Host => uri.host(),
+                URIComponent::Port => uri.components.port_str(&uri.serialization),
+                URIComponent::Path => Some(uri.path()),
+                URIComponent::Query => uri.query(),
+                URIComponent::Fragment => uri.fragment(),
+            }
+            .map(str::len)
+            .unwrap_or(0);
+
+            check(
+                URILimit::ComponentLength(component),
+                self.max_component_length(component),
+                length,
+            )?;
+        }
+
+        let pairs = uri
+            .query()
+            .map(|query| query.split('&').filter(|pair| !pair.is_empty()).count())
+            .unwrap_or(0);
+        check(URILimit::QueryPairs, self.max_query_pairs, pairs)
+    }
+}
+
+fn check(limit: URILimit, maximum: Option<usize>, actual: usize) -> Result<(), LimitExceededError> {
+    match maximum {
+        Some(maximum) if actual > maximum => Err(LimitExceededError {
+            limit,
+            maximum,
+            actual,
+        }),
+        _ => Ok(()),
+    }
+}
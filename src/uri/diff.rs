@@ -0,0 +1,198 @@
+use super::{percent::normalize_into, URIComponent, URI};
+use std::collections::BTreeMap;
+
+/// The components which differ between two [`URI`]s
+///
+/// The scheme and host are compared case-insensitively, ports are compared after applying the
+/// default port of the scheme, percent-encoding is normalized, and the query is compared as
+/// decoded name and value pairs without regard to order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct URIDiff {
+    components: Vec<URIComponent>,
+    added_query_keys: Vec<String>,
+    removed_query_keys: Vec<String>,
+    changed_query_keys: Vec<String>,
+}
+
+impl URIDiff {
+    /// Compares `old` against `new`
+    pub(crate) fn new(old: &URI, new: &URI) -> Self {
+        let mut diff = URIDiff::default();
+
+        let lowercase = |value: Option<&str>| value.map(str::to_ascii_lowercase);
+        let normalize = |value: Option<&str>| {
+            value.map(|value| {
+                let mut output = String::with_capacity(value.len());
+                normalize_into(&mut output, value);
+                output
+            })
+        };
+
+        diff.check(
+            URIComponent::Scheme,
+            lowercase(old.scheme()) != lowercase(new.scheme()),
+        );
+        diff.check(
+            URIComponent::UserInfo,
+            normalize(old.userinfo()) != normalize(new.userinfo()),
+        );
+        diff.check(
+            URIComponent::Host,
+            normalize(lowercase(old.host()).as_deref())
+                != normalize(lowercase(new.host()).as_deref()),
+        );
+        diff.check(
+            URIComponent::Port,
+            old.port_or_default() != new.port_or_default(),
+        );
+        diff.check(
+            URIComponent::Path,
+            normalize(Some(old.path())) != normalize(Some(new.path())),
+        );
+
+        let old_pairs = query_map(old);
+        let new_pairs = query_map(new);
+        for (name, values) in &old_pairs {
+            match new_pairs.get(name) {
+                None => diff.removed_query_keys.push(name.clone()),
+                Some(new_values) if new_values != values => {
+                    diff.changed_query_keys.push(name.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        diff.added_query_keys.extend(
+            new_pairs
+                .keys()
+                .filter(|name| !old_pairs.contains_key(*name))
+                .cloned(),
+        );
+        diff.check(
+            URIComponent::Query,
+            old.query().is_some() != new.query().is_some() || old_pairs != new_pairs,
+        );
+
+        diff.check(
+            URIComponent::Fragment,
+            normalize(old.fragment()) != normalize(new.fragment()),
+        );
+
+        diff
+    }
+
+    /// Checks if the URIs are equivalent
+    ///
+    /// ## Return Value
+    /// Returns true if no component differs
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Checks if `component` differs
+    ///
+    /// ## Parameters
+    ///  * `component` - The component to check
+    ///
+    /// ## Return Value
+    /// Returns true if `component` differs between the URIs
+    pub fn contains(&self, component: URIComponent) -> bool {
+        self.components.contains(&component)
+    }
+
+    /// Gets the components which differ
+    ///
+    /// ## Return Value
+    /// Returns the differing components in the order they appear in a URI
+    pub fn components(&self) -> &[URIComponent] {
+        &self.components
+    }
+
+    /// Gets the query names which only appear in the new URI
+    ///
+    /// ## Return Value
+    /// Returns the decoded names in sorted order
+    pub fn added_query_keys(&self) -> &[String] {
+        &self.added_query_keys
+    }
+
+    /// Gets the query names which only appear in the old URI
+    ///
+    /// ## Return Value
+    /// Returns the decoded names in sorted order
+    pub fn removed_query_keys(&self) -> &[String] {
+        &self.removed_query_keys
+    }
+
+    /// Gets the query names which appear in both URIs with different values
+    ///
+    /// ## Return Value
+    /// Returns the decoded names in sorted order
+    pub fn changed_query_keys(&self) -> &[String] {
+        &self.changed_query_keys
+    }
+
+    /// Records `component` as differing if `differs` is true
+    fn check(&mut self, component: URIComponent, differs: bool) {
+        if differs {
+            self.components.push(component);
+        }
+    }
+}
+
+/// Collects the sorted values of each query name in `uri`
+fn query_map(uri: &URI) -> BTreeMap<String, Vec<String>> {
+    let mut pairs: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, value) in uri.query_pairs() {
+        pairs
+            .entry(name.into_owned())
+            .or_default()
+            .push(value.into_owned());
+    }
+
+    for values in pairs.values_mut() {
+        values.sort();
+    }
+
+    pairs
+}
+
+impl std::fmt::Display for URIDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.components.is_empty() {
+            return write!(f, "no differences");
+        }
+
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", component)?;
+
+            if *component == URIComponent::Query {
+                let keys = [
+                    ("added", &self.added_query_keys),
+                    ("removed", &self.removed_query_keys),
+                    ("changed", &self.changed_query_keys),
+                ];
+
+                let mut first = true;
+                for (label, names) in keys.iter().filter(|(_, names)| !names.is_empty()) {
+                    write!(
+                        f,
+                        "{}{}: {}",
+                        if first { " (" } else { "; " },
+                        label,
+                        names.join(", ")
+                    )?;
+                    first = false;
+                }
+
+                if !first {
+                    write!(f, ")")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+/// Removes the "." and ".." segments from `path` following RFC 3986 section 5.2.4
+///
+/// A ".." segment never climbs above the root, so the result can not escape the directory the
+/// path starts in.
+///
+/// ## Parameters
+///  * `path` - The path to remove the dot segments from
+///
+/// ## Return Value
+/// Returns `path` with its dot segments resolved
+pub fn remove_dot_segments(path: &str) -> String {
+    let mut output = path.to_owned();
+    remove_dot_segments_in_place(&mut output);
+    output
+}
+
+/// Removes the "." and ".." segments from `path` without allocating
+///
+/// ## Parameters
+///  * `path` - The path to remove the dot segments from, which is replaced by the result
+pub fn remove_dot_segments_in_place(path: &mut String) {
+    let mut bytes = std::mem::take(path).into_bytes();
+    let length = remove_from_bytes(&mut bytes);
+    bytes.truncate(length);
+
+    // Only whole segments are moved, so the bytes are still valid UTF-8
+    *path = String::from_utf8(bytes).unwrap();
+}
+
+/// Removes the dot segments from `buffer`, writing the output over its front
+///
+/// The output never grows past the input which has been read, so both share one buffer.
+///
+/// ## Return Value
+/// Returns the length of the output
+fn remove_from_bytes(buffer: &mut [u8]) -> usize {
+    let mut read = 0;
+    let mut write = 0;
+
+    while read < buffer.len() {
+        let input = &buffer[read..];
+
+        if input.starts_with(b"../") {
+            read += 3;
+        } else if input.starts_with(b"./") || input.starts_with(b"/./") {
+            read += 2;
+        } else if input == b"/." {
+            read += 1;
+            buffer[read] = b'/';
+        } else if input.starts_with(b"/../") || input == b"/.." {
+            read += 2;
+            if read + 1 == buffer.len() {
+                buffer[read] = b'/';
+            } else {
+                read += 1;
+            }
+            write = buffer[..write]
+                .iter()
+                .rposition(|byte| *byte == b'/')
+                .unwrap_or(0);
+        } else if input == b"." || input == b".." {
+            read = buffer.len();
+        } else {
+            let start = usize::from(input[0] == b'/');
+            let end = input[start..]
+                .iter()
+                .position(|byte| *byte == b'/')
+                .map(|end| end + start)
+                .unwrap_or(input.len());
+
+            buffer.copy_within(read..read + end, write);
+            read += end;
+            write += end;
+        }
+    }
+
+    write
+}
@@ -0,0 +1,265 @@
+use super::URIComponent;
+use crate::ErrorKind;
+
+/// An error while parsing a URI
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidURIError {
+    /// A component of the URI is invalid
+    InvalidComponent(InvalidComponentError),
+
+    /// A configured limit was exceeded
+    LimitExceeded(LimitExceededError),
+}
+
+/// A component of a URI is invalid
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidComponentError {
+    /// The component which is invalid
+    pub component: URIComponent,
+
+    /// The byte offset into the input where the problem was found
+    pub offset: usize,
+
+    /// The offending character, if the problem is caused by a single character
+    pub character: Option<char>,
+}
+
+/// A scheme is not valid
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidSchemeError;
+
+/// A limit from a [`URIParseConfig`](super::URIParseConfig) was exceeded
+#[derive(Clone, PartialEq, Eq)]
+pub struct LimitExceededError {
+    /// The limit which was exceeded
+    pub limit: URILimit,
+
+    /// The configured maximum
+    pub maximum: usize,
+
+    /// The size of the input
+    pub actual: usize,
+}
+
+/// One of the limits which may be placed on a URI while parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum URILimit {
+    /// The length of the entire URI
+    Length,
+
+    /// The length of a single component
+    ComponentLength(URIComponent),
+
+    /// The number of pairs in the query
+    QueryPairs,
+}
+
+impl InvalidURIError {
+    /// Creates an error for `component` caused by the character at `offset` in `input`
+    pub(crate) const fn at(input: &[u8], component: URIComponent, offset: usize) -> Self {
+        InvalidURIError::InvalidComponent(InvalidComponentError {
+            component,
+            offset,
+            character: char_at(input, offset),
+        })
+    }
+
+    /// Creates an error for `component` which is not caused by a single character
+    pub(crate) const fn malformed(component: URIComponent, offset: usize) -> Self {
+        InvalidURIError::InvalidComponent(InvalidComponentError {
+            component,
+            offset,
+            character: None,
+        })
+    }
+
+    /// Gets the component which caused this error
+    ///
+    /// ## Return Value
+    /// Returns the invalid component, if this error was caused by one
+    pub const fn component(&self) -> Option<URIComponent> {
+        match self {
+            InvalidURIError::InvalidComponent(error) => Some(error.component),
+            InvalidURIError::LimitExceeded(error) => match error.limit {
+                URILimit::ComponentLength(component) => Some(component),
+                URILimit::Length => None,
+                URILimit::QueryPairs => Some(URIComponent::Query),
+            },
+        }
+    }
+
+    /// Gets the byte offset into the input where this error was found
+    ///
+    /// ## Return Value
+    /// Returns the byte offset, if this error has a location
+    pub const fn offset(&self) -> Option<usize> {
+        match self {
+            InvalidURIError::InvalidComponent(error) => Some(error.offset),
+            InvalidURIError::LimitExceeded(_) => None,
+        }
+    }
+
+    /// Gets the character which caused this error
+    ///
+    /// ## Return Value
+    /// Returns the offending character, if this error was caused by a single character
+    pub const fn character(&self) -> Option<char> {
+        match self {
+            InvalidURIError::InvalidComponent(error) => error.character,
+            InvalidURIError::LimitExceeded(_) => None,
+        }
+    }
+
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            InvalidURIError::InvalidComponent(error) => error.kind(),
+            InvalidURIError::LimitExceeded(error) => error.kind(),
+        }
+    }
+}
+
+impl std::error::Error for InvalidURIError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InvalidURIError::InvalidComponent(error) => Some(error),
+            InvalidURIError::LimitExceeded(error) => Some(error),
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidURIError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidURIError::InvalidComponent(error) => write!(f, "{}", error),
+            InvalidURIError::LimitExceeded(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidURIError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl InvalidComponentError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self.character {
+            Some(_) => ErrorKind::InvalidCharacter,
+            None => ErrorKind::InvalidFormat,
+        }
+    }
+}
+
+impl std::error::Error for InvalidComponentError {}
+
+impl std::fmt::Display for InvalidComponentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid {}", self.component)?;
+
+        match self.character {
+            Some(character) => write!(f, " - unexpected {:?} at byte {}", character, self.offset),
+            None => write!(f, " at byte {}", self.offset),
+        }
+    }
+}
+
+impl std::fmt::Debug for InvalidComponentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl LimitExceededError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        ErrorKind::TooLong
+    }
+}
+
+impl std::error::Error for LimitExceededError {}
+
+impl std::fmt::Display for LimitExceededError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.limit {
+            URILimit::Length => write!(f, "URI length"),
+            URILimit::ComponentLength(component) => write!(f, "{} length", component),
+            URILimit::QueryPairs => write!(f, "query pair count"),
+        }?;
+
+        write!(
+            f,
+            " {} exceeds the maximum of {}",
+            self.actual, self.maximum
+        )
+    }
+}
+
+impl std::fmt::Debug for LimitExceededError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl InvalidSchemeError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        ErrorKind::InvalidFormat
+    }
+}
+
+impl std::error::Error for InvalidSchemeError {}
+
+impl std::fmt::Display for InvalidSchemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid scheme")
+    }
+}
+
+impl std::fmt::Debug for InvalidSchemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// Decodes the UTF-8 character starting at `offset` in `input`
+const fn char_at(input: &[u8], offset: usize) -> Option<char> {
+    if offset >= input.len() {
+        return None;
+    }
+
+    let first = input[offset] as u32;
+    let (length, mut value) = match first {
+        0x00..=0x7F => return char::from_u32(first),
+        0xC0..=0xDF => (2, first & 0x1F),
+        0xE0..=0xEF => (3, first & 0x0F),
+        _ => (4, first & 0x07),
+    };
+
+    let mut i = 1;
+    while i < length {
+        if offset + i >= input.len() {
+            return None;
+        }
+
+        value = (value << 6) | (input[offset + i] as u32 & 0x3F);
+        i += 1;
+    }
+
+    char::from_u32(value)
+}
@@ -0,0 +1,99 @@
+use super::{parse, FormError};
+use serde::{
+    de::{value::MapDeserializer, Deserialize, Deserializer, IntoDeserializer, Visitor},
+    forward_to_deserialize_any,
+};
+use std::borrow::Cow;
+
+/// Deserializes a value from form data
+///
+/// The value must be a structure, a map, or a sequence of pairs whose names and values are
+/// primitives. Primitive values are parsed from the decoded text of each pair.
+///
+/// ## Parameters
+///  * `input` - The encoded form data
+///
+/// ## Return Value
+/// Returns the deserialized value
+pub fn from_str<'de, T: Deserialize<'de>>(input: &'de str) -> Result<T, FormError> {
+    T::deserialize(MapDeserializer::new(
+        parse(input).map(|(name, value)| (Part(name), Part(value))),
+    ))
+}
+
+/// A single decoded name or value
+struct Part<'de>(Cow<'de, str>);
+
+/// Generates deserializer methods which parse the text into a primitive
+macro_rules! deserialize_parsed {
+    ($($method: ident => $visit: ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.0.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(error) => Err(FormError::Custom(format!("invalid value \"{}\" - {}", self.0, error))),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> IntoDeserializer<'de, FormError> for Part<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for Part<'de> {
+    type Error = FormError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Cow::Borrowed(value) => visitor.visit_borrowed_str(value),
+            Cow::Owned(value) => visitor.visit_string(value),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(IntoDeserializer::<'_, FormError>::into_deserializer(self.0))
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
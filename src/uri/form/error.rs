@@ -0,0 +1,56 @@
+use crate::ErrorKind;
+
+/// An error while converting to or from form data
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormError {
+    /// The type cannot be represented as form data
+    Unsupported(&'static str),
+
+    /// A custom error raised while serializing or deserializing
+    Custom(String),
+}
+
+impl FormError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            FormError::Unsupported(_) => ErrorKind::Unsupported,
+            FormError::Custom(_) => ErrorKind::Other,
+        }
+    }
+}
+
+impl std::error::Error for FormError {}
+
+impl serde::ser::Error for FormError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        FormError::Custom(msg.to_string())
+    }
+}
+
+impl serde::de::Error for FormError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        FormError::Custom(msg.to_string())
+    }
+}
+
+impl std::fmt::Display for FormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormError::Unsupported(kind) => {
+                write!(f, "{} cannot be represented as form data", kind)
+            }
+            FormError::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::fmt::Debug for FormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
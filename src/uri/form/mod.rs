@@ -0,0 +1,164 @@
+//! `application/x-www-form-urlencoded` utilities
+//!
+//! With the `serde` feature enabled, structures can be written to and read from form data
+//! directly using [`to_string`] and [`from_str`].
+
+use super::percent::{hex_value, push_encoded};
+use std::borrow::Cow;
+
+mod plus;
+
+#[cfg(feature = "serde")]
+mod de;
+#[cfg(feature = "serde")]
+mod error;
+#[cfg(feature = "serde")]
+mod ser;
+
+pub use plus::PlusDecoding;
+
+#[cfg(feature = "serde")]
+pub use de::from_str;
+#[cfg(feature = "serde")]
+pub use error::FormError;
+#[cfg(feature = "serde")]
+pub use ser::to_string;
+
+/// An iterator over the decoded name and value pairs of form data
+#[derive(Clone)]
+pub struct FormPairs<'a> {
+    remaining: &'a str,
+    plus: PlusDecoding,
+}
+
+/// Parses form data into its name and value pairs
+///
+/// ## Parameters
+///  * `input` - The form data, such as the query of a URI
+///
+/// ## Return Value
+/// Returns an iterator over the decoded pairs
+pub fn parse(input: &str) -> FormPairs<'_> {
+    parse_with(input, PlusDecoding::Space)
+}
+
+/// Parses form data into its name and value pairs, decoding '+' as specified by `plus`
+///
+/// ## Parameters
+///  * `input` - The form data, such as the query of a URI
+///  * `plus` - How a '+' in a name or value is decoded
+///
+/// ## Return Value
+/// Returns an iterator over the decoded pairs
+pub fn parse_with(input: &str, plus: PlusDecoding) -> FormPairs<'_> {
+    FormPairs {
+        remaining: input,
+        plus,
+    }
+}
+
+/// Encodes `name` and `value` and appends them to `output` as a pair
+///
+/// ## Parameters
+///  * `output` - The form data to append to
+///  * `name` - The unencoded name
+///  * `value` - The unencoded value
+pub fn append_pair(output: &mut String, name: &str, value: &str) {
+    if !output.is_empty() {
+        output.push('&');
+    }
+
+    encode_into(output, name);
+    output.push('=');
+    encode_into(output, value);
+}
+
+/// Encodes `input` onto the end of `output` using the form encoding
+///
+/// ## Parameters
+///  * `output` - The string to append to
+///  * `input` - The text to encode
+pub fn encode_into(output: &mut String, input: &str) {
+    for &byte in input.as_bytes() {
+        match byte {
+            b' ' => output.push('+'),
+            b'*' | b'-' | b'.' | b'_' => output.push(byte as char),
+            _ if byte.is_ascii_alphanumeric() => output.push(byte as char),
+            _ => push_encoded(output, byte),
+        }
+    }
+}
+
+/// Decodes a single name or value from form data, treating '+' as a space
+///
+/// ## Parameters
+///  * `input` - The encoded text
+///
+/// ## Return Value
+/// Returns the decoded text, replacing invalid UTF-8 sequences
+pub fn decode(input: &str) -> Cow<'_, str> {
+    decode_with(input, PlusDecoding::Space)
+}
+
+/// Decodes a single name or value from form data, treating '+' as specified by `plus`
+///
+/// ## Parameters
+///  * `input` - The encoded text
+///  * `plus` - How a '+' is decoded
+///
+/// ## Return Value
+/// Returns the decoded text, replacing invalid UTF-8 sequences
+pub fn decode_with(input: &str, plus: PlusDecoding) -> Cow<'_, str> {
+    let plus_is_space = plus == PlusDecoding::Space;
+    if !input.contains('%') && (!plus_is_space || !input.contains('+')) {
+        return Cow::Borrowed(input);
+    }
+
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' if plus_is_space => output.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                match (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                    (Some(high), Some(low)) => {
+                        output.push((high << 4) | low);
+                        i += 2;
+                    }
+                    _ => output.push(b'%'),
+                }
+            }
+            byte => output.push(byte),
+        }
+        i += 1;
+    }
+
+    Cow::Owned(String::from_utf8_lossy(&output).into_owned())
+}
+
+impl<'a> Iterator for FormPairs<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining.is_empty() {
+                return None;
+            }
+
+            let (pair, remaining) = self
+                .remaining
+                .split_once('&')
+                .unwrap_or((self.remaining, ""));
+            self.remaining = remaining;
+
+            if pair.is_empty() {
+                continue;
+            }
+
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            return Some((decode_with(name, self.plus), decode_with(value, self.plus)));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// How a '+' is treated when decoding a name or value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PlusDecoding {
+    /// '+' decodes to a space as required by `application/x-www-form-urlencoded`
+    #[default]
+    Space,
+
+    /// '+' is kept as written, matching plain RFC 3986 percent-decoding
+    ///
+    /// Use this for queries which were not produced by an HTML form, where a literal '+' is data.
+    Literal,
+}
@@ -0,0 +1,467 @@
+use super::{append_pair, FormError};
+use serde::ser::{
+    Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple, Serializer,
+};
+
+/// Serializes `value` as form data
+///
+/// The value must be a structure, a map, or a sequence of pairs whose names and values are
+/// primitives. `None` values are skipped.
+///
+/// ## Parameters
+///  * `value` - The value to serialize
+///
+/// ## Return Value
+/// Returns the encoded form data
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, FormError> {
+    let mut output = String::new();
+    value.serialize(FormSerializer {
+        output: &mut output,
+    })?;
+    Ok(output)
+}
+
+/// Serializes the top-level collection of pairs
+struct FormSerializer<'a> {
+    output: &'a mut String,
+}
+
+/// Serializes the fields of a structure or the entries of a map
+struct PairsSerializer<'a> {
+    output: &'a mut String,
+    key: Option<Option<String>>,
+}
+
+/// Serializes a single pair of a sequence
+struct PairSerializer<'a> {
+    output: &'a mut String,
+}
+
+/// Serializes the name and value within a pair of a sequence
+struct TupleSerializer<'a> {
+    output: &'a mut String,
+    key: Option<Option<String>>,
+}
+
+/// Serializes a primitive name or value into text, or `None` if it should be skipped
+struct PartSerializer;
+
+/// Generates the serializer methods a serializer does not support
+macro_rules! unsupported {
+    ($($method: ident($($arg: ty),*) -> $kind: literal,)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<Self::Ok, Self::Error> {
+                Err(FormError::Unsupported($kind))
+            }
+        )*
+    };
+}
+
+/// Generates the compound serializer methods a serializer does not support
+macro_rules! unsupported_compound {
+    () => {
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Err(FormError::Unsupported("a tuple structure"))
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(FormError::Unsupported("a tuple variant"))
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(FormError::Unsupported("a structure variant"))
+        }
+
+        fn serialize_newtype_variant<T: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(FormError::Unsupported("a newtype variant"))
+        }
+    };
+}
+
+/// Generates the serializer methods for primitives which cannot be a collection of pairs
+macro_rules! unsupported_primitives {
+    () => {
+        unsupported! {
+            serialize_bool(bool) -> "a boolean",
+            serialize_i8(i8) -> "an integer",
+            serialize_i16(i16) -> "an integer",
+            serialize_i32(i32) -> "an integer",
+            serialize_i64(i64) -> "an integer",
+            serialize_u8(u8) -> "an integer",
+            serialize_u16(u16) -> "an integer",
+            serialize_u32(u32) -> "an integer",
+            serialize_u64(u64) -> "an integer",
+            serialize_f32(f32) -> "a float",
+            serialize_f64(f64) -> "a float",
+            serialize_char(char) -> "a character",
+            serialize_str(&str) -> "a string",
+            serialize_bytes(&[u8]) -> "bytes",
+            serialize_unit_struct(&'static str) -> "a unit structure",
+            serialize_unit_variant(&'static str, u32, &'static str) -> "a unit variant",
+        }
+    };
+}
+
+impl<'a> Serializer for FormSerializer<'a> {
+    type Ok = ();
+    type Error = FormError;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Impossible<(), FormError>;
+    type SerializeTupleVariant = Impossible<(), FormError>;
+    type SerializeMap = PairsSerializer<'a>;
+    type SerializeStruct = PairsSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), FormError>;
+
+    unsupported_primitives!();
+    unsupported_compound!();
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(PairsSerializer {
+            output: self.output,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(PairsSerializer {
+            output: self.output,
+            key: None,
+        })
+    }
+}
+
+impl<'a> SerializeSeq for FormSerializer<'a> {
+    type Ok = ();
+    type Error = FormError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(PairSerializer {
+            output: &mut *self.output,
+        })
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a> SerializeTuple for FormSerializer<'a> {
+    type Ok = ();
+    type Error = FormError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a> SerializeMap for PairsSerializer<'a> {
+    type Ok = ();
+    type Error = FormError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(key.serialize(PartSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().flatten();
+        if let (Some(key), Some(value)) = (key, value.serialize(PartSerializer)?) {
+            append_pair(self.output, &key, &value);
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a> SerializeStruct for PairsSerializer<'a> {
+    type Ok = ();
+    type Error = FormError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        if let Some(value) = value.serialize(PartSerializer)? {
+            append_pair(self.output, key, &value);
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a> Serializer for PairSerializer<'a> {
+    type Ok = ();
+    type Error = FormError;
+
+    type SerializeSeq = Impossible<(), FormError>;
+    type SerializeTuple = TupleSerializer<'a>;
+    type SerializeTupleStruct = Impossible<(), FormError>;
+    type SerializeTupleVariant = Impossible<(), FormError>;
+    type SerializeMap = Impossible<(), FormError>;
+    type SerializeStruct = Impossible<(), FormError>;
+    type SerializeStructVariant = Impossible<(), FormError>;
+
+    unsupported_primitives!();
+    unsupported_compound!();
+
+    unsupported! {
+        serialize_none() -> "an optional pair",
+        serialize_unit() -> "a unit",
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+        Err(FormError::Unsupported("an optional pair"))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(FormError::Unsupported("a nested sequence"))
+    }
+
+    fn serialize_tuple(self, length: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        if length != 2 {
+            return Err(FormError::Unsupported("a tuple which is not a pair"));
+        }
+
+        Ok(TupleSerializer {
+            output: self.output,
+            key: None,
+        })
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(FormError::Unsupported("a nested map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(FormError::Unsupported("a nested structure"))
+    }
+}
+
+impl<'a> SerializeTuple for TupleSerializer<'a> {
+    type Ok = ();
+    type Error = FormError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let part = value.serialize(PartSerializer)?;
+
+        match self.key.take() {
+            None => self.key = Some(part),
+            Some(key) => {
+                if let (Some(key), Some(value)) = (key, part) {
+                    append_pair(self.output, &key, &value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+impl Serializer for PartSerializer {
+    type Ok = Option<String>;
+    type Error = FormError;
+
+    type SerializeSeq = Impossible<Option<String>, FormError>;
+    type SerializeTuple = Impossible<Option<String>, FormError>;
+    type SerializeTupleStruct = Impossible<Option<String>, FormError>;
+    type SerializeTupleVariant = Impossible<Option<String>, FormError>;
+    type SerializeMap = Impossible<Option<String>, FormError>;
+    type SerializeStruct = Impossible<Option<String>, FormError>;
+    type SerializeStructVariant = Impossible<Option<String>, FormError>;
+
+    unsupported_compound!();
+
+    unsupported! {
+        serialize_bytes(&[u8]) -> "bytes",
+    }
+
+    fn serialize_bool(self, value: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(value.to_owned()))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(FormError::Unsupported("a nested sequence"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(FormError::Unsupported("a nested tuple"))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(FormError::Unsupported("a nested map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(FormError::Unsupported("a nested structure"))
+    }
+}
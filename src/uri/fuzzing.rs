@@ -0,0 +1,74 @@
+use super::URI;
+use crate::{
+    fuzzing::string,
+    ip::{v4::IPv4Address, v6::IPv6Address},
+};
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+/// Schemes to choose from, including a scheme with every allowed symbol
+const SCHEMES: &[&str] = &["http", "https", "ftp", "file", "urn", "a+b-c.d"];
+
+/// The pieces of a path, query, or fragment, including percent-encodings and dot segments
+const PATH_PIECES: &[&str] = &[
+    "a", "Z", "0", "-", ".", "_", "~", "!", "$", "&", "'", "(", ")", "*", "+", ",", ";", "=", ":",
+    "@", "/", "?", "%2F", "%C3%A9", "..", "/./",
+];
+
+/// The pieces of user information
+const USERINFO_PIECES: &[&str] = &["a", "Z", "0", "-", ".", "~", "!", "$", "=", ":", "%40"];
+
+/// The pieces of a registered host name
+const HOST_PIECES: &[&str] = &["a", "b", "z", "0", "9", "-", ".", "_", "~", "%41", "xn--"];
+
+impl<'a> Arbitrary<'a> for URI {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut uri = String::new();
+
+        if u.arbitrary()? {
+            uri.push_str(u.choose(SCHEMES)?);
+            uri.push(':');
+        }
+
+        let has_authority = u.arbitrary()?;
+        if has_authority {
+            uri.push_str("//");
+
+            if u.ratio(1u8, 4)? {
+                uri.push_str(&string(u, USERINFO_PIECES, 0..=8)?);
+                uri.push('@');
+            }
+
+            match u.int_in_range(0..=2u8)? {
+                0 => uri.push_str(&IPv4Address::from(u.arbitrary::<[u8; 4]>()?).to_string()),
+                1 => uri.push_str(&format!(
+                    "[{}]",
+                    IPv6Address::from(u.arbitrary::<[u8; 16]>()?)
+                )),
+                _ => uri.push_str(&string(u, HOST_PIECES, 0..=16)?),
+            }
+
+            if let Some(port) = u.arbitrary::<Option<u16>>()? {
+                uri.push_str(&format!(":{}", port));
+            }
+        }
+
+        let path = string(u, PATH_PIECES, 0..=32)?;
+        let (path, rest) = path.split_once('?').unwrap_or((&path, ""));
+        if has_authority && !path.is_empty() && !path.starts_with('/') {
+            uri.push('/');
+        }
+        uri.push_str(path);
+
+        if !rest.is_empty() || u.arbitrary()? {
+            uri.push('?');
+            uri.push_str(rest);
+        }
+
+        if u.arbitrary()? {
+            uri.push('#');
+            uri.push_str(&string(u, PATH_PIECES, 0..=16)?);
+        }
+
+        uri.parse().map_err(|_| Error::IncorrectFormat)
+    }
+}
@@ -0,0 +1,54 @@
+use super::{percent::decode_bytes, SchemeRegistry, URI};
+use crate::{endpoint::Endpoint, host::Host};
+
+impl Endpoint {
+    /// Gets the endpoint a connection for `uri` should be made to
+    ///
+    /// ## Parameters
+    ///  * `uri` - The URI to take the host and port from
+    ///
+    /// ## Return Value
+    /// Returns the interpreted host with the explicit port of `uri`, falling back to the default
+    /// port of its scheme, or [`None`] if `uri` has no host or the host can not be interpreted
+    pub fn from_uri(uri: &URI) -> Option<Self> {
+        Endpoint::from_uri_with(uri, &SchemeRegistry::new())
+    }
+
+    /// Gets the endpoint a connection for `uri` should be made to, taking default ports from
+    /// `schemes`
+    ///
+    /// ## Parameters
+    ///  * `uri` - The URI to take the host and port from
+    ///  * `schemes` - The registry to look the default port up in
+    ///
+    /// ## Return Value
+    /// Returns the interpreted host with the explicit port of `uri`, falling back to the default
+    /// port `schemes` has for its scheme, or [`None`] if `uri` has no host or the host can not be
+    /// interpreted
+    pub fn from_uri_with(uri: &URI, schemes: &SchemeRegistry) -> Option<Self> {
+        let host = uri.host().and_then(parse_host)?;
+        Some(Endpoint::new(host, uri.port_or_default_with(schemes)))
+    }
+}
+
+/// Interprets the host text of a URI
+///
+/// ## Parameters
+///  * `host` - The host as written in the URI, including the brackets around an IP literal
+///
+/// ## Return Value
+/// Returns the interpreted host, or [`None`] if it is an unsupported IP literal or does not
+/// decode to a valid domain name
+pub(crate) fn parse_host(host: &str) -> Option<Host> {
+    if host.starts_with('[') {
+        return host.parse().ok().filter(Host::is_ip);
+    }
+
+    if !host.contains('%') {
+        return host.parse().ok();
+    }
+
+    String::from_utf8(decode_bytes(host))
+        .ok()
+        .and_then(|host| host.parse().ok())
+}
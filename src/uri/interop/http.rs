@@ -0,0 +1,51 @@
+use crate::uri::{InvalidURIError, URI};
+use ::http::uri::{InvalidUri, Uri};
+
+impl TryFrom<URI> for Uri {
+    type Error = InvalidUri;
+
+    fn try_from(uri: URI) -> Result<Self, Self::Error> {
+        Uri::try_from(uri.serialization)
+    }
+}
+
+impl TryFrom<&URI> for Uri {
+    type Error = InvalidUri;
+
+    fn try_from(uri: &URI) -> Result<Self, Self::Error> {
+        Uri::try_from(uri.serialization.as_str())
+    }
+}
+
+impl TryFrom<Uri> for URI {
+    type Error = InvalidURIError;
+
+    fn try_from(uri: Uri) -> Result<Self, Self::Error> {
+        URI::try_from(&uri)
+    }
+}
+
+impl TryFrom<&Uri> for URI {
+    type Error = InvalidURIError;
+
+    fn try_from(uri: &Uri) -> Result<Self, Self::Error> {
+        // The display form of an authority-form target, such as "example.com:443", would be
+        // mistaken for a scheme and path, so the components are joined as a URI reference
+        let mut serialization = String::new();
+        if let Some(scheme) = uri.scheme_str() {
+            serialization.push_str(scheme);
+            serialization.push(':');
+        }
+
+        if let Some(authority) = uri.authority() {
+            serialization.push_str("//");
+            serialization.push_str(authority.as_str());
+        }
+
+        if let Some(path_and_query) = uri.path_and_query() {
+            serialization.push_str(path_and_query.as_str());
+        }
+
+        serialization.parse()
+    }
+}
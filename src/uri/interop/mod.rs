@@ -0,0 +1,7 @@
+//! Conversions to and from the URI types of other crates
+
+#[cfg(feature = "http")]
+mod http;
+
+#[cfg(feature = "url")]
+mod url;
@@ -0,0 +1,35 @@
+use crate::uri::{InvalidURIError, ParseMode, URI};
+use ::url::{ParseError, Url};
+
+impl TryFrom<URI> for Url {
+    type Error = ParseError;
+
+    fn try_from(uri: URI) -> Result<Self, Self::Error> {
+        Url::try_from(&uri)
+    }
+}
+
+impl TryFrom<&URI> for Url {
+    type Error = ParseError;
+
+    fn try_from(uri: &URI) -> Result<Self, Self::Error> {
+        Url::parse(&uri.serialization)
+    }
+}
+
+impl TryFrom<Url> for URI {
+    type Error = InvalidURIError;
+
+    fn try_from(url: Url) -> Result<Self, Self::Error> {
+        URI::try_from(&url)
+    }
+}
+
+impl TryFrom<&Url> for URI {
+    type Error = InvalidURIError;
+
+    fn try_from(url: &Url) -> Result<Self, Self::Error> {
+        // Serialized URLs follow the WHATWG rules, which allow characters RFC 3986 does not
+        URI::parse_with_mode(url.as_str(), ParseMode::WHATWG)
+    }
+}
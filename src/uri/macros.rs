@@ -0,0 +1,15 @@
+/// Creates a constant [`URIRef`](crate::uri::URIRef) from a string literal
+///
+/// The literal is parsed following RFC 3986 at compile time, so an invalid URI fails the build
+/// instead of panicking at runtime. Use [`URIRef::to_uri`](crate::uri::URIRef::to_uri) to get
+/// an owned [`URI`](crate::uri::URI).
+#[macro_export]
+macro_rules! uri {
+    ($uri: literal) => {{
+        const URI: $crate::uri::URIRef<'static> = match $crate::uri::URIRef::parse($uri) {
+            Ok(uri) => uri,
+            Err(_) => panic!(concat!("invalid URI \"", $uri, "\"")),
+        };
+        URI
+    }};
+}
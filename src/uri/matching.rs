@@ -0,0 +1,56 @@
+use super::percent::normalize_into;
+
+/// Checks if `path` matches `pattern`
+///
+/// Both are split into segments after normalizing their percent-encoding. A "*" segment in
+/// `pattern` matches exactly one segment and a "**" segment matches any number of segments.
+pub(crate) fn path_matches(path: &str, pattern: &str) -> bool {
+    let path = normalize(path);
+    let pattern = normalize(pattern);
+
+    let path: Vec<&str> = path.split('/').collect();
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    segments_match(&path, &pattern)
+}
+
+/// Checks if `host` matches `pattern`, ignoring case and a trailing root '.'
+///
+/// A leading "*." in `pattern` matches any subdomain, at any depth, of the rest of the pattern
+/// but not the rest of the pattern itself.
+pub(crate) fn host_matches(host: &str, pattern: &str) -> bool {
+    let host = normalize(host).to_ascii_lowercase();
+    let pattern = normalize(pattern).to_ascii_lowercase();
+
+    let host = host.strip_suffix('.').unwrap_or(&host);
+    let pattern = pattern.strip_suffix('.').unwrap_or(&pattern);
+
+    match pattern.strip_prefix("*.") {
+        Some(parent) => host
+            .strip_suffix(parent)
+            .and_then(|subdomain| subdomain.strip_suffix('.'))
+            .map(|subdomain| !subdomain.is_empty())
+            .unwrap_or(false),
+        None => host == pattern,
+    }
+}
+
+/// Matches each segment of `path` against `pattern`
+fn segments_match(path: &[&str], pattern: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| segments_match(&path[skip..], rest)),
+        Some((segment, rest)) => match path.split_first() {
+            Some((first, remaining)) => {
+                (*segment == "*" || segment == first) && segments_match(remaining, rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Normalizes the percent-encoding of `value`
+fn normalize(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    normalize_into(&mut output, value);
+    output
+}
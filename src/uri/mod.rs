@@ -1,4 +1,440 @@
 //! URI utilities
 
+use crate::endpoint::Endpoint;
+use components::Components;
+use std::str::FromStr;
+
+mod authority;
+mod builder;
+mod cache_key;
+mod chars;
+mod component;
+mod components;
+mod config;
+mod diff;
+mod dot_segments;
+mod error;
+mod host;
+mod interop;
+mod macros;
+mod matching;
+mod mode;
+mod normalize;
+mod parse;
+mod relative;
+mod scheme;
+mod serializer;
+mod uri_ref;
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "proptest")]
+mod strategy;
+
+pub mod form;
+pub mod percent;
+pub mod uri_list;
+
+pub use crate::host::Host;
+
+pub use authority::Authority;
+pub use builder::URIBuilder;
+pub use cache_key::CacheKeyConfig;
+pub use component::URIComponent;
+pub use config::URIParseConfig;
+pub use diff::URIDiff;
+pub use dot_segments::{remove_dot_segments, remove_dot_segments_in_place};
+pub use error::{
+    InvalidComponentError, InvalidSchemeError, InvalidURIError, LimitExceededError, URILimit,
+};
+pub use mode::ParseMode;
+pub use scheme::{Scheme, SchemeInfo, SchemeRegistry};
+pub use uri_ref::URIRef;
+
 /// A uniform resource identifier (URI)
-pub struct URI {}
+///
+/// Any URI reference may be represented, so relative references without a scheme are accepted.
+///
+/// A URI parsed following RFC 3986 keeps the input exactly as written, so [`URI::as_str`] and
+/// [`Display`](std::fmt::Display) reproduce it byte-for-byte. Parsing following the WHATWG rules,
+/// building, joining, and other operations which create a new URI produce a new serialization
+/// instead.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct URI {
+    serialization: String,
+    components: Components,
+}
+
+impl URI {
+    /// Parses a [`URI`] following the rules of `mode`
+    ///
+    /// ## Parameters
+    ///  * `input` - The text to parse
+    ///  * `mode` - The set of parsing rules to follow
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`URI`] if `input` is valid under `mode`
+    pub fn parse_with_mode(input: &str, mode: ParseMode) -> Result<Self, InvalidURIError> {
+        parse::parse(input, mode)
+    }
+
+    /// Parses a [`URI`] following the rules and limits of `config`
+    ///
+    /// ## Parameters
+    ///  * `input` - The text to parse
+    ///  * `config` - The parsing rules and limits to follow
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`URI`] if `input` is valid and within the limits of `config`
+    pub fn parse_with_config(
+        input: &str,
+        config: &URIParseConfig,
+    ) -> Result<Self, InvalidURIError> {
+        config
+            .check_length(input)
+            .map_err(InvalidURIError::LimitExceeded)?;
+
+        let uri = parse::parse(input, config.mode())?;
+        config
+            .check_components(&uri)
+            .map_err(InvalidURIError::LimitExceeded)?;
+        config.check_authority(&uri)?;
+
+        Ok(uri)
+    }
+
+    /// Gets the text of this URI
+    ///
+    /// ## Return Value
+    /// Returns the complete serialization of this URI
+    pub fn as_str(&self) -> &str {
+        &self.serialization
+    }
+
+    /// Gets the scheme of this URI
+    ///
+    /// ## Return Value
+    /// Returns the scheme of this URI, if it has one
+    pub fn scheme(&self) -> Option<&str> {
+        self.components.scheme(&self.serialization)
+    }
+
+    /// Gets the well-known information about the scheme of this URI
+    ///
+    /// ## Return Value
+    /// Returns the information about the scheme of this URI, if it has a well-known scheme
+    pub fn scheme_info(&self) -> Option<SchemeInfo> {
+        self.scheme_info_with(&SchemeRegistry::new())
+    }
+
+    /// Gets the information `schemes` has about the scheme of this URI
+    ///
+    /// ## Parameters
+    ///  * `schemes` - The registry to look the scheme up in
+    ///
+    /// ## Return Value
+    /// Returns the information about the scheme of this URI, if it has a scheme known to
+    /// `schemes`
+    pub fn scheme_info_with(&self, schemes: &SchemeRegistry) -> Option<SchemeInfo> {
+        self.scheme().and_then(|scheme| schemes.lookup(scheme))
+    }
+
+    /// Gets the authority of this URI
+    ///
+    /// ## Return Value
+    /// Returns the complete authority of this URI, if it has one
+    pub fn authority(&self) -> Option<&str> {
+        self.components.authority(&self.serialization)
+    }
+
+    /// Gets the user information of this URI
+    ///
+    /// ## Return Value
+    /// Returns the user information of this URI, if it has any
+    pub fn userinfo(&self) -> Option<&str> {
+        self.components.userinfo(&self.serialization)
+    }
+
+    /// Gets the host of this URI
+    ///
+    /// ## Return Value
+    /// Returns the host of this URI, including the brackets around an IP literal, if it has an
+    /// authority
+    pub fn host(&self) -> Option<&str> {
+        self.components.host(&self.serialization)
+    }
+
+    /// Gets the port of this URI
+    ///
+    /// ## Return Value
+    /// Returns the port of this URI, if one is specified
+    pub fn port(&self) -> Option<u16> {
+        self.components.port(&self.serialization)
+    }
+
+    /// Gets the port of this URI, falling back to the default port of its scheme
+    ///
+    /// ## Return Value
+    /// Returns the explicit port of this URI, or the well-known default port of its scheme
+    pub fn port_or_default(&self) -> Option<u16> {
+        self.port_or_default_with(&SchemeRegistry::new())
+    }
+
+    /// Gets the port of this URI, falling back to the default port `schemes` has for its scheme
+    ///
+    /// ## Parameters
+    ///  * `schemes` - The registry to look the default port up in
+    ///
+    /// ## Return Value
+    /// Returns the explicit port of this URI, or the default port of its scheme in `schemes`
+    pub fn port_or_default_with(&self, schemes: &SchemeRegistry) -> Option<u16> {
+        self.port().or_else(|| {
+            self.scheme_info_with(schemes)
+                .and_then(|info| info.default_port())
+        })
+    }
+
+    /// Resolves `reference` against this URI following RFC 3986
+    ///
+    /// ## Parameters
+    ///  * `reference` - The reference to resolve, which may be relative
+    ///
+    /// ## Return Value
+    /// Returns the resolved [`URI`] if `reference` is valid
+    pub fn join(&self, reference: &str) -> Result<URI, InvalidURIError> {
+        let reference = parse::parse(reference, ParseMode::RFC3986)?;
+        Ok(relative::resolve(self, &reference))
+    }
+
+    /// Creates the shortest reference which resolves to `target` against this URI
+    ///
+    /// This is the inverse of [`URI::join`]. If `target` has a different scheme, it is returned
+    /// unchanged.
+    ///
+    /// ## Parameters
+    ///  * `target` - The URI the reference should resolve to
+    ///
+    /// ## Return Value
+    /// Returns the relative reference to `target`
+    pub fn make_relative(&self, target: &URI) -> URI {
+        relative::make_relative(self, target)
+    }
+
+    /// Finds which components differ between this URI and `other`
+    ///
+    /// ## Parameters
+    ///  * `other` - The URI to compare against
+    ///
+    /// ## Return Value
+    /// Returns the differences from this URI to `other`
+    pub fn diff(&self, other: &URI) -> URIDiff {
+        URIDiff::new(self, other)
+    }
+
+    /// Checks if the path of this URI matches `pattern`
+    ///
+    /// Percent-encoding is normalized before matching. A "*" segment in `pattern` matches exactly
+    /// one segment and a "**" segment matches any number of segments, so "/api/*/items" matches
+    /// "/api/v1/items" but not "/api/v1/beta/items".
+    ///
+    /// ## Parameters
+    ///  * `pattern` - The path pattern to match against
+    ///
+    /// ## Return Value
+    /// Returns true if the path matches `pattern`
+    pub fn path_matches(&self, pattern: &str) -> bool {
+        matching::path_matches(self.path(), pattern)
+    }
+
+    /// Checks if the host of this URI matches `pattern`
+    ///
+    /// Hosts are compared case-insensitively. A leading "*." in `pattern` matches any subdomain,
+    /// so "*.example.com" matches "a.example.com" and "a.b.example.com" but not "example.com".
+    ///
+    /// ## Parameters
+    ///  * `pattern` - The host pattern to match against
+    ///
+    /// ## Return Value
+    /// Returns true if this URI has a host which matches `pattern`
+    pub fn host_matches(&self, pattern: &str) -> bool {
+        self.host()
+            .map(|host| matching::host_matches(host, pattern))
+            .unwrap_or(false)
+    }
+
+    /// Gets the host and port a connection for this URI should be made to
+    ///
+    /// ## Return Value
+    /// Returns the interpreted host and the explicit port, falling back to the default port of
+    /// the scheme, or [`None`] if either is unavailable
+    pub fn endpoint(&self) -> Option<(Host, u16)> {
+        self.endpoint_with(&SchemeRegistry::new())
+    }
+
+    /// Gets the host and port a connection for this URI should be made to, taking default ports
+    /// from `schemes`
+    ///
+    /// ## Parameters
+    ///  * `schemes` - The registry to look the default port up in
+    ///
+    /// ## Return Value
+    /// Returns the interpreted host and the explicit port, falling back to the default port of
+    /// the scheme in `schemes`, or [`None`] if either is unavailable
+    pub fn endpoint_with(&self, schemes: &SchemeRegistry) -> Option<(Host, u16)> {
+        let endpoint = Endpoint::from_uri_with(self, schemes)?;
+        let port = endpoint.port()?;
+        Some((endpoint.host().clone(), port))
+    }
+
+    /// Gets the path of this URI
+    ///
+    /// ## Return Value
+    /// Returns the path of this URI, which may be empty
+    pub fn path(&self) -> &str {
+        self.components.path(&self.serialization)
+    }
+
+    /// Gets the query of this URI
+    ///
+    /// ## Return Value
+    /// Returns the query of this URI without the leading '?', if it has one
+    pub fn query(&self) -> Option<&str> {
+        self.components.query(&self.serialization)
+    }
+
+    /// Gets the fragment of this URI
+    ///
+    /// ## Return Value
+    /// Returns the fragment of this URI without the leading '#', if it has one
+    pub fn fragment(&self) -> Option<&str> {
+        self.components.fragment(&self.serialization)
+    }
+
+    /// Gets the name and value pairs of the query, decoded as form data
+    ///
+    /// A '+' decodes to a space. Use [`URI::query_pairs_with`] when the query was not produced by
+    /// an HTML form and a '+' must be kept.
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the decoded pairs, which is empty if there is no query
+    pub fn query_pairs(&self) -> form::FormPairs<'_> {
+        form::parse(self.query().unwrap_or(""))
+    }
+
+    /// Gets the name and value pairs of the query, decoding '+' as specified by `plus`
+    ///
+    /// ## Parameters
+    ///  * `plus` - How a '+' in a name or value is decoded
+    ///
+    /// ## Return Value
+    /// Returns an iterator over the decoded pairs, which is empty if there is no query
+    pub fn query_pairs_with(&self, plus: form::PlusDecoding) -> form::FormPairs<'_> {
+        form::parse_with(self.query().unwrap_or(""), plus)
+    }
+
+    /// Compares against `other` after normalizing the case, percent-encoding, default port, and
+    /// dot segments of both
+    ///
+    /// ## Parameters
+    ///  * `other` - The URI to compare against
+    ///
+    /// ## Return Value
+    /// Returns true if `other` is a valid URI equivalent to this one
+    pub fn eq_normalized(&self, other: &str) -> bool {
+        self.eq_normalized_with(other, &SchemeRegistry::new())
+    }
+
+    /// Compares against `other` after normalizing both, removing the default ports `schemes` has
+    /// for their schemes
+    ///
+    /// ## Parameters
+    ///  * `other` - The URI to compare against
+    ///  * `schemes` - The registry to look default ports up in
+    ///
+    /// ## Return Value
+    /// Returns true if `other` is a valid URI equivalent to this one
+    pub fn eq_normalized_with(&self, other: &str, schemes: &SchemeRegistry) -> bool {
+        if self.serialization == other {
+            return true;
+        }
+
+        match parse::parse(other, ParseMode::RFC3986) {
+            Ok(other) => {
+                normalize::normalized(self, schemes) == normalize::normalized(&other, schemes)
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Generates a key which is identical for equivalent URIs
+    ///
+    /// The scheme and host are lowercased, percent-encoding is normalized, the default port and
+    /// dot segments are removed, the query pairs are sorted, and the fragment is dropped.
+    ///
+    /// ## Return Value
+    /// Returns the cache key of this URI
+    pub fn cache_key(&self) -> String {
+        self.cache_key_with(&CacheKeyConfig::new())
+    }
+
+    /// Generates a key which is identical for equivalent URIs, dropping the query parameters
+    /// ignored by `config` and the default ports of the schemes it knows
+    ///
+    /// ## Parameters
+    ///  * `config` - The settings controlling which query parameters are kept and which schemes
+    ///    are known
+    ///
+    /// ## Return Value
+    /// Returns the cache key of this URI
+    pub fn cache_key_with(&self, config: &CacheKeyConfig) -> String {
+        config.generate(self)
+    }
+}
+
+impl FromStr for URI {
+    type Err = InvalidURIError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        URI::parse_with_mode(s, ParseMode::RFC3986)
+    }
+}
+
+impl From<URI> for String {
+    fn from(uri: URI) -> Self {
+        uri.serialization
+    }
+}
+
+impl AsRef<str> for URI {
+    fn as_ref(&self) -> &str {
+        &self.serialization
+    }
+}
+
+impl PartialEq<str> for URI {
+    fn eq(&self, other: &str) -> bool {
+        self.serialization == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for URI {
+    fn eq(&self, other: &&'a str) -> bool {
+        self == *other
+    }
+}
+
+impl std::fmt::Display for URI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.serialization)
+    }
+}
+
+impl std::fmt::Debug for URI {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,16 @@
+/// The set of rules used when parsing a [`URI`](super::URI)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// Strict parsing following RFC 3986
+    ///
+    /// The input must be a valid URI reference and is kept exactly as written.
+    #[default]
+    RFC3986,
+
+    /// Forgiving parsing following the WHATWG URL Standard
+    ///
+    /// Surrounding whitespace is trimmed, backslashes are accepted as slashes in special schemes,
+    /// disallowed characters are percent-encoded, and the host, port, and path are canonicalized
+    /// the same way a browser would.
+    WHATWG,
+}
@@ -0,0 +1,59 @@
+use super::{dot_segments::remove_dot_segments, percent::normalize_into, SchemeRegistry, URI};
+
+/// Writes the syntax-normalized form of `uri` following RFC 3986 section 6.2.2 and the scheme
+/// defaults from section 6.2.3, taking default ports from `schemes`
+pub(crate) fn normalized(uri: &URI, schemes: &SchemeRegistry) -> String {
+    let mut output = String::with_capacity(uri.serialization.len());
+    write_normalized_base(&mut output, uri, schemes);
+
+    if let Some(query) = uri.query() {
+        output.push('?');
+        normalize_into(&mut output, query);
+    }
+
+    if let Some(fragment) = uri.fragment() {
+        output.push('#');
+        normalize_into(&mut output, fragment);
+    }
+
+    output
+}
+
+/// Writes the normalized scheme, authority, and path of `uri` into `output`
+///
+/// The scheme and host are lowercased, percent-encoding is normalized, and the default port and
+/// dot segments are removed. The default port is the one `schemes` has for the scheme of `uri`.
+pub(crate) fn write_normalized_base(output: &mut String, uri: &URI, schemes: &SchemeRegistry) {
+    if let Some(scheme) = uri.scheme() {
+        output.push_str(&scheme.to_ascii_lowercase());
+        output.push(':');
+    }
+
+    if let Some(host) = uri.host() {
+        output.push_str("//");
+
+        if let Some(userinfo) = uri.userinfo() {
+            normalize_into(output, userinfo);
+            output.push('@');
+        }
+
+        normalize_into(output, &host.to_ascii_lowercase());
+
+        let default_port = uri
+            .scheme_info_with(schemes)
+            .and_then(|info| info.default_port());
+        if let Some(port) = uri.port().filter(|port| Some(*port) != default_port) {
+            output.push(':');
+            output.push_str(&port.to_string());
+        }
+    }
+
+    let mut path = String::with_capacity(uri.path().len());
+    normalize_into(&mut path, uri.path());
+    if path.starts_with('/') {
+        path = remove_dot_segments(&path);
+    } else if path.is_empty() && uri.host().is_some() {
+        path.push('/');
+    }
+    output.push_str(&path);
+}
@@ -0,0 +1,14 @@
+use super::{InvalidURIError, ParseMode, URI};
+
+mod rfc3986;
+mod whatwg;
+
+pub(super) use rfc3986::{parse_authority, parse_components};
+
+/// Parses `input` into a [`URI`] following the rules of `mode`
+pub(super) fn parse(input: &str, mode: ParseMode) -> Result<URI, InvalidURIError> {
+    match mode {
+        ParseMode::RFC3986 => rfc3986::parse(input),
+        ParseMode::WHATWG => whatwg::parse(input),
+    }
+}
@@ -0,0 +1,208 @@
+use crate::uri::{
+    chars::{find_invalid, find_invalid_scheme, is_ipv6, is_userinfo, CharClass},
+    components::{AuthorityComponents, Components},
+    InvalidURIError, URIComponent, URI,
+};
+
+/// Parses `input` as a URI reference, keeping it exactly as written
+pub(super) fn parse(input: &str) -> Result<URI, InvalidURIError> {
+    parse_components(input).map(|components| URI {
+        serialization: input.to_owned(),
+        components,
+    })
+}
+
+/// Locates and validates each component of `input` without copying it
+pub(crate) const fn parse_components(input: &str) -> Result<Components, InvalidURIError> {
+    let bytes = input.as_bytes();
+    let mut position = 0;
+
+    // A ':' before any other delimiter marks the end of the scheme
+    let mut scheme = None;
+    let end = find_from(bytes, 0, b":/?#");
+    if end < bytes.len() && bytes[end] == b':' {
+        if let Some(invalid) = find_invalid_scheme(bytes, 0, end) {
+            return Err(InvalidURIError::at(bytes, URIComponent::Scheme, invalid));
+        }
+
+        scheme = Some(0..end);
+        position = end + 1;
+    }
+
+    let mut authority = None;
+    if position + 1 < bytes.len() && bytes[position] == b'/' && bytes[position + 1] == b'/' {
+        let start = position + 2;
+        let end = find_from(bytes, start, b"/?#");
+        authority = match parse_authority(bytes, start, end) {
+            Ok(components) => Some(components),
+            Err(error) => return Err(error),
+        };
+        position = end;
+    }
+
+    let end = find_from(bytes, position, b"?#");
+    if let Some(invalid) = find_invalid(bytes, position, end, CharClass::Path) {
+        return Err(InvalidURIError::at(bytes, URIComponent::Path, invalid));
+    }
+    let path = position..end;
+    position = end;
+
+    let mut query = None;
+    if position < bytes.len() && bytes[position] == b'?' {
+        let start = position + 1;
+        let end = find_from(bytes, start, b"#");
+        if let Some(invalid) = find_invalid(bytes, start, end, CharClass::Query) {
+            return Err(InvalidURIError::at(bytes, URIComponent::Query, invalid));
+        }
+        query = Some(start..end);
+        position = end;
+    }
+
+    let mut fragment = None;
+    if position < bytes.len() && bytes[position] == b'#' {
+        let start = position + 1;
+        if let Some(invalid) = find_invalid(bytes, start, bytes.len(), CharClass::Query) {
+            return Err(InvalidURIError::at(bytes, URIComponent::Fragment, invalid));
+        }
+        fragment = Some(start..bytes.len());
+    }
+
+    Ok(Components {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+    })
+}
+
+/// Parses the authority located from `start` to `end` in `bytes`
+pub(crate) const fn parse_authority(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Result<AuthorityComponents, InvalidURIError> {
+    let mut userinfo = None;
+    let mut host_start = start;
+
+    let mut i = end;
+    while i > start {
+        i -= 1;
+        if bytes[i] == b'@' {
+            if let Some(invalid) = find_invalid(bytes, start, i, CharClass::UserInfo) {
+                return Err(InvalidURIError::at(bytes, URIComponent::UserInfo, invalid));
+            }
+
+            userinfo = Some(start..i);
+            host_start = i + 1;
+            break;
+        }
+    }
+
+    let host_end = if host_start < end && bytes[host_start] == b'[' {
+        let close = find_from(bytes, host_start, b"]");
+        if close >= end {
+            return Err(InvalidURIError::malformed(URIComponent::Host, host_start));
+        }
+        close + 1
+    } else {
+        let colon = find_from(bytes, host_start, b":");
+        if colon < end {
+            colon
+        } else {
+            end
+        }
+    };
+
+    if let Err(error) = check_host(bytes, host_start, host_end) {
+        return Err(error);
+    }
+
+    let port = if host_end == end {
+        None
+    } else if bytes[host_end] == b':' {
+        let mut value = 0u32;
+        let mut i = host_end + 1;
+        while i < end {
+            if !bytes[i].is_ascii_digit() {
+                return Err(InvalidURIError::at(bytes, URIComponent::Port, i));
+            }
+
+            value = value * 10 + (bytes[i] - b'0') as u32;
+            if value > u16::MAX as u32 {
+                return Err(InvalidURIError::malformed(URIComponent::Port, host_end + 1));
+            }
+            i += 1;
+        }
+
+        Some(host_end + 1..end)
+    } else {
+        return Err(InvalidURIError::at(bytes, URIComponent::Host, host_end));
+    };
+
+    Ok(AuthorityComponents {
+        userinfo,
+        host: host_start..host_end,
+        port,
+    })
+}
+
+/// Checks that the bytes from `start` to `end` are an IP literal, an IPv4 address, or a
+/// registered name
+const fn check_host(bytes: &[u8], start: usize, end: usize) -> Result<(), InvalidURIError> {
+    if end - start >= 2 && bytes[start] == b'[' && bytes[end - 1] == b']' {
+        if is_ip_literal(bytes, start + 1, end - 1) {
+            Ok(())
+        } else {
+            Err(InvalidURIError::malformed(URIComponent::Host, start))
+        }
+    } else {
+        match find_invalid(bytes, start, end, CharClass::RegName) {
+            Some(invalid) => Err(InvalidURIError::at(bytes, URIComponent::Host, invalid)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Checks if the bytes from `start` to `end` are an IPv6 address or an "IPvFuture" address
+const fn is_ip_literal(bytes: &[u8], start: usize, end: usize) -> bool {
+    if start == end || (bytes[start] != b'v' && bytes[start] != b'V') {
+        return is_ipv6(bytes, start, end);
+    }
+
+    let mut i = start + 1;
+    while i < end && bytes[i].is_ascii_hexdigit() {
+        i += 1;
+    }
+
+    if i == start + 1 || i == end || bytes[i] != b'.' || i + 1 == end {
+        return false;
+    }
+
+    i += 1;
+    while i < end {
+        if !is_userinfo(bytes[i]) {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Finds the first of `delimiters` in `bytes` at or after `start`
+const fn find_from(bytes: &[u8], start: usize, delimiters: &[u8]) -> usize {
+    let mut i = start;
+    while i < bytes.len() {
+        let mut j = 0;
+        while j < delimiters.len() {
+            if bytes[i] == delimiters[j] {
+                return i;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    bytes.len()
+}
@@ -0,0 +1,537 @@
+#[cfg(feature = "idna")]
+use crate::dns::IDNAConfig;
+#[cfg(not(feature = "idna"))]
+use crate::dns::Label;
+use crate::uri::{
+    percent::{decode_bytes, encode_into, EncodeSet},
+    scheme,
+    serializer::Serializer,
+    InvalidURIError, URIComponent, URI,
+};
+use std::{
+    fmt::Write,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+/// Parses `input` following the WHATWG URL Standard, producing its canonical serialization
+pub(super) fn parse(input: &str) -> Result<URI, InvalidURIError> {
+    let filtered: String = input
+        .trim_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+
+    parse_filtered(&filtered).map_err(|mut error| {
+        if let InvalidURIError::InvalidComponent(error) = &mut error {
+            error.offset = original_offset(input, error.offset);
+        }
+        error
+    })
+}
+
+/// Maps `offset` in the trimmed and filtered form of `input` back to its offset in `input`
+fn original_offset(input: &str, offset: usize) -> usize {
+    let trimmed = input.trim_start_matches(|c: char| c <= ' ');
+    let mut filtered = 0;
+    for (i, c) in trimmed.char_indices() {
+        if matches!(c, '\t' | '\n' | '\r') {
+            continue;
+        }
+
+        if filtered == offset {
+            return input.len() - trimmed.len() + i;
+        }
+        filtered += c.len_utf8();
+    }
+
+    input.len() - trimmed.len() + trimmed.trim_end_matches(|c: char| c <= ' ').len()
+}
+
+/// Gets the offset of `part`, which must be a slice of `input`, within `input`
+fn offset_of(input: &str, part: &str) -> usize {
+    part.as_ptr() as usize - input.as_ptr() as usize
+}
+
+/// Parses `input` after surrounding whitespace and embedded tabs and newlines are removed
+fn parse_filtered(input: &str) -> Result<URI, InvalidURIError> {
+    let mut rest = input;
+    let mut serializer = Serializer::new();
+
+    let scheme = split_scheme(rest).map(|(scheme, remaining)| {
+        rest = remaining;
+        scheme.to_ascii_lowercase()
+    });
+    let scheme = scheme.as_deref();
+
+    // References without a scheme are treated like the special schemes browsers resolve them against
+    let special = scheme.map(is_special).unwrap_or(true);
+    let is_slash = |c: char| c == '/' || (special && c == '\\');
+
+    if let Some(scheme) = scheme {
+        serializer.scheme(scheme);
+    }
+
+    let authority = match scheme {
+        Some("file") => match strip_two_slashes(rest, is_slash) {
+            Some(remaining) => {
+                rest = remaining;
+                Some(split_authority(&mut rest, is_slash))
+            }
+            None => Some(""),
+        },
+        Some(_) if special => {
+            rest = rest.trim_start_matches(is_slash);
+            Some(split_authority(&mut rest, is_slash))
+        }
+        _ => strip_two_slashes(rest, is_slash).map(|remaining| {
+            rest = remaining;
+            split_authority(&mut rest, is_slash)
+        }),
+    };
+
+    if let Some(authority) = authority {
+        write_authority(&mut serializer, input, authority, scheme, special)?;
+    }
+
+    let path_end = rest.find(['?', '#']).unwrap_or(rest.len());
+    let (path, remaining) = rest.split_at(path_end);
+    rest = remaining;
+
+    let path = if special && (scheme.is_some() || authority.is_some()) {
+        normalize_path(path, is_slash)
+    } else if path.is_empty() || (scheme.is_some() && !path.starts_with('/')) {
+        encode(path, EncodeSet::C0Control)
+    } else if scheme.is_some() || authority.is_some() {
+        normalize_path(path, is_slash)
+    } else {
+        encode(&path.replace('\\', "/"), EncodeSet::Path)
+    };
+    serializer.path(&path);
+
+    if let Some(remaining) = rest.strip_prefix('?') {
+        let query_end = remaining.find('#').unwrap_or(remaining.len());
+        let set = if special {
+            EncodeSet::SpecialQuery
+        } else {
+            EncodeSet::Query
+        };
+
+        serializer.query(&encode(&remaining[..query_end], set));
+        rest = &remaining[query_end..];
+    }
+
+    if let Some(fragment) = rest.strip_prefix('#') {
+        serializer.fragment(&encode(fragment, EncodeSet::Fragment));
+    }
+
+    Ok(serializer.finish())
+}
+
+/// Checks if `scheme` is one of the special schemes from the URL Standard
+fn is_special(scheme: &str) -> bool {
+    matches!(scheme, "ftp" | "file" | "http" | "https" | "ws" | "wss")
+}
+
+/// Splits a leading scheme and ':' from `input`
+fn split_scheme(input: &str) -> Option<(&str, &str)> {
+    if !input.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let end =
+        input.find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')))?;
+    if input.as_bytes()[end] != b':' {
+        return None;
+    }
+
+    Some((&input[..end], &input[end + 1..]))
+}
+
+/// Removes two leading slashes from `input` if they are present
+fn strip_two_slashes(input: &str, is_slash: impl Fn(char) -> bool) -> Option<&str> {
+    let mut chars = input.chars();
+    match (chars.next(), chars.next()) {
+        (Some(first), Some(second)) if is_slash(first) && is_slash(second) => Some(&input[2..]),
+        _ => None,
+    }
+}
+
+/// Splits the authority from the front of `rest`
+fn split_authority<'a>(rest: &mut &'a str, is_slash: impl Fn(char) -> bool) -> &'a str {
+    let end = rest
+        .find(|c: char| is_slash(c) || c == '?' || c == '#')
+        .unwrap_or(rest.len());
+    let (authority, remaining) = rest.split_at(end);
+    *rest = remaining;
+    authority
+}
+
+/// Canonicalizes each part of `authority` and writes it into `serializer`
+fn write_authority(
+    serializer: &mut Serializer,
+    input: &str,
+    authority: &str,
+    scheme: Option<&str>,
+    special: bool,
+) -> Result<(), InvalidURIError> {
+    let (userinfo, host_port) = match authority.rfind('@') {
+        Some(at) => (encode_userinfo(&authority[..at]), &authority[at + 1..]),
+        None => (None, authority),
+    };
+
+    let (host, port) = if host_port.starts_with('[') {
+        let end = host_port.find(']').ok_or(InvalidURIError::malformed(
+            URIComponent::Host,
+            offset_of(input, host_port),
+        ))? + 1;
+        match &host_port[end..] {
+            "" => (&host_port[..end], None),
+            port if port.starts_with(':') => (&host_port[..end], Some(&port[1..])),
+            port => {
+                return Err(InvalidURIError::at(
+                    input.as_bytes(),
+                    URIComponent::Host,
+                    offset_of(input, port),
+                ))
+            }
+        }
+    } else {
+        match host_port.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+
+    let host_offset = offset_of(input, host);
+    let mut host = parse_host(input, host, special)?;
+    let port_offset = port.map(|port| offset_of(input, port));
+    let port = parse_port(input, port, scheme)?;
+
+    if scheme == Some("file") {
+        if userinfo.is_some() {
+            return Err(InvalidURIError::malformed(
+                URIComponent::UserInfo,
+                offset_of(input, authority),
+            ));
+        }
+
+        if let (Some(_), Some(offset)) = (&port, port_offset) {
+            return Err(InvalidURIError::malformed(URIComponent::Port, offset));
+        }
+
+        if host == "localhost" {
+            host.clear();
+        }
+    } else if special && host.is_empty() {
+        return Err(InvalidURIError::malformed(URIComponent::Host, host_offset));
+    }
+
+    serializer.authority(userinfo.as_deref(), &host, port.as_deref());
+    Ok(())
+}
+
+/// Encodes the username and password of `userinfo`, dropping it entirely if both are empty
+fn encode_userinfo(userinfo: &str) -> Option<String> {
+    let (username, password) = userinfo.split_once(':').unwrap_or((userinfo, ""));
+    if username.is_empty() && password.is_empty() {
+        return None;
+    }
+
+    let mut output = encode(username, EncodeSet::UserInfo);
+    if !password.is_empty() {
+        output.push(':');
+        encode_into(&mut output, password, EncodeSet::UserInfo);
+    }
+
+    Some(output)
+}
+
+/// Parses and canonicalizes `host`
+fn parse_host(input: &str, host: &str, special: bool) -> Result<String, InvalidURIError> {
+    let malformed = || InvalidURIError::malformed(URIComponent::Host, offset_of(input, host));
+    let invalid = |invalid: usize| {
+        InvalidURIError::at(
+            input.as_bytes(),
+            URIComponent::Host,
+            offset_of(input, host) + invalid,
+        )
+    };
+
+    if let Some(literal) = host.strip_prefix('[') {
+        let address = literal
+            .strip_suffix(']')
+            .and_then(|literal| literal.parse::<Ipv6Addr>().ok())
+            .ok_or_else(malformed)?;
+
+        let mut output = String::from("[");
+        write_ipv6(&mut output, address);
+        output.push(']');
+        return Ok(output);
+    }
+
+    if !special {
+        if let Some(position) = host
+            .bytes()
+            .position(|byte| byte != b'%' && is_forbidden_domain(byte))
+        {
+            return Err(invalid(position));
+        }
+
+        return Ok(encode(host, EncodeSet::C0Control));
+    }
+
+    // Point at the offending character when it was written directly rather than percent-encoded
+    if let Some(position) = host
+        .bytes()
+        .position(|byte| byte != b'%' && is_forbidden_domain(byte))
+    {
+        return Err(invalid(position));
+    }
+
+    let domain = String::from_utf8(decode_bytes(host)).map_err(|_| malformed())?;
+    let domain = domain_to_ascii(&domain).ok_or_else(malformed)?;
+    if domain.bytes().any(|byte| !byte.is_ascii() || is_forbidden_domain(byte)) {
+        return Err(malformed());
+    }
+
+    if ends_in_number(&domain) {
+        return parse_ipv4(&domain)
+            .map(|address| address.to_string())
+            .ok_or_else(malformed);
+    }
+
+    Ok(domain)
+}
+
+/// Converts `domain` to lowercase ASCII, replacing each Unicode label with its A-label
+#[cfg(feature = "idna")]
+fn domain_to_ascii(domain: &str) -> Option<String> {
+    IDNAConfig::new().to_idna().to_ascii(domain).ok()
+}
+
+/// Converts `domain` to lowercase ASCII, replacing each Unicode label with its A-label
+///
+/// Without the "idna" feature, labels are only lowercased before being Punycode encoded rather
+/// than fully mapped by UTS #46.
+#[cfg(not(feature = "idna"))]
+fn domain_to_ascii(domain: &str) -> Option<String> {
+    if domain.is_ascii() {
+        return Some(domain.to_ascii_lowercase());
+    }
+
+    let labels: Vec<String> = domain
+        .split('.')
+        .map(|label| Label::new(&label.to_lowercase()).to_ascii().into_owned())
+        .collect();
+    Some(labels.join("."))
+}
+
+/// Checks if `byte` is a forbidden domain code point
+fn is_forbidden_domain(byte: u8) -> bool {
+    byte <= 0x20
+        || byte == 0x7F
+        || matches!(
+            byte,
+            b'#' | b'%'
+                | b'/'
+                | b':'
+                | b'<'
+                | b'>'
+                | b'?'
+                | b'@'
+                | b'['
+                | b'\\'
+                | b']'
+                | b'^'
+                | b'|'
+        )
+}
+
+/// Checks if the last label of `domain` is a number, making it an IPv4 address
+fn ends_in_number(domain: &str) -> bool {
+    let last = ipv4_parts(domain).last().unwrap_or("");
+    (!last.is_empty() && last.bytes().all(|byte| byte.is_ascii_digit()))
+        || parse_ipv4_number(last).is_some()
+}
+
+/// Splits `domain` into its dot-separated parts, ignoring a single trailing dot
+fn ipv4_parts(domain: &str) -> impl Iterator<Item = &str> {
+    let domain = match domain.strip_suffix('.') {
+        Some(stripped) if !stripped.is_empty() => stripped,
+        _ => domain,
+    };
+
+    domain.split('.')
+}
+
+/// Parses an IPv4 address allowing the shortened, octal, and hexadecimal forms browsers accept
+fn parse_ipv4(domain: &str) -> Option<Ipv4Addr> {
+    let numbers = ipv4_parts(domain)
+        .map(parse_ipv4_number)
+        .collect::<Option<Vec<_>>>()?;
+    if numbers.len() > 4 {
+        return None;
+    }
+
+    let (last, rest) = numbers.split_last()?;
+    if rest.iter().any(|number| *number > 255) || *last >= 256u64.pow(5 - numbers.len() as u32) {
+        return None;
+    }
+
+    let mut address = *last;
+    for (i, number) in rest.iter().enumerate() {
+        address += number * 256u64.pow(3 - i as u32);
+    }
+
+    Some(Ipv4Addr::from(address as u32))
+}
+
+/// Parses a single part of an IPv4 address in decimal, octal ("0" prefix), or hexadecimal ("0x"
+/// prefix)
+fn parse_ipv4_number(part: &str) -> Option<u64> {
+    if part.is_empty() {
+        return None;
+    }
+
+    let (digits, radix) = if let Some(digits) = part
+        .strip_prefix("0x")
+        .or_else(|| part.strip_prefix("0X"))
+    {
+        (digits, 16)
+    } else if part.len() > 1 && part.starts_with('0') {
+        (&part[1..], 8)
+    } else {
+        (part, 10)
+    };
+
+    if digits.is_empty() {
+        return Some(0);
+    }
+
+    if !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    u64::from_str_radix(digits, radix).ok()
+}
+
+/// Writes `address` compressing the longest run of zero pieces and without an embedded IPv4 form
+fn write_ipv6(output: &mut String, address: Ipv6Addr) {
+    let segments = address.segments();
+
+    let mut compress: Option<(usize, usize)> = None;
+    let mut i = 0;
+    while i < segments.len() {
+        if segments[i] != 0 {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < segments.len() && segments[i] == 0 {
+            i += 1;
+        }
+
+        let length = i - start;
+        if length > 1 && compress.map(|(_, longest)| length > longest).unwrap_or(true) {
+            compress = Some((start, length));
+        }
+    }
+
+    let mut i = 0;
+    while i < segments.len() {
+        if let Some((start, length)) = compress {
+            if i == start {
+                output.push_str(if i == 0 { "::" } else { ":" });
+                i += length;
+                continue;
+            }
+        }
+
+        write!(output, "{:x}", segments[i]).unwrap();
+        if i < segments.len() - 1 {
+            output.push(':');
+        }
+        i += 1;
+    }
+}
+
+/// Validates `port`, dropping it if it is empty or the default for `scheme`
+fn parse_port(
+    input: &str,
+    port: Option<&str>,
+    scheme: Option<&str>,
+) -> Result<Option<String>, InvalidURIError> {
+    let port = match port {
+        Some(port) if !port.is_empty() => port,
+        _ => return Ok(None),
+    };
+
+    let offset = offset_of(input, port);
+    if let Some(position) = port.bytes().position(|byte| !byte.is_ascii_digit()) {
+        return Err(InvalidURIError::at(
+            input.as_bytes(),
+            URIComponent::Port,
+            offset + position,
+        ));
+    }
+
+    let port: u16 = port
+        .parse()
+        .map_err(|_| InvalidURIError::malformed(URIComponent::Port, offset))?;
+    // Only the special schemes have their default port removed
+    let default_port = scheme
+        .filter(|scheme| is_special(scheme))
+        .and_then(scheme::well_known)
+        .and_then(|info| info.default_port());
+    if default_port == Some(port) {
+        return Ok(None);
+    }
+
+    Ok(Some(port.to_string()))
+}
+
+/// Encodes the segments of a hierarchical path and resolves its dot segments
+fn normalize_path(path: &str, is_slash: impl Fn(char) -> bool) -> String {
+    let path = path.strip_prefix(&is_slash).unwrap_or(path);
+
+    let mut segments: Vec<String> = Vec::new();
+    let mut parts = path.split(&is_slash).peekable();
+    while let Some(segment) = parts.next() {
+        let last = parts.peek().is_none();
+
+        match segment.to_ascii_lowercase().as_str() {
+            ".." | ".%2e" | "%2e." | "%2e%2e" => {
+                segments.pop();
+                if last {
+                    segments.push(String::new());
+                }
+            }
+            "." | "%2e" => {
+                if last {
+                    segments.push(String::new());
+                }
+            }
+            _ => segments.push(encode(segment, EncodeSet::Path)),
+        }
+    }
+
+    let mut output = String::new();
+    for segment in segments {
+        output.push('/');
+        output.push_str(&segment);
+    }
+
+    if output.is_empty() {
+        output.push('/');
+    }
+
+    output
+}
+
+/// Percent-encodes `input` into a new string
+fn encode(input: &str, set: EncodeSet) -> String {
+    let mut output = String::with_capacity(input.len());
+    encode_into(&mut output, input, set);
+    output
+}
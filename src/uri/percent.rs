@@ -0,0 +1,219 @@
+//! Percent-encoding utilities
+//!
+//! Decoded components are not always text, so [`decode_to_bytes`] gives the exact bytes while
+//! [`decode_lossy`] and [`decode_utf8`] interpret them as UTF-8.
+
+use super::chars::{is_unreserved, CharClass};
+use std::{borrow::Cow, string::FromUtf8Error};
+
+/// A set of bytes which must be percent-encoded
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EncodeSet {
+    /// C0 control characters and all non-ASCII bytes
+    C0Control,
+
+    /// The WHATWG fragment percent-encode set
+    Fragment,
+
+    /// The WHATWG query percent-encode set
+    Query,
+
+    /// The WHATWG special-query percent-encode set
+    SpecialQuery,
+
+    /// The WHATWG path percent-encode set
+    Path,
+
+    /// The WHATWG userinfo percent-encode set
+    UserInfo,
+}
+
+impl EncodeSet {
+    /// Checks if `byte` must be encoded under this set
+    pub(crate) const fn contains(self, byte: u8) -> bool {
+        if byte < 0x20 || byte > 0x7E {
+            return true;
+        }
+
+        match self {
+            EncodeSet::C0Control => false,
+            EncodeSet::Fragment => matches!(byte, b' ' | b'"' | b'<' | b'>' | b'`'),
+            EncodeSet::Query => matches!(byte, b' ' | b'"' | b'#' | b'<' | b'>'),
+            EncodeSet::SpecialQuery => EncodeSet::Query.contains(byte) || byte == b'\'',
+            EncodeSet::Path => {
+                EncodeSet::Query.contains(byte) || matches!(byte, b'?' | b'`' | b'{' | b'}')
+            }
+            EncodeSet::UserInfo => {
+                EncodeSet::Path.contains(byte)
+                    || matches!(
+                        byte,
+                        b'/' | b':' | b';' | b'=' | b'@' | b'[' | b'\\' | b']' | b'^' | b'|'
+                    )
+            }
+        }
+    }
+}
+
+/// Percent-encodes `input` onto the end of `output`
+///
+/// ## Parameters
+///  * `output` - The string to append to
+///  * `input` - The text to encode
+///  * `set` - The bytes which must be encoded
+pub(crate) fn encode_into(output: &mut String, input: &str, set: EncodeSet) {
+    for &byte in input.as_bytes() {
+        if set.contains(byte) {
+            push_encoded(output, byte);
+        } else {
+            output.push(byte as char);
+        }
+    }
+}
+
+/// Percent-encodes every byte of `input` outside of `class` onto the end of `output`, keeping
+/// valid percent-encoded triplets as written
+///
+/// ## Parameters
+///  * `output` - The string to append to
+///  * `input` - The text to encode
+///  * `class` - The bytes which may appear unencoded
+pub(crate) fn encode_class_into(output: &mut String, input: &str, class: CharClass) {
+    let bytes = input.as_bytes();
+    for (i, &byte) in bytes.iter().enumerate() {
+        let is_triplet = byte == b'%'
+            && i + 2 < bytes.len()
+            && hex_value(bytes[i + 1]).is_some()
+            && hex_value(bytes[i + 2]).is_some();
+
+        if is_triplet || class.contains(byte) {
+            output.push(byte as char);
+        } else {
+            push_encoded(output, byte);
+        }
+    }
+}
+
+/// Appends `byte` to `output` as a percent-encoded triplet
+pub(crate) fn push_encoded(output: &mut String, byte: u8) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    output.push('%');
+    output.push(HEX[(byte >> 4) as usize] as char);
+    output.push(HEX[(byte & 0xF) as usize] as char);
+}
+
+/// Appends `input` to `output` with uppercase hexadecimal digits in each triplet and with
+/// unreserved characters decoded
+pub(crate) fn normalize_into(output: &mut String, input: &str) {
+    let bytes = input.as_bytes();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(high), Some(low)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                let byte = (high << 4) | low;
+                if is_unreserved(byte) {
+                    output.push(byte as char);
+                } else {
+                    push_encoded(output, byte);
+                }
+
+                i += 3;
+                continue;
+            }
+        }
+
+        let length = utf8_length(bytes[i]);
+        output.push_str(&input[i..i + length]);
+        i += length;
+    }
+}
+
+/// Gets the length of the UTF-8 sequence starting with `byte`
+const fn utf8_length(byte: u8) -> usize {
+    match byte {
+        0xF0.. => 4,
+        0xE0.. => 3,
+        0xC0.. => 2,
+        _ => 1,
+    }
+}
+
+/// Percent-decodes `input` into the bytes it represents
+///
+/// ## Parameters
+///  * `input` - The encoded text
+///
+/// ## Return Value
+/// Returns the decoded bytes, with invalid escapes left untouched, borrowing `input` if it has no
+/// escapes
+pub fn decode_to_bytes(input: &str) -> Cow<'_, [u8]> {
+    if input.contains('%') {
+        Cow::Owned(decode_bytes(input))
+    } else {
+        Cow::Borrowed(input.as_bytes())
+    }
+}
+
+/// Percent-decodes `input` as UTF-8 text
+///
+/// ## Parameters
+///  * `input` - The encoded text
+///
+/// ## Return Value
+/// Returns the decoded text, replacing invalid UTF-8 sequences with U+FFFD
+pub fn decode_lossy(input: &str) -> Cow<'_, str> {
+    match decode_to_bytes(input) {
+        Cow::Borrowed(_) => Cow::Borrowed(input),
+        Cow::Owned(bytes) => Cow::Owned(match String::from_utf8(bytes) {
+            Ok(decoded) => decoded,
+            Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+        }),
+    }
+}
+
+/// Percent-decodes `input` as UTF-8 text, rejecting invalid sequences
+///
+/// ## Parameters
+///  * `input` - The encoded text
+///
+/// ## Return Value
+/// Returns the decoded text, or an error holding the decoded bytes if they are not valid UTF-8
+pub fn decode_utf8(input: &str) -> Result<Cow<'_, str>, FromUtf8Error> {
+    match decode_to_bytes(input) {
+        Cow::Borrowed(_) => Ok(Cow::Borrowed(input)),
+        Cow::Owned(bytes) => String::from_utf8(bytes).map(Cow::Owned),
+    }
+}
+
+/// Percent-decodes `input` into raw bytes, leaving invalid escapes untouched
+pub(crate) fn decode_bytes(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(high), Some(low)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                output.push((high << 4) | low);
+                i += 3;
+                continue;
+            }
+        }
+
+        output.push(bytes[i]);
+        i += 1;
+    }
+
+    output
+}
+
+/// Gets the value of an ASCII hexadecimal digit
+pub(crate) const fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
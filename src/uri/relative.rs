@@ -0,0 +1,208 @@
+//! Resolution of references against a base URI and the inverse, from RFC 3986 section 5
+
+use super::{dot_segments::remove_dot_segments, serializer::Serializer, URI};
+
+/// Resolves `reference` against `base` following RFC 3986 section 5.2.2
+pub(crate) fn resolve(base: &URI, reference: &URI) -> URI {
+    let mut serializer = Serializer::new();
+
+    if let Some(scheme) = reference.scheme() {
+        serializer.scheme(scheme);
+        copy_authority(&mut serializer, reference);
+        serializer.path(&remove_dot_segments(reference.path()));
+        copy_query(&mut serializer, reference);
+    } else {
+        if let Some(scheme) = base.scheme() {
+            serializer.scheme(scheme);
+        }
+
+        if reference.authority().is_some() {
+            copy_authority(&mut serializer, reference);
+            serializer.path(&remove_dot_segments(reference.path()));
+            copy_query(&mut serializer, reference);
+        } else {
+            copy_authority(&mut serializer, base);
+
+            if reference.path().is_empty() {
+                serializer.path(base.path());
+                copy_query(
+                    &mut serializer,
+                    if reference.query().is_some() {
+                        reference
+                    } else {
+                        base
+                    },
+                );
+            } else {
+                let path = if reference.path().starts_with('/') {
+                    remove_dot_segments(reference.path())
+                } else {
+                    remove_dot_segments(&merge(base, reference.path()))
+                };
+
+                serializer.path(&path);
+                copy_query(&mut serializer, reference);
+            }
+        }
+    }
+
+    if let Some(fragment) = reference.fragment() {
+        serializer.fragment(fragment);
+    }
+
+    serializer.finish()
+}
+
+/// Creates the shortest reference which resolves to `target` when resolved against `base`
+pub(crate) fn make_relative(base: &URI, target: &URI) -> URI {
+    let same_scheme = match (base.scheme(), target.scheme()) {
+        (Some(base), Some(target)) => base.eq_ignore_ascii_case(target),
+        (None, None) => true,
+        _ => false,
+    };
+    if !same_scheme {
+        return target.clone();
+    }
+
+    let mut serializer = Serializer::new();
+
+    let same_authority = match (base.authority(), target.authority()) {
+        (Some(_), Some(_)) => {
+            base.userinfo() == target.userinfo()
+                && base.components.port_str(&base.serialization)
+                    == target.components.port_str(&target.serialization)
+                && base
+                    .host()
+                    .unwrap_or("")
+                    .eq_ignore_ascii_case(target.host().unwrap_or(""))
+        }
+        (None, None) => true,
+        _ => false,
+    };
+
+    let base_path = base.path();
+    let target_path = target.path();
+
+    let path = if !same_authority {
+        if target.authority().is_none() {
+            return target.clone();
+        }
+
+        copy_authority(&mut serializer, target);
+        target_path.to_owned()
+    } else if base_path == target_path {
+        if target.query().is_some() || base.query().is_none() {
+            String::new()
+        } else {
+            match last_segment(target_path) {
+                "" => "./".to_owned(),
+                segment if segment.contains(':') => format!("./{}", segment),
+                segment => segment.to_owned(),
+            }
+        }
+    } else if base_path.starts_with('/') && target_path.starts_with('/') {
+        shortest_path(base_path, target_path)
+    } else if target_path.starts_with('/') && !target_path.starts_with("//") {
+        target_path.to_owned()
+    } else if target.authority().is_some() {
+        copy_authority(&mut serializer, target);
+        target_path.to_owned()
+    } else {
+        return target.clone();
+    };
+
+    serializer.path(&path);
+
+    // An empty reference keeps the query of the base, so it is only omitted when identical
+    let keep_base_query = path.is_empty() && target.query() == base.query();
+    if let Some(query) = target.query().filter(|_| !keep_base_query) {
+        serializer.query(query);
+    }
+
+    if let Some(fragment) = target.fragment() {
+        serializer.fragment(fragment);
+    }
+
+    serializer.finish()
+}
+
+/// Finds the shorter of the relative-path and absolute-path references from `base` to `target`,
+/// which must both be absolute paths
+fn shortest_path(base: &str, target: &str) -> String {
+    let base_directory = &base[..base.rfind('/').unwrap() + 1];
+    let mut base_segments: Vec<&str> = base_directory[1..].split('/').collect();
+    base_segments.pop();
+
+    let target_directory_end = target.rfind('/').unwrap() + 1;
+    let mut target_segments: Vec<&str> = target[1..target_directory_end].split('/').collect();
+    target_segments.pop();
+
+    let common = base_segments
+        .iter()
+        .zip(&target_segments)
+        .take_while(|(base, target)| base == target)
+        .count();
+
+    let mut relative = "../".repeat(base_segments.len() - common);
+    for segment in &target_segments[common..] {
+        relative.push_str(segment);
+        relative.push('/');
+    }
+    relative.push_str(&target[target_directory_end..]);
+
+    // A relative path must not be empty, start with a scheme-like segment, or be mistaken for
+    // an authority
+    if relative.is_empty() {
+        relative.push_str("./");
+    } else if relative.starts_with('/')
+        || relative
+            .split('/')
+            .next()
+            .map(|segment| segment.contains(':'))
+            .unwrap_or(false)
+    {
+        relative.insert_str(0, "./");
+    }
+
+    if relative.len() > target.len() && !target.starts_with("//") {
+        target.to_owned()
+    } else {
+        relative
+    }
+}
+
+/// Gets the final segment of `path`
+fn last_segment(path: &str) -> &str {
+    &path[path.rfind('/').map(|slash| slash + 1).unwrap_or(0)..]
+}
+
+/// Merges a relative-path `reference` with the path of `base` following RFC 3986 section 5.2.3
+fn merge(base: &URI, reference: &str) -> String {
+    if base.authority().is_some() && base.path().is_empty() {
+        return format!("/{}", reference);
+    }
+
+    let base_path = base.path();
+    match base_path.rfind('/') {
+        Some(slash) => format!("{}{}", &base_path[..slash + 1], reference),
+        None => reference.to_owned(),
+    }
+}
+
+/// Copies the authority of `uri`, if it has one, into `serializer`
+fn copy_authority(serializer: &mut Serializer, uri: &URI) {
+    if let Some(host) = uri.host() {
+        serializer.authority(
+            uri.userinfo(),
+            host,
+            uri.components.port_str(&uri.serialization),
+        );
+    }
+}
+
+/// Copies the query of `uri`, if it has one, into `serializer`
+fn copy_query(serializer: &mut Serializer, uri: &URI) {
+    if let Some(query) = uri.query() {
+        serializer.query(query);
+    }
+}
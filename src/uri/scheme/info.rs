@@ -0,0 +1,39 @@
+/// Information about how URIs with a scheme are interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SchemeInfo {
+    default_port: Option<u16>,
+    authority_required: bool,
+}
+
+impl SchemeInfo {
+    /// Creates a new [`SchemeInfo`]
+    ///
+    /// ## Parameters
+    ///  * `default_port` - The port used when a URI does not specify one
+    ///  * `authority_required` - Whether URIs with the scheme must have an authority
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`SchemeInfo`]
+    pub const fn new(default_port: Option<u16>, authority_required: bool) -> Self {
+        SchemeInfo {
+            default_port,
+            authority_required,
+        }
+    }
+
+    /// Gets the port used when a URI does not specify one
+    ///
+    /// ## Return Value
+    /// Returns the default port, if the scheme has one
+    pub const fn default_port(&self) -> Option<u16> {
+        self.default_port
+    }
+
+    /// Checks if URIs with the scheme must have an authority
+    ///
+    /// ## Return Value
+    /// Returns true if an authority is required
+    pub const fn is_authority_required(&self) -> bool {
+        self.authority_required
+    }
+}
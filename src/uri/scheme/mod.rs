@@ -0,0 +1,180 @@
+use super::{chars::is_scheme, InvalidSchemeError};
+use std::{borrow::Cow, hash::Hash, str::FromStr};
+
+mod info;
+mod registry;
+
+pub use info::SchemeInfo;
+pub use registry::SchemeRegistry;
+
+pub(crate) use registry::well_known;
+
+/// A URI scheme, such as "https"
+///
+/// Schemes compare and hash case-insensitively but keep the case they were written in.
+#[derive(Clone)]
+pub struct Scheme {
+    name: Cow<'static, str>,
+}
+
+impl Scheme {
+    /// The "data" scheme
+    pub const DATA: Scheme = Scheme::from_static("data");
+
+    /// The "file" scheme
+    pub const FILE: Scheme = Scheme::from_static("file");
+
+    /// The "ftp" scheme
+    pub const FTP: Scheme = Scheme::from_static("ftp");
+
+    /// The "http" scheme
+    pub const HTTP: Scheme = Scheme::from_static("http");
+
+    /// The "https" scheme
+    pub const HTTPS: Scheme = Scheme::from_static("https");
+
+    /// The "ldap" scheme
+    pub const LDAP: Scheme = Scheme::from_static("ldap");
+
+    /// The "ldaps" scheme
+    pub const LDAPS: Scheme = Scheme::from_static("ldaps");
+
+    /// The "mailto" scheme
+    pub const MAILTO: Scheme = Scheme::from_static("mailto");
+
+    /// The "ssh" scheme
+    pub const SSH: Scheme = Scheme::from_static("ssh");
+
+    /// The "telnet" scheme
+    pub const TELNET: Scheme = Scheme::from_static("telnet");
+
+    /// The "urn" scheme
+    pub const URN: Scheme = Scheme::from_static("urn");
+
+    /// The "ws" scheme
+    pub const WS: Scheme = Scheme::from_static("ws");
+
+    /// The "wss" scheme
+    pub const WSS: Scheme = Scheme::from_static("wss");
+
+    /// Creates a new [`Scheme`]
+    ///
+    /// ## Parameters
+    ///  * `name` - The name of the scheme
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Scheme`] if `name` is a valid scheme
+    pub fn new<S: Into<String>>(name: S) -> Result<Self, InvalidSchemeError> {
+        let name = name.into();
+        if !is_scheme(name.as_bytes(), 0, name.len()) {
+            return Err(InvalidSchemeError);
+        }
+
+        Ok(Scheme {
+            name: Cow::Owned(name),
+        })
+    }
+
+    /// Creates a new [`Scheme`] from a static string
+    ///
+    /// ## Parameters
+    ///  * `name` - The name of the scheme, must be a valid scheme
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`Scheme`]
+    pub const fn from_static(name: &'static str) -> Self {
+        assert!(is_scheme(name.as_bytes(), 0, name.len()));
+        Scheme {
+            name: Cow::Borrowed(name),
+        }
+    }
+
+    /// Gets the name of this scheme
+    ///
+    /// ## Return Value
+    /// Returns the name of this scheme as it was written
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the well-known information about this scheme
+    ///
+    /// ## Return Value
+    /// Returns the information about this scheme, if it is well-known
+    pub fn info(&self) -> Option<SchemeInfo> {
+        registry::well_known(&self.name)
+    }
+
+    /// Gets the default port of this scheme
+    ///
+    /// ## Return Value
+    /// Returns the well-known default port of this scheme, if it has one
+    pub fn default_port(&self) -> Option<u16> {
+        self.info().and_then(|info| info.default_port())
+    }
+
+    /// Checks if URIs with this scheme must have an authority
+    ///
+    /// ## Return Value
+    /// Returns true if this scheme is well-known as requiring an authority
+    pub fn is_authority_required(&self) -> bool {
+        self.info()
+            .map(|info| info.is_authority_required())
+            .unwrap_or(false)
+    }
+}
+
+impl FromStr for Scheme {
+    type Err = InvalidSchemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scheme::new(s)
+    }
+}
+
+impl AsRef<str> for Scheme {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PartialEq for Scheme {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl Eq for Scheme {}
+
+impl PartialEq<str> for Scheme {
+    fn eq(&self, other: &str) -> bool {
+        self.name.eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for Scheme {
+    fn eq(&self, other: &&str) -> bool {
+        self.name.eq_ignore_ascii_case(other)
+    }
+}
+
+impl Hash for Scheme {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for byte in self.name.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        state.write_u8(0xFF);
+    }
+}
+
+impl std::fmt::Display for Scheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl std::fmt::Debug for Scheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,85 @@
+use super::SchemeInfo;
+use std::collections::BTreeMap;
+
+/// The schemes which are known without any registration
+const WELL_KNOWN: &[(&str, SchemeInfo)] = &[
+    ("data", SchemeInfo::new(None, false)),
+    ("file", SchemeInfo::new(None, false)),
+    ("ftp", SchemeInfo::new(Some(21), true)),
+    ("http", SchemeInfo::new(Some(80), true)),
+    ("https", SchemeInfo::new(Some(443), true)),
+    ("ldap", SchemeInfo::new(Some(389), true)),
+    ("ldaps", SchemeInfo::new(Some(636), true)),
+    ("mailto", SchemeInfo::new(None, false)),
+    ("ssh", SchemeInfo::new(Some(22), true)),
+    ("telnet", SchemeInfo::new(Some(23), true)),
+    ("urn", SchemeInfo::new(None, false)),
+    ("ws", SchemeInfo::new(Some(80), true)),
+    ("wss", SchemeInfo::new(Some(443), true)),
+];
+
+/// A set of schemes and how URIs using them are interpreted
+///
+/// A registry starts with the well-known schemes, such as "http" and "mailto". Registrations only
+/// affect the registry they are made in, so a registry is passed to the parser through a
+/// [`URIParseConfig`](crate::uri::URIParseConfig) rather than shared by every user of the crate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemeRegistry {
+    /// The registered schemes, keyed by their lowercase name
+    registered: BTreeMap<String, SchemeInfo>,
+}
+
+impl SchemeRegistry {
+    /// Creates a new [`SchemeRegistry`] knowing only the well-known schemes
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`SchemeRegistry`]
+    pub const fn new() -> Self {
+        SchemeRegistry {
+            registered: BTreeMap::new(),
+        }
+    }
+
+    /// Registers information about a scheme, replacing any well-known information about it
+    ///
+    /// ## Parameters
+    ///  * `name` - The name of the scheme, in any case
+    ///  * `info` - The information to register
+    ///
+    /// ## Return Value
+    /// Returns the information previously known for the scheme, if any
+    pub fn register(&mut self, name: &str, info: SchemeInfo) -> Option<SchemeInfo> {
+        let previous = self.lookup(name);
+        self.registered.insert(name.to_ascii_lowercase(), info);
+        previous
+    }
+
+    /// Gets the information known for a scheme
+    ///
+    /// ## Parameters
+    ///  * `name` - The name of the scheme, in any case
+    ///
+    /// ## Return Value
+    /// Returns the registered information about the scheme, or the well-known information if it
+    /// was not registered
+    pub fn lookup(&self, name: &str) -> Option<SchemeInfo> {
+        match self.registered.get(&name.to_ascii_lowercase()) {
+            Some(info) => Some(*info),
+            None => well_known(name),
+        }
+    }
+}
+
+impl Default for SchemeRegistry {
+    fn default() -> Self {
+        SchemeRegistry::new()
+    }
+}
+
+/// Gets the information about the well-known scheme `name`
+pub(crate) fn well_known(name: &str) -> Option<SchemeInfo> {
+    WELL_KNOWN
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|(_, info)| *info)
+}
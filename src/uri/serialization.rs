@@ -0,0 +1,15 @@
+use super::URI;
+use crate::serialization::ParseVisitor;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for URI {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for URI {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ParseVisitor::new("a URI"))
+    }
+}
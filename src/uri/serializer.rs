@@ -0,0 +1,101 @@
+use super::{
+    components::{AuthorityComponents, Components},
+    URI,
+};
+
+/// Incrementally writes the components of a URI while recording where each one is placed
+pub(crate) struct Serializer {
+    serialization: String,
+    components: Components,
+}
+
+impl Serializer {
+    /// Creates a new, empty [`Serializer`]
+    pub(crate) fn new() -> Self {
+        Serializer {
+            serialization: String::new(),
+            components: Components::default(),
+        }
+    }
+
+    /// Writes the scheme followed by ':'
+    ///
+    /// ## Parameters
+    ///  * `scheme` - The already validated scheme
+    pub(crate) fn scheme(&mut self, scheme: &str) {
+        self.components.scheme = Some(self.push(scheme));
+        self.serialization.push(':');
+    }
+
+    /// Writes the authority preceded by "//"
+    ///
+    /// ## Parameters
+    ///  * `userinfo` - The already encoded user information, if any
+    ///  * `host` - The already encoded host
+    ///  * `port` - The port text, if any
+    pub(crate) fn authority(&mut self, userinfo: Option<&str>, host: &str, port: Option<&str>) {
+        self.serialization.push_str("//");
+
+        let userinfo = userinfo.map(|userinfo| {
+            let range = self.push(userinfo);
+            self.serialization.push('@');
+            range
+        });
+
+        let host = self.push(host);
+
+        let port = port.map(|port| {
+            self.serialization.push(':');
+            self.push(port)
+        });
+
+        self.components.authority = Some(AuthorityComponents {
+            userinfo,
+            host,
+            port,
+        });
+    }
+
+    /// Writes the path
+    ///
+    /// ## Parameters
+    ///  * `path` - The already encoded path
+    pub(crate) fn path(&mut self, path: &str) {
+        self.components.path = self.push(path);
+    }
+
+    /// Writes the query preceded by '?'
+    ///
+    /// ## Parameters
+    ///  * `query` - The already encoded query
+    pub(crate) fn query(&mut self, query: &str) {
+        self.serialization.push('?');
+        self.components.query = Some(self.push(query));
+    }
+
+    /// Writes the fragment preceded by '#'
+    ///
+    /// ## Parameters
+    ///  * `fragment` - The already encoded fragment
+    pub(crate) fn fragment(&mut self, fragment: &str) {
+        self.serialization.push('#');
+        self.components.fragment = Some(self.push(fragment));
+    }
+
+    /// Completes the serialization
+    ///
+    /// ## Return Value
+    /// Returns the [`URI`] that was written
+    pub(crate) fn finish(self) -> URI {
+        URI {
+            serialization: self.serialization,
+            components: self.components,
+        }
+    }
+
+    fn push(&mut self, value: &str) -> std::ops::Range<usize> {
+        let start = self.serialization.len();
+        self.serialization.push_str(value);
+        start..self.serialization.len()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{uri::InvalidURIError, ErrorKind};
+
+/// A line of a `text/uri-list` does not hold a valid URI
+#[derive(Clone, PartialEq, Eq)]
+pub struct InvalidLineError {
+    /// The line number, starting from 1
+    pub line: usize,
+
+    /// The reason the URI is invalid
+    pub error: InvalidURIError,
+}
+
+impl InvalidLineError {
+    /// Gets the broad category of this error
+    ///
+    /// ## Return Value
+    /// Returns the [`ErrorKind`] this error falls under
+    pub const fn kind(&self) -> ErrorKind {
+        self.error.kind()
+    }
+}
+
+impl std::error::Error for InvalidLineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl std::fmt::Display for InvalidLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid URI on line {} - {}", self.line, self.error)
+    }
+}
+
+impl std::fmt::Debug for InvalidLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
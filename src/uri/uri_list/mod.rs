@@ -0,0 +1,75 @@
+//! `text/uri-list` utilities from RFC 2483
+//!
+//! Each line of the list holds a single URI. Lines are separated by CRLF and lines starting with
+//! '#' are comments.
+
+use super::URI;
+
+mod error;
+
+pub use error::InvalidLineError;
+
+/// Parses each URI in a `text/uri-list`
+///
+/// Blank lines and comments are skipped. Lines separated by a lone LF are accepted as well.
+///
+/// ## Parameters
+///  * `input` - The contents of the list
+///
+/// ## Return Value
+/// Returns the result of parsing each URI line, in order
+pub fn parse(input: &str) -> Vec<Result<URI, InvalidLineError>> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+
+            Some(
+                line.parse()
+                    .map_err(|error| InvalidLineError { line: i + 1, error }),
+            )
+        })
+        .collect()
+}
+
+/// Writes `uris` as a `text/uri-list`
+///
+/// ## Parameters
+///  * `uris` - The URIs to write
+///
+/// ## Return Value
+/// Returns the contents of the list
+pub fn to_string<'a, I: IntoIterator<Item = &'a URI>>(uris: I) -> String {
+    let mut output = String::new();
+    for uri in uris {
+        append_uri(&mut output, uri);
+    }
+    output
+}
+
+/// Appends `uri` to `output` as a line of a `text/uri-list`
+///
+/// ## Parameters
+///  * `output` - The list to append to
+///  * `uri` - The URI to append
+pub fn append_uri(output: &mut String, uri: &URI) {
+    output.push_str(uri.as_str());
+    output.push_str("\r\n");
+}
+
+/// Appends `comment` to `output` as comment lines of a `text/uri-list`
+///
+/// ## Parameters
+///  * `output` - The list to append to
+///  * `comment` - The text of the comment, which is split into a comment line for each line
+pub fn append_comment(output: &mut String, comment: &str) {
+    for line in comment.lines() {
+        output.push('#');
+        output.push_str(line);
+        output.push_str("\r\n");
+    }
+}
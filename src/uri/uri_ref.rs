@@ -0,0 +1,164 @@
+use super::{components::Components, parse::parse_components, InvalidURIError, URI};
+
+/// A uniform resource identifier (URI) borrowed from the text it was parsed from
+///
+/// Parsing only records where each component is located, so no allocation takes place. Parsing
+/// always follows RFC 3986 as the WHATWG rules may require rewriting the input.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct URIRef<'a> {
+    serialization: &'a str,
+    components: Components,
+}
+
+impl<'a> URIRef<'a> {
+    /// Parses a [`URIRef`] from `input` following RFC 3986
+    ///
+    /// ## Parameters
+    ///  * `input` - The text to parse
+    ///
+    /// ## Return Value
+    /// Returns the parsed [`URIRef`] if `input` is valid
+    pub const fn parse(input: &'a str) -> Result<Self, InvalidURIError> {
+        match parse_components(input) {
+            Ok(components) => Ok(URIRef {
+                serialization: input,
+                components,
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Gets the text this URI was parsed from
+    ///
+    /// ## Return Value
+    /// Returns the complete text of this URI
+    pub const fn as_str(&self) -> &'a str {
+        self.serialization
+    }
+
+    /// Gets the scheme of this URI
+    ///
+    /// ## Return Value
+    /// Returns the scheme of this URI, if it has one
+    pub fn scheme(&self) -> Option<&'a str> {
+        self.components.scheme(self.serialization)
+    }
+
+    /// Gets the authority of this URI
+    ///
+    /// ## Return Value
+    /// Returns the complete authority of this URI, if it has one
+    pub fn authority(&self) -> Option<&'a str> {
+        self.components.authority(self.serialization)
+    }
+
+    /// Gets the user information of this URI
+    ///
+    /// ## Return Value
+    /// Returns the user information of this URI, if it has any
+    pub fn userinfo(&self) -> Option<&'a str> {
+        self.components.userinfo(self.serialization)
+    }
+
+    /// Gets the host of this URI
+    ///
+    /// ## Return Value
+    /// Returns the host of this URI, including the brackets around an IP literal, if it has an
+    /// authority
+    pub fn host(&self) -> Option<&'a str> {
+        self.components.host(self.serialization)
+    }
+
+    /// Gets the port of this URI
+    ///
+    /// ## Return Value
+    /// Returns the port of this URI, if one is specified
+    pub fn port(&self) -> Option<u16> {
+        self.components.port(self.serialization)
+    }
+
+    /// Gets the path of this URI
+    ///
+    /// ## Return Value
+    /// Returns the path of this URI, which may be empty
+    pub fn path(&self) -> &'a str {
+        self.components.path(self.serialization)
+    }
+
+    /// Gets the query of this URI
+    ///
+    /// ## Return Value
+    /// Returns the query of this URI without the leading '?', if it has one
+    pub fn query(&self) -> Option<&'a str> {
+        self.components.query(self.serialization)
+    }
+
+    /// Gets the fragment of this URI
+    ///
+    /// ## Return Value
+    /// Returns the fragment of this URI without the leading '#', if it has one
+    pub fn fragment(&self) -> Option<&'a str> {
+        self.components.fragment(self.serialization)
+    }
+
+    /// Copies this URI into an owned [`URI`]
+    ///
+    /// ## Return Value
+    /// Returns the newly created [`URI`]
+    pub fn to_uri(&self) -> URI {
+        URI {
+            serialization: self.serialization.to_owned(),
+            components: self.components.clone(),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for URIRef<'a> {
+    type Error = InvalidURIError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        URIRef::parse(value)
+    }
+}
+
+impl<'a> From<&'a URI> for URIRef<'a> {
+    fn from(uri: &'a URI) -> Self {
+        URIRef {
+            serialization: &uri.serialization,
+            components: uri.components.clone(),
+        }
+    }
+}
+
+impl<'a> From<URIRef<'a>> for URI {
+    fn from(uri: URIRef<'a>) -> Self {
+        URI {
+            serialization: uri.serialization.to_owned(),
+            components: uri.components,
+        }
+    }
+}
+
+impl<'a> PartialEq<URI> for URIRef<'a> {
+    fn eq(&self, other: &URI) -> bool {
+        self.serialization == other.serialization
+    }
+}
+
+impl<'a> PartialEq<URIRef<'a>> for URI {
+    fn eq(&self, other: &URIRef<'a>) -> bool {
+        self.serialization == other.serialization
+    }
+}
+
+impl<'a> std::fmt::Display for URIRef<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.serialization)
+    }
+}
+
+impl<'a> std::fmt::Debug for URIRef<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
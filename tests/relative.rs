@@ -0,0 +1,26 @@
+use net_utils::uri::URI;
+
+/// Checks that the relative reference from `base` to `target` is `expected` and resolves back
+fn round_trip(base: &str, target: &str, expected: &str) {
+    let base: URI = base.parse().unwrap();
+    let target: URI = target.parse().unwrap();
+
+    let relative = base.make_relative(&target);
+    assert_eq!(relative.as_str(), expected);
+    assert_eq!(base.join(relative.as_str()).unwrap(), target);
+}
+
+#[test]
+fn same_directory_segment_with_colon() {
+    round_trip("http://a/b/x:y?q", "http://a/b/x:y", "./x:y");
+}
+
+#[test]
+fn same_authority_with_empty_base_path() {
+    round_trip("http://a", "http://a/x", "/x");
+}
+
+#[test]
+fn different_authority() {
+    round_trip("http://a/b", "http://c/d", "//c/d");
+}
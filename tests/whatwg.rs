@@ -0,0 +1,25 @@
+use net_utils::uri::{ParseMode, URI};
+
+/// Checks that `input` parses following the WHATWG URL Standard to `expected`
+fn parses_to(input: &str, expected: &str) {
+    let uri = URI::parse_with_mode(input, ParseMode::WHATWG).unwrap();
+    assert_eq!(uri.as_str(), expected);
+}
+
+#[test]
+fn unicode_host() {
+    parses_to("http://Bücher.example/", "http://xn--bcher-kva.example/");
+}
+
+#[test]
+fn percent_encoded_unicode_host() {
+    parses_to(
+        "http://B%C3%BCcher.example/",
+        "http://xn--bcher-kva.example/",
+    );
+}
+
+#[test]
+fn forbidden_host_code_point() {
+    assert!(URI::parse_with_mode("http://a<b.example/", ParseMode::WHATWG).is_err());
+}